@@ -0,0 +1,481 @@
+use std::time::Duration;
+
+use actix::Addr;
+use actix_web::{get, HttpResponse, post, put, web};
+use actix_web::http::StatusCode;
+use actix_web::web::Bytes;
+use futures::StreamExt;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tracing::warn;
+
+use crate::error;
+use crate::journal::{JournalEntry, JournalQuery};
+use crate::logging::CorrelationId;
+use crate::messages::actions::Action;
+use crate::messages::requests;
+use crate::server;
+use crate::services::{HeartbeatPolicy, HeartbeatPolicyChange};
+
+// Resource style endpoints for the calls the CSMS sends to a charging station. Unlike
+// `/api/post-request`, which only queues a call for the web client, every endpoint here waits for
+// the reply of the station and answers with it:
+//   200 / 202  the station accepted (202 when it only scheduled the operation)
+//   400        the body is not a valid request for the station's OCPP version
+//   409        the station rejected the request, the body holds its CallResult
+//   502        the station answered with a CallError or an invalid CallResult
+//   503        the station is not connected, or disconnected before it answered
+//   504        the station did not answer within server::CALL_RESULT_TIMEOUT
+// Bodies are OCPP 2.0.1 requests. OCPP 1.6 charge points only take the ones that map onto a
+// single 1.6 call, e.g. setting one variable, which becomes a ChangeConfiguration.
+
+/// Body of POST /api/stations/{id}/remote-start. The remoteStartId is generated by the server.
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct RemoteStartBody {
+    #[serde(rename = "idToken")]
+    pub id_token: requests::IdTokenType,
+    #[serde(rename = "evseId")]
+    pub evse_id: Option<i64>,
+    #[serde(rename = "groupIdToken")]
+    pub group_id_token: Option<requests::IdTokenType>,
+    #[serde(rename = "chargingProfile")]
+    pub charging_profile: Option<requests::ChargingProfileType>,
+}
+
+/// Query of GET /api/stations
+#[derive(Deserialize)]
+pub struct StationFilter {
+    /// only stations with a connector in this status, e.g. "Available" or "Faulted"
+    pub status: Option<String>,
+    pub online: Option<bool>,
+}
+
+/// Query of GET /api/offline-stations
+#[derive(Deserialize)]
+pub struct OfflineQuery {
+    /// seconds, the configured offline threshold when missing
+    pub threshold: Option<u64>,
+}
+
+/// How many of the newest entries GET /api/journal returns when the query has no limit
+const JOURNAL_QUERY_LIMIT: usize = 1000;
+
+/// Body of the reply to a CallError
+#[derive(Serialize, JsonSchema)]
+pub struct CallErrorBody {
+    error: String,
+    #[serde(rename = "errorCode")]
+    error_code: String,
+    #[serde(rename = "errorDetails")]
+    error_details: Value,
+}
+
+/// A variable of the heartbeat policy set on the station with SetVariables
+#[derive(Serialize, JsonSchema)]
+pub struct PushedVariable {
+    /// variable of the OCPPCommCtrlr component, e.g. HeartbeatInterval
+    variable: String,
+    /// attributeStatus of the SetVariables result, status of a 1.6 ChangeConfiguration result
+    status: String,
+}
+
+/// Reply to PUT /api/stations/{id}/heartbeat
+#[derive(Serialize, JsonSchema)]
+pub struct HeartbeatPolicyBody {
+    policy: HeartbeatPolicy,
+    /// variables sent to the station, empty when it is offline
+    pushed: Vec<PushedVariable>,
+}
+
+/// Status code for a CallResult whose `status` field tells whether the request was accepted
+fn status_code(payload: &Value, accepted: &[&str], scheduled: &[&str]) -> StatusCode {
+    match payload.get("status").and_then(|status| status.as_str()) {
+        Some(status) if accepted.contains(&status) => StatusCode::OK,
+        Some(status) if scheduled.contains(&status) => StatusCode::ACCEPTED,
+        _ => StatusCode::CONFLICT,
+    }
+}
+
+/// Sends a call to a station and waits for its reply
+async fn send_call(srv: &Addr<server::OcppServer>, charger_id: &str, action: Action, payload: Value,
+                   correlation: &CorrelationId) -> Result<server::CallReply, error::Error> {
+    let sent = srv.send(server::SendCall {
+        charger_id: charger_id.to_string(),
+        action: action.name().to_string(),
+        payload,
+        correlation_id: correlation.0.clone(),
+    }).await;
+    let receiver = match sent {
+        Ok(Ok(receiver)) => receiver,
+        Ok(Err(server::SendCallError::Offline)) => {
+            return Err(error::Error { message: format!("Charging station {} is offline", charger_id), status: 503 });
+        }
+        Ok(Err(server::SendCallError::InvalidPayload(e))) => {
+            return Err(error::Error { message: e, status: 400 });
+        }
+        Err(_) => return Err(error::Error { message: "Unable to send the call".to_string(), status: 500 }),
+    };
+    match actix_web::rt::time::timeout(server::CALL_RESULT_TIMEOUT, receiver).await {
+        Ok(Ok(reply)) => Ok(reply),
+        // the server drops the calls of a station when its session ends
+        Ok(Err(_)) => Err(error::Error {
+            message: format!("Charging station {} disconnected before answering", charger_id),
+            status: 503,
+        }),
+        Err(_) => Err(error::Error {
+            message: format!("Charging station {} did not answer within {} seconds",
+                             charger_id, server::CALL_RESULT_TIMEOUT.as_secs()),
+            status: 504,
+        }),
+    }
+}
+
+/// Turns the reply of a station into the HTTP response. `result_status` maps the CallResult of
+/// the station onto the status code of the response.
+fn reply_response(action: Action, reply: server::CallReply,
+                  result_status: impl Fn(&Value) -> StatusCode) -> HttpResponse {
+    match reply {
+        server::CallReply::Result(payload) => HttpResponse::build(result_status(&payload)).json(payload),
+        server::CallReply::Error { error_code, error_description, error_details } => {
+            HttpResponse::BadGateway().json(CallErrorBody {
+                error: error_description,
+                error_code,
+                error_details,
+            })
+        }
+        server::CallReply::ProtocolViolation { payload, violation } => {
+            HttpResponse::BadGateway().json(json!({
+                "error": format!("Invalid {} call result: {}", action.name(), violation),
+                "callResult": payload,
+            }))
+        }
+    }
+}
+
+/// Sends a call to a station, waits for the reply and turns it into the HTTP response
+async fn call_station(srv: &Addr<server::OcppServer>, charger_id: String, action: Action, payload: Value,
+                      correlation: CorrelationId,
+                      result_status: impl Fn(&Value) -> StatusCode) -> Result<HttpResponse, error::Error> {
+    let reply = send_call(srv, &charger_id, action, payload, &correlation).await?;
+    Ok(reply_response(action, reply, result_status))
+}
+
+#[post("/api/stations/{id}/reset")]
+async fn reset(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>, correlation: CorrelationId,
+               body: web::Json<requests::ResetRequest>) -> Result<HttpResponse, error::Error> {
+    let payload = serde_json::to_value(body.into_inner()).unwrap();
+    call_station(&srv, id.into_inner(), Action::Reset, payload, correlation,
+                 |result| status_code(result, &["Accepted"], &["Scheduled"])).await
+}
+
+#[post("/api/stations/{id}/unlock")]
+async fn unlock(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>, correlation: CorrelationId,
+                body: web::Json<requests::UnlockConnectorRequest>) -> Result<HttpResponse, error::Error> {
+    let payload = serde_json::to_value(body.into_inner()).unwrap();
+    call_station(&srv, id.into_inner(), Action::UnlockConnector, payload, correlation,
+                 |result| status_code(result, &["Unlocked"], &[])).await
+}
+
+#[post("/api/stations/{id}/remote-start")]
+async fn remote_start(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>,
+                      correlation: CorrelationId,
+                      body: web::Json<RemoteStartBody>) -> Result<HttpResponse, error::Error> {
+    let body = body.into_inner();
+    let request = requests::RequestStartTransactionRequest {
+        charging_profile: body.charging_profile,
+        custom_data: None,
+        evse_id: body.evse_id,
+        group_id_token: body.group_id_token,
+        id_token: body.id_token,
+        // replaced by the server
+        remote_start_id: 0,
+    };
+    let payload = serde_json::to_value(request).unwrap();
+    call_station(&srv, id.into_inner(), Action::RequestStartTransaction, payload, correlation,
+                 |result| status_code(result, &["Accepted"], &[])).await
+}
+
+#[post("/api/stations/{id}/remote-stop")]
+async fn remote_stop(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>, correlation: CorrelationId,
+                     body: web::Json<requests::RequestStopTransactionRequest>) -> Result<HttpResponse, error::Error> {
+    let payload = serde_json::to_value(body.into_inner()).unwrap();
+    call_station(&srv, id.into_inner(), Action::RequestStopTransaction, payload, correlation,
+                 |result| status_code(result, &["Accepted"], &[])).await
+}
+
+#[post("/api/stations/{id}/change-availability")]
+async fn change_availability(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>,
+                             correlation: CorrelationId,
+                             body: web::Json<requests::ChangeAvailabilityRequest>) -> Result<HttpResponse, error::Error> {
+    let payload = serde_json::to_value(body.into_inner()).unwrap();
+    call_station(&srv, id.into_inner(), Action::ChangeAvailability, payload, correlation,
+                 |result| status_code(result, &["Accepted"], &["Scheduled"])).await
+}
+
+#[post("/api/stations/{id}/trigger-message")]
+async fn trigger_message(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>,
+                         correlation: CorrelationId,
+                         body: web::Json<requests::TriggerMessageRequest>) -> Result<HttpResponse, error::Error> {
+    let payload = serde_json::to_value(body.into_inner()).unwrap();
+    call_station(&srv, id.into_inner(), Action::TriggerMessage, payload, correlation,
+                 |result| status_code(result, &["Accepted"], &[])).await
+}
+
+#[post("/api/stations/{id}/reserve")]
+async fn reserve(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>, correlation: CorrelationId,
+                 body: web::Json<requests::ReserveNowRequest>) -> Result<HttpResponse, error::Error> {
+    let payload = serde_json::to_value(body.into_inner()).unwrap();
+    call_station(&srv, id.into_inner(), Action::ReserveNow, payload, correlation,
+                 |result| status_code(result, &["Accepted"], &[])).await
+}
+
+#[post("/api/stations/{id}/cancel-reservation")]
+async fn cancel_reservation(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>,
+                            correlation: CorrelationId,
+                            body: web::Json<requests::CancelReservationRequest>) -> Result<HttpResponse, error::Error> {
+    let payload = serde_json::to_value(body.into_inner()).unwrap();
+    call_station(&srv, id.into_inner(), Action::CancelReservation, payload, correlation,
+                 |result| status_code(result, &["Accepted"], &[])).await
+}
+
+/// Reads variables with GetVariables, which an OCPP 1.6 charge point gets as GetConfiguration.
+/// Every variable has its own status in the result, so any answer of the station is a 200.
+#[post("/api/stations/{id}/get-variables")]
+async fn get_variables(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>,
+                       correlation: CorrelationId,
+                       body: web::Json<requests::GetVariablesRequest>) -> Result<HttpResponse, error::Error> {
+    let payload = serde_json::to_value(body.into_inner()).unwrap();
+    call_station(&srv, id.into_inner(), Action::GetVariables, payload, correlation, |_| StatusCode::OK).await
+}
+
+#[post("/api/stations/{id}/variables")]
+async fn set_variables(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>,
+                       correlation: CorrelationId,
+                       body: web::Json<requests::SetVariablesRequest>) -> Result<HttpResponse, error::Error> {
+    let payload = serde_json::to_value(body.into_inner()).unwrap();
+    // every variable has its own status, the request counts as rejected if one of them failed
+    call_station(&srv, id.into_inner(), Action::SetVariables, payload, correlation, |result| {
+        match result.get("setVariableResult").and_then(|results| results.as_array()) {
+            Some(results) => {
+                let all_accepted = results.iter().all(|result| {
+                    matches!(result.get("attributeStatus").and_then(|status| status.as_str()),
+                             Some("Accepted") | Some("RebootRequired"))
+                });
+                if all_accepted { StatusCode::OK } else { StatusCode::CONFLICT }
+            }
+            // ChangeConfiguration result of an OCPP 1.6 charge point
+            None => status_code(result, &["Accepted", "RebootRequired"], &[]),
+        }
+    }).await
+}
+
+#[get("/api/stations/{id}/heartbeat")]
+async fn heartbeat_policy(srv: web::Data<Addr<server::OcppServer>>,
+                          id: web::Path<String>) -> Result<HttpResponse, error::Error> {
+    match srv.send(server::GetHeartbeatPolicy { charger_id: id.into_inner() }).await {
+        Ok(policy) => Ok(HttpResponse::Ok().json(policy)),
+        Err(_) => Err(error::Error { message: "Unable to get the heartbeat policy".to_string(), status: 500 }),
+    }
+}
+
+/// Whether the attributeStatus of a SetVariables result, or the status of a 1.6
+/// ChangeConfiguration result, means the station took the value
+fn variable_accepted(status: &str) -> bool {
+    matches!(status, "Accepted" | "RebootRequired")
+}
+
+/// Sets a variable of the OCPPCommCtrlr component with SetVariables, which an OCPP 1.6 charge point
+/// gets as ChangeConfiguration
+async fn push_variable(srv: &Addr<server::OcppServer>, charger_id: &str, variable: &str, value: u64,
+                       correlation: &CorrelationId) -> Result<server::CallReply, error::Error> {
+    let payload = json!({
+        "setVariableData": [{
+            "attributeValue": value.to_string(),
+            "component": { "name": "OCPPCommCtrlr" },
+            "variable": { "name": variable },
+        }]
+    });
+    send_call(srv, charger_id, Action::SetVariables, payload, correlation).await
+}
+
+/// Changes the heartbeat policy of a station. A new Heartbeat or ping interval is pushed to a
+/// connected station with SetVariables, one variable per call so that OCPP 1.6 charge points get
+/// it as ChangeConfiguration. The server applies the policy only once the station accepted every
+/// variable; when it rejects one, the variables it already took are set back and the policy stays
+/// as it was (409). An offline station gets its Heartbeat interval with the response to its next
+/// BootNotification, hence 202.
+#[put("/api/stations/{id}/heartbeat")]
+async fn set_heartbeat_policy(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>,
+                              correlation: CorrelationId,
+                              body: web::Json<HeartbeatPolicyChange>) -> Result<HttpResponse, error::Error> {
+    let charger_id = id.into_inner();
+    let change = body.into_inner();
+    let previous = match srv.send(server::GetHeartbeatPolicy { charger_id: charger_id.clone() }).await {
+        Ok(policy) => policy,
+        Err(_) => return Err(error::Error { message: "Unable to get the heartbeat policy".to_string(), status: 500 }),
+    };
+    previous.with(&change).validate().map_err(|message| error::Error { message, status: 400 })?;
+    let variables = [("HeartbeatInterval", change.heartbeat_interval, previous.heartbeat_interval),
+                     ("WebSocketPingInterval", change.ping_interval, previous.ping_interval)];
+    let mut pushed = Vec::new();
+    let mut offline = false;
+    let mut failure = None;
+    for (variable, value, _) in variables.iter() {
+        let value = match value {
+            Some(value) => *value,
+            None => continue,
+        };
+        match push_variable(&srv, &charger_id, variable, value, &correlation).await {
+            Ok(server::CallReply::Result(result)) => {
+                let status = result.pointer("/setVariableResult/0/attributeStatus")
+                    .or_else(|| result.get("status"))
+                    .and_then(|status| status.as_str())
+                    .unwrap_or_default();
+                pushed.push(PushedVariable { variable: variable.to_string(), status: status.to_string() });
+                if !variable_accepted(status) {
+                    break;
+                }
+            }
+            Ok(reply) => {
+                failure = Some(Ok(reply));
+                break;
+            }
+            Err(e) if e.status == 503 => {
+                offline = true;
+                break;
+            }
+            Err(e) => {
+                failure = Some(Err(e));
+                break;
+            }
+        }
+    }
+    let all_accepted = pushed.iter().all(|pushed| variable_accepted(&pushed.status));
+    if failure.is_none() && all_accepted {
+        let policy = match srv.send(server::SetHeartbeatPolicy { charger_id, change }).await {
+            Ok(Ok(policy)) => policy,
+            Ok(Err(e)) => return Err(error::Error { message: e, status: 400 }),
+            Err(_) => return Err(error::Error { message: "Unable to change the heartbeat policy".to_string(), status: 500 }),
+        };
+        let status = if offline { StatusCode::ACCEPTED } else { StatusCode::OK };
+        return Ok(HttpResponse::build(status).json(HeartbeatPolicyBody { policy, pushed }));
+    }
+    // the station keeps running with the previous policy
+    for pushed in pushed.iter().filter(|pushed| variable_accepted(&pushed.status)) {
+        if let Some((variable, _, value)) = variables.iter().find(|(variable, _, _)| *variable == pushed.variable) {
+            if let Err(e) = push_variable(&srv, &charger_id, variable, *value, &correlation).await {
+                warn!(station_id = %charger_id, "unable to set {} back to {}: {}", variable, value, e.message);
+            }
+        }
+    }
+    match failure {
+        Some(Ok(reply)) => Ok(reply_response(Action::SetVariables, reply, |_| StatusCode::CONFLICT)),
+        Some(Err(e)) => Err(e),
+        None => Ok(HttpResponse::Conflict().json(HeartbeatPolicyBody { policy: previous, pushed })),
+    }
+}
+
+#[get("/api/stations")]
+async fn stations(srv: web::Data<Addr<server::OcppServer>>,
+                  filter: web::Query<StationFilter>) -> Result<HttpResponse, error::Error> {
+    let filter = filter.into_inner();
+    match srv.send(server::GetStations { status: filter.status, online: filter.online }).await {
+        Ok(stations) => Ok(HttpResponse::Ok().json(stations)),
+        Err(_) => Err(error::Error { message: "Unable to get list of stations".to_string(), status: 500 }),
+    }
+}
+
+#[get("/api/stations/{id}")]
+async fn station(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>) -> Result<HttpResponse, error::Error> {
+    let charger_id = id.into_inner();
+    match srv.send(server::GetStation { charger_id: charger_id.clone() }).await {
+        Ok(Some(station)) => Ok(HttpResponse::Ok().json(station)),
+        Ok(None) => Err(error::Error { message: format!("Unknown charging station {}", charger_id), status: 404 }),
+        Err(_) => Err(error::Error { message: "Unable to get the station".to_string(), status: 500 }),
+    }
+}
+
+#[get("/api/stations/{id}/availability")]
+async fn availability(srv: web::Data<Addr<server::OcppServer>>,
+                      id: web::Path<String>) -> Result<HttpResponse, error::Error> {
+    let charger_id = id.into_inner();
+    match srv.send(server::GetAvailability { charger_id: charger_id.clone() }).await {
+        Ok(Some(availability)) => Ok(HttpResponse::Ok().json(availability)),
+        Ok(None) => Err(error::Error { message: format!("Unknown charging station {}", charger_id), status: 404 }),
+        Err(_) => Err(error::Error { message: "Unable to get the availability of the station".to_string(), status: 500 }),
+    }
+}
+
+#[get("/api/offline-stations")]
+async fn offline_stations(srv: web::Data<Addr<server::OcppServer>>,
+                          query: web::Query<OfflineQuery>) -> Result<HttpResponse, error::Error> {
+    let threshold = query.threshold.map(Duration::from_secs);
+    match srv.send(server::GetOfflineStations { threshold }).await {
+        Ok(offline) => Ok(HttpResponse::Ok().json(offline)),
+        Err(_) => Err(error::Error { message: "Unable to get the offline stations".to_string(), status: 500 }),
+    }
+}
+
+/// Server-Sent Events stream of the frames to and from a station, one `frame` event per frame
+/// with a `traffic::TrafficFrame` as data, e.g. `curl -N https://localhost:8443/api/stations/CS001/traffic`.
+/// The station does not have to be connected yet.
+#[get("/api/stations/{id}/traffic")]
+async fn traffic(srv: web::Data<Addr<server::OcppServer>>, id: web::Path<String>) -> Result<HttpResponse, error::Error> {
+    let frames = match srv.send(server::ListenTraffic { charger_id: id.into_inner() }).await {
+        Ok(frames) => frames,
+        Err(_) => return Err(error::Error { message: "Unable to listen to the station".to_string(), status: 500 }),
+    };
+    let events = frames.map(|frame| {
+        let data = serde_json::to_string(&frame).unwrap();
+        Ok::<_, actix_web::Error>(Bytes::from(format!("event: frame\ndata: {}\n\n", data)))
+    });
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .header("Cache-Control", "no-cache")
+        .streaming(events))
+}
+
+async fn query_journal(srv: &Addr<server::OcppServer>, query: JournalQuery) -> Result<Vec<JournalEntry>, error::Error> {
+    query.validate().map_err(|message| error::Error { message, status: 400 })?;
+    match srv.send(server::QueryJournal(query)).await {
+        Ok(Ok(entries)) => Ok(entries),
+        Ok(Err(e)) => Err(error::Error { message: format!("Unable to read the journal: {}", e), status: 500 }),
+        Err(_) => Err(error::Error { message: "Unable to query the journal".to_string(), status: 500 }),
+    }
+}
+
+#[get("/api/journal")]
+async fn journal(srv: web::Data<Addr<server::OcppServer>>,
+                 query: web::Query<JournalQuery>) -> Result<HttpResponse, error::Error> {
+    let mut query = query.into_inner();
+    query.limit = Some(query.limit.unwrap_or(JOURNAL_QUERY_LIMIT));
+    let entries = query_journal(&srv, query).await?;
+    Ok(HttpResponse::Ok().json(entries))
+}
+
+/// Journal entries as JSON Lines, the same format as the journal file, so an export can be
+/// given to `ocpp-replay`. Unlike GET /api/journal there is no default limit.
+#[get("/api/journal/export")]
+async fn journal_export(srv: web::Data<Addr<server::OcppServer>>,
+                        query: web::Query<JournalQuery>) -> Result<HttpResponse, error::Error> {
+    let entries = query_journal(&srv, query.into_inner()).await?;
+    let mut body = String::new();
+    for entry in entries {
+        body.push_str(&serde_json::to_string(&entry).unwrap());
+        body.push('\n');
+    }
+    Ok(HttpResponse::Ok()
+        .content_type("application/x-ndjson")
+        .header("Content-Disposition", "attachment; filename=\"journal.jsonl\"")
+        .body(body))
+}
+
+/// Prometheus metrics of the server in the text exposition format, see `metrics::Metrics`
+#[get("/metrics")]
+async fn metrics(srv: web::Data<Addr<server::OcppServer>>) -> Result<HttpResponse, error::Error> {
+    match srv.send(server::GetMetrics).await {
+        Ok(metrics) => Ok(HttpResponse::Ok().content_type("text/plain; version=0.0.4").body(metrics)),
+        Err(_) => Err(error::Error { message: "Unable to get the metrics".to_string(), status: 500 }),
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use actix_web::HttpRequest;
+use actix_web::http::header::AUTHORIZATION;
+
+// HTTP basic authentication of the stations, security profiles 1 and 2. A station sends its
+// password with the websocket handshake of /ocpp/{station id}, the user name being its id:
+//   Authorization: Basic base64(<station id>:<password>)
+// The upgrade is refused with 401 when the header is missing, malformed or does not match the
+// password configured in station_passwords. Stations of security profile 3 authenticate with the
+// client certificate the TLS listener checks, they need no password.
+
+/// Passwords the stations authenticate with, none with security profile 3
+#[derive(Clone, Default)]
+pub struct StationAuth {
+    /// key: station id, possibly lowercased by the configuration
+    passwords: Option<HashMap<String, String>>,
+}
+
+/// Value of a base64 digit of the standard alphabet
+fn digit(c: u8) -> Option<u32> {
+    match c {
+        b'A'..=b'Z' => Some((c - b'A') as u32),
+        b'a'..=b'z' => Some((c - b'a') as u32 + 26),
+        b'0'..=b'9' => Some((c - b'0') as u32 + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// Decodes padded base64, the encoding of the basic authentication credentials
+fn decode_base64(encoded: &str) -> Option<Vec<u8>> {
+    let encoded = encoded.as_bytes();
+    if !encoded.len().is_multiple_of(4) {
+        return None;
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() / 4 * 3);
+    for (i, chunk) in encoded.chunks(4).enumerate() {
+        let last = i == encoded.len() / 4 - 1;
+        let padding = chunk.iter().rev().take_while(|c| **c == b'=').count();
+        if padding > 2 || (padding > 0 && !last) {
+            return None;
+        }
+        let mut bits = 0u32;
+        for c in &chunk[..4 - padding] {
+            bits = bits << 6 | digit(*c)?;
+        }
+        bits <<= 6 * padding as u32;
+        decoded.extend_from_slice(&bits.to_be_bytes()[1..4 - padding]);
+    }
+    Some(decoded)
+}
+
+/// Compares in a time that does not depend on where the strings differ
+fn same_secret(a: &str, b: &str) -> bool {
+    a.len() == b.len() && a.bytes().zip(b.bytes()).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+impl StationAuth {
+    /// Basic authentication with these passwords, keyed by station id
+    pub fn basic(passwords: HashMap<String, String>) -> StationAuth {
+        StationAuth { passwords: Some(passwords) }
+    }
+
+    /// Checks the credentials of the handshake of a station
+    pub fn check(&self, station_id: &str, r: &HttpRequest) -> Result<(), String> {
+        let header = r.headers().get(AUTHORIZATION).and_then(|header| header.to_str().ok());
+        self.check_header(station_id, header)
+    }
+
+    fn check_header(&self, station_id: &str, header: Option<&str>) -> Result<(), String> {
+        let passwords = match &self.passwords {
+            Some(passwords) => passwords,
+            None => return Ok(()),
+        };
+        let credentials = header.ok_or_else(|| String::from("no Authorization header"))?;
+        let credentials = credentials.strip_prefix("Basic ")
+            .and_then(|encoded| decode_base64(encoded.trim()))
+            .and_then(|decoded| String::from_utf8(decoded).ok())
+            .ok_or_else(|| String::from("the Authorization header is not basic authentication"))?;
+        let (user, password) = credentials.split_once(':')
+            .ok_or_else(|| String::from("the Authorization header is not basic authentication"))?;
+        if user != station_id {
+            return Err(format!("the user {} is not the station id", user));
+        }
+        let expected = passwords.iter()
+            .find(|(id, _)| id.eq_ignore_ascii_case(station_id))
+            .map(|(_, password)| password)
+            .ok_or_else(|| String::from("the station has no password"))?;
+        if !same_secret(password, expected) {
+            return Err(String::from("wrong password"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_is_decoded() {
+        assert_eq!(decode_base64("Q1MwMDE6c2VjcmV0"), Some(b"CS001:secret".to_vec()));
+        assert_eq!(decode_base64("Q1MwMDE6cGFzcw=="), Some(b"CS001:pass".to_vec()));
+        assert_eq!(decode_base64("Q1MwMDE6cGFzczE="), Some(b"CS001:pass1".to_vec()));
+        assert_eq!(decode_base64(""), Some(Vec::new()));
+        assert_eq!(decode_base64("Q1MwMDE"), None);
+        assert_eq!(decode_base64("Q1M=MDE6"), None);
+        assert_eq!(decode_base64("Q1Mw*DE6"), None);
+    }
+
+    #[test]
+    fn stations_need_their_password() {
+        let mut passwords = HashMap::new();
+        // as read from STATION_PASSWORDS.CS001
+        passwords.insert(String::from("cs001"), String::from("secret"));
+        let auth = StationAuth::basic(passwords);
+        assert_eq!(auth.check_header("CS001", Some("Basic Q1MwMDE6c2VjcmV0")), Ok(()));
+        assert_eq!(auth.check_header("CS001", None), Err(String::from("no Authorization header")));
+        // CS001:pass
+        assert_eq!(auth.check_header("CS001", Some("Basic Q1MwMDE6cGFzcw==")), Err(String::from("wrong password")));
+        // CS002:secret
+        assert!(auth.check_header("CS001", Some("Basic Q1MwMDI6c2VjcmV0")).is_err());
+        assert!(auth.check_header("CS002", Some("Basic Q1MwMDI6c2VjcmV0")).is_err());
+        assert!(auth.check_header("CS001", Some("Bearer Q1MwMDE6c2VjcmV0")).is_err());
+        assert_eq!(StationAuth::default().check_header("CS001", None), Ok(()));
+    }
+}
@@ -0,0 +1,210 @@
+// Replays a journal of the OCPP traffic against a running server, for regression testing: the
+// calls a station sent are sent again, over a new websocket session of that station, and the
+// replies of the server are compared with the replies in the journal.
+//
+//   ocpp-replay journal.jsonl [--url ws://127.0.0.1:8887] [--station CS001] [--protocol ocpp2.0.1]
+//               [--ignore currentTime,interval] [--ca cert.pem] [--cert client.pem --key client.key]
+//               [--password secret]
+//
+// Fields named by --ignore are left out of the comparison wherever they appear in a payload,
+// currentTime by default since it changes with every reply. The exit code is 1 when a reply
+// differs from the journal. --password is sent with basic authentication, the station id being
+// the user name, for servers of security profile 1 or 2.
+
+#[path = "shared/client.rs"]
+mod client;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+use actix_web::rt::time::timeout;
+use awc::ws;
+use futures::{SinkExt, StreamExt};
+use serde_json::Value;
+
+use rusted_ocpp_server::journal::{Journal, JournalEntry, JournalQuery};
+use rusted_ocpp_server::traffic::TrafficDirection;
+
+use client::TlsOptions;
+
+/// How long the server has to reply to a replayed call
+const REPLY_TIMEOUT: Duration = Duration::from_secs(10);
+
+struct Options {
+    journal: PathBuf,
+    url: String,
+    station: Option<String>,
+    protocol: String,
+    ignore: Vec<String>,
+    tls: TlsOptions,
+    /// basic authentication password of the station
+    password: Option<String>,
+}
+
+fn usage() -> ! {
+    eprintln!("usage: ocpp-replay <journal.jsonl> [--url ws://127.0.0.1:8887] [--station id] \
+               [--protocol ocpp2.0.1] [--ignore field,...] [--ca cert.pem] [--cert cert.pem --key key.pem] \
+               [--password secret]");
+    process::exit(2);
+}
+
+fn options() -> Options {
+    let mut args = std::env::args().skip(1);
+    let mut options = Options {
+        journal: PathBuf::new(),
+        url: String::from("ws://127.0.0.1:8887"),
+        station: None,
+        protocol: String::from("ocpp2.0.1"),
+        ignore: vec![String::from("currentTime")],
+        tls: TlsOptions::default(),
+        password: None,
+    };
+    let mut journal = None;
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage());
+        match arg.as_str() {
+            "--url" => options.url = value(),
+            "--station" => options.station = Some(value()),
+            "--protocol" => options.protocol = value(),
+            "--ignore" => options.ignore = value().split(',').map(String::from).collect(),
+            "--password" => options.password = Some(value()),
+            _ if options.tls.parse(&arg, &mut value) => {}
+            _ if arg.starts_with("--") || journal.is_some() => usage(),
+            _ => journal = Some(PathBuf::from(arg)),
+        }
+    }
+    options.journal = journal.unwrap_or_else(|| usage());
+    options
+}
+
+/// Drops the ignored fields wherever they appear
+fn without_ignored(value: &Value, ignore: &[String]) -> Value {
+    match value {
+        Value::Object(fields) => Value::Object(fields.iter()
+            .filter(|(name, _)| !ignore.contains(name))
+            .map(|(name, field)| (name.clone(), without_ignored(field, ignore)))
+            .collect()),
+        Value::Array(items) => Value::Array(items.iter().map(|item| without_ignored(item, ignore)).collect()),
+        _ => value.clone(),
+    }
+}
+
+/// Replays the calls of one station, returns how many replies differ from the journal
+async fn replay_station(options: &Options, station: &str, entries: &[JournalEntry]) -> Result<usize, String> {
+    let client = client::client(&options.tls).map_err(|e| e.to_string())?;
+    let url = format!("{}/ocpp/{}", options.url.trim_end_matches('/'), station);
+    let mut request = client.ws(url.as_str()).protocols(vec![options.protocol.as_str()]);
+    if let Some(password) = &options.password {
+        request = request.basic_auth(station, Some(password));
+    }
+    let (_, mut connection) = request.connect().await
+        .map_err(|e| format!("unable to connect to {}: {}", url, e))?;
+    let recorded_replies: HashMap<&String, &JournalEntry> = entries.iter()
+        .filter(|entry| entry.direction == TrafficDirection::Outbound)
+        .filter(|entry| matches!(entry.message_type_id, Some(3) | Some(4)))
+        .filter_map(|entry| entry.message_id.as_ref().map(|message_id| (message_id, entry)))
+        .collect();
+    let mut differences = 0;
+    let sent_by_station = entries.iter()
+        .filter(|entry| entry.direction == TrafficDirection::Inbound)
+        .filter(|entry| matches!(entry.message_type_id, Some(2) | Some(6)));
+    for entry in sent_by_station {
+        let frame = match entry.frame() {
+            Some(frame) => frame,
+            None => continue,
+        };
+        connection.send(ws::Message::Text(frame)).await.map_err(|e| e.to_string())?;
+        // SEND messages are not answered
+        if entry.message_type_id != Some(2) {
+            continue;
+        }
+        let message_id = entry.message_id.clone().unwrap_or_default();
+        let action = entry.action.clone().unwrap_or_default();
+        let reply = loop {
+            let frame = match timeout(REPLY_TIMEOUT, connection.next()).await {
+                Ok(Some(Ok(frame))) => frame,
+                Ok(Some(Err(e))) => return Err(e.to_string()),
+                Ok(None) => return Err(String::from("the server closed the connection")),
+                Err(_) => break None,
+            };
+            let text = match frame {
+                ws::Frame::Text(text) => text,
+                ws::Frame::Ping(ping) => {
+                    connection.send(ws::Message::Pong(ping)).await.map_err(|e| e.to_string())?;
+                    continue;
+                }
+                ws::Frame::Close(_) => return Err(String::from("the server closed the connection")),
+                _ => continue,
+            };
+            // calls of the server are not part of the replay
+            let fields: Vec<Value> = serde_json::from_slice(&text).unwrap_or_default();
+            let is_reply = matches!(fields.first().and_then(|id| id.as_u64()), Some(3) | Some(4)) &&
+                fields.get(1).and_then(|id| id.as_str()) == Some(message_id.as_str());
+            if is_reply {
+                break Some(fields);
+            }
+        };
+        let expected = recorded_replies.get(&message_id).and_then(|reply| reply.frame())
+            .and_then(|frame| serde_json::from_str::<Vec<Value>>(&frame).ok());
+        match (reply, expected) {
+            (None, _) => {
+                differences += 1;
+                println!("{} {} {}: no reply within {} seconds", station, action, message_id, REPLY_TIMEOUT.as_secs());
+            }
+            (Some(_), None) => println!("{} {} {}: no reply in the journal, skipped", station, action, message_id),
+            (Some(reply), Some(expected)) => {
+                let reply = without_ignored(&Value::Array(reply), &options.ignore);
+                let expected = without_ignored(&Value::Array(expected), &options.ignore);
+                if reply == expected {
+                    println!("{} {} {}: same reply", station, action, message_id);
+                } else {
+                    differences += 1;
+                    println!("{} {} {}: different reply\n  journal: {}\n  server:  {}",
+                             station, action, message_id, expected, reply);
+                }
+            }
+        }
+    }
+    let _ = connection.close().await;
+    Ok(differences)
+}
+
+#[actix_web::main]
+async fn main() {
+    let options = options();
+    let query = JournalQuery { station: options.station.clone(), ..JournalQuery::default() };
+    let entries = match Journal::read(&options.journal, &query) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("unable to read {}: {}", options.journal.display(), e);
+            process::exit(2);
+        }
+    };
+    // stations are replayed one after the other, in the order they first appear in the journal
+    let mut stations: Vec<String> = Vec::new();
+    for entry in &entries {
+        if !stations.contains(&entry.station_id) {
+            stations.push(entry.station_id.clone());
+        }
+    }
+    let mut differences = 0;
+    for station in stations {
+        let station_entries: Vec<JournalEntry> = entries.iter()
+            .filter(|entry| entry.station_id == station)
+            .cloned()
+            .collect();
+        match replay_station(&options, &station, &station_entries).await {
+            Ok(station_differences) => differences += station_differences,
+            Err(e) => {
+                differences += 1;
+                println!("{}: {}", station, e);
+            }
+        }
+    }
+    println!("{} difference(s)", differences);
+    if differences > 0 {
+        process::exit(1);
+    }
+}
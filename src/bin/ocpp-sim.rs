@@ -0,0 +1,579 @@
+// Simulated OCPP 2.0.1 charging stations, to exercise the server without hardware. Every station
+// connects with the ocpp2.0.1 sub-protocol, boots, sends its heartbeats, reports the status of its
+// connectors and runs scripted transactions with meter values. Calls of the CSMS are answered
+// after --delay milliseconds with the configured status:
+//
+//   ocpp-sim [--url ws://127.0.0.1:8887] [--stations 1] [--prefix SIM] [--evses 1]
+//            [--transactions 1] [--duration 60] [--meter-interval 10] [--power 11000] [--id-token TAG001]
+//            [--reset Accepted|Rejected|Scheduled] [--set-variables Accepted|Rejected|RebootRequired]
+//            [--remote-start Accepted|Rejected] [--remote-stop Accepted|Rejected] [--delay 0]
+//            [--ca cert.pem] [--cert client.pem --key client.key] [--password secret]
+//
+// A remote start that is accepted starts a transaction on the requested EVSE, an accepted
+// Reset boots the station again. Other calls of the CSMS are answered with NotImplemented.
+// With --password every station authenticates with basic authentication, as security profiles 1
+// and 2 require, its id being the user name.
+
+#[path = "shared/client.rs"]
+mod client;
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::process;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use actix_web::rt::spawn;
+use actix_web::rt::time::{delay_for, timeout};
+use awc::ws;
+use chrono::{SecondsFormat, Utc};
+use futures::channel::{mpsc, oneshot};
+use futures::future::join_all;
+use futures::{SinkExt, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use rusted_ocpp_server::messages::{requests, responses};
+
+use client::TlsOptions;
+
+/// How long a simulated station waits for the CallResult of the CSMS
+const CALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Status of the replies to the calls of the CSMS, checked against the response enums when the
+/// options are read
+struct Behaviour {
+    reset: String,
+    set_variables: String,
+    remote_start: String,
+    remote_stop: String,
+    delay: Duration,
+}
+
+struct Options {
+    url: String,
+    stations: usize,
+    prefix: String,
+    evses: i64,
+    transactions: usize,
+    duration: Duration,
+    meter_interval: Duration,
+    power: f64,
+    id_token: String,
+    behaviour: Behaviour,
+    tls: TlsOptions,
+    /// basic authentication password of every station
+    password: Option<String>,
+}
+
+fn usage() -> ! {
+    eprintln!("usage: ocpp-sim [--url ws://127.0.0.1:8887] [--stations n] [--prefix SIM] [--evses n] \
+               [--transactions n] [--duration s] [--meter-interval s] [--power W] [--id-token id] \
+               [--reset status] [--set-variables status] [--remote-start status] [--remote-stop status] \
+               [--delay ms] [--ca cert.pem] [--cert cert.pem --key key.pem] [--password secret]");
+    process::exit(2);
+}
+
+/// Value of an enum of the OCPP messages from its name, e.g. "Accepted"
+fn enum_value<T: DeserializeOwned>(name: &str) -> Option<T> {
+    serde_json::from_value(Value::String(name.to_string())).ok()
+}
+
+fn status<T: DeserializeOwned>(name: &str) -> String {
+    match enum_value::<T>(name) {
+        Some(_) => name.to_string(),
+        None => {
+            eprintln!("{} is not a valid status", name);
+            usage();
+        }
+    }
+}
+
+fn number<T: std::str::FromStr>(value: String) -> T {
+    value.parse().unwrap_or_else(|_| usage())
+}
+
+fn options() -> Options {
+    let mut args = std::env::args().skip(1);
+    let mut options = Options {
+        url: String::from("ws://127.0.0.1:8887"),
+        stations: 1,
+        prefix: String::from("SIM"),
+        evses: 1,
+        transactions: 1,
+        duration: Duration::from_secs(60),
+        meter_interval: Duration::from_secs(10),
+        power: 11000.0,
+        id_token: String::from("TAG001"),
+        behaviour: Behaviour {
+            reset: String::from("Accepted"),
+            set_variables: String::from("Accepted"),
+            remote_start: String::from("Accepted"),
+            remote_stop: String::from("Accepted"),
+            delay: Duration::from_millis(0),
+        },
+        tls: TlsOptions::default(),
+        password: None,
+    };
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage());
+        match arg.as_str() {
+            "--url" => options.url = value(),
+            "--stations" => options.stations = number(value()),
+            "--prefix" => options.prefix = value(),
+            "--evses" => options.evses = number(value()),
+            "--transactions" => options.transactions = number(value()),
+            "--duration" => options.duration = Duration::from_secs(number(value())),
+            "--meter-interval" => options.meter_interval = Duration::from_secs(number(value())),
+            "--power" => options.power = number(value()),
+            "--id-token" => options.id_token = value(),
+            "--reset" => options.behaviour.reset = status::<responses::ResetStatusEnumType>(&value()),
+            "--set-variables" => options.behaviour.set_variables =
+                status::<responses::SetVariableStatusEnumType>(&value()),
+            "--remote-start" => options.behaviour.remote_start =
+                status::<responses::RequestStartStopStatusEnumType>(&value()),
+            "--remote-stop" => options.behaviour.remote_stop =
+                status::<responses::RequestStartStopStatusEnumType>(&value()),
+            "--delay" => options.behaviour.delay = Duration::from_millis(number(value())),
+            "--password" => options.password = Some(value()),
+            _ if options.tls.parse(&arg, &mut value) => {}
+            _ => usage(),
+        }
+    }
+    if options.evses < 1 || options.meter_interval.as_secs() == 0 {
+        usage();
+    }
+    options
+}
+
+fn now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, false)
+}
+
+/// Converts between the request and response variants of the same OCPP type, e.g. ComponentType
+fn convert<F: Serialize, T: DeserializeOwned>(value: &F) -> T {
+    serde_json::from_value(serde_json::to_value(value).unwrap()).unwrap()
+}
+
+fn id_token(id_token: &str) -> requests::IdTokenType {
+    requests::IdTokenType {
+        additional_info: None,
+        custom_data: None,
+        id_token: id_token.to_string(),
+        id_token_type_type: requests::IdTokenEnumType::Iso14443,
+    }
+}
+
+/// A transaction running on an EVSE of a simulated station
+struct Transaction {
+    id: String,
+    seq_no: i64,
+    energy: f64, // Wh
+    /// set by RequestStopTransaction, the transaction ends at the next meter value
+    stop: Option<requests::ReasonEnumType>,
+}
+
+/// Transaction started by the script or by a RequestStartTransaction
+struct TransactionStart {
+    evse_id: i64,
+    id_token: requests::IdTokenType,
+    remote_start_id: Option<i64>,
+}
+
+struct Station {
+    id: String,
+    options: Rc<Options>,
+    outgoing: mpsc::UnboundedSender<ws::Message>,
+    pending: RefCell<HashMap<String, oneshot::Sender<Result<Value, String>>>>, // key: MessageId
+    transactions: RefCell<HashMap<i64, Transaction>>, // key: evse id
+    last_transaction: Cell<u64>,
+}
+
+impl Station {
+    fn send(&self, frame: Value) {
+        println!("{}: outgoing message: {}", self.id, frame);
+        let _ = self.outgoing.unbounded_send(ws::Message::Text(frame.to_string()));
+    }
+
+    /// Sends a call to the CSMS and waits for its CallResult
+    async fn call<T: Serialize>(&self, action: &str, request: &T) -> Result<Value, String> {
+        let message_id = Uuid::new_v4().to_string();
+        let (sender, receiver) = oneshot::channel();
+        self.pending.borrow_mut().insert(message_id.clone(), sender);
+        self.send(json!([2, message_id, action, serde_json::to_value(request).unwrap()]));
+        match timeout(CALL_TIMEOUT, receiver).await {
+            Ok(Ok(reply)) => reply,
+            Ok(Err(_)) => Err(String::from("the connection was closed")),
+            Err(_) => {
+                self.pending.borrow_mut().remove(&message_id);
+                Err(format!("no reply to {} within {} seconds", action, CALL_TIMEOUT.as_secs()))
+            }
+        }
+    }
+
+    /// Boots until the CSMS accepts the station, returns the heartbeat interval
+    async fn boot(&self, reason: requests::BootReasonEnumType) -> Result<Duration, String> {
+        let request = requests::BootNotificationRequest {
+            charging_station: requests::ChargingStationType {
+                custom_data: None,
+                firmware_version: Some(String::from(env!("CARGO_PKG_VERSION"))),
+                model: String::from("ocpp-sim"),
+                modem: None,
+                serial_number: Some(self.id.clone()),
+                vendor_name: String::from("rusted_ocpp_server"),
+            },
+            custom_data: None,
+            reason,
+        };
+        loop {
+            let reply = self.call("BootNotification", &request).await?;
+            let response: responses::BootNotificationResponse = serde_json::from_value(reply)
+                .map_err(|e| format!("invalid BootNotification result: {}", e))?;
+            let interval = Duration::from_secs(response.interval.max(10) as u64);
+            if let responses::RegistrationStatusEnumType::Accepted = response.status {
+                return Ok(interval);
+            }
+            println!("{}: boot not accepted, retrying in {} seconds", self.id, interval.as_secs());
+            delay_for(interval).await;
+        }
+    }
+
+    async fn status(&self, evse_id: i64, status: requests::ConnectorStatusEnumType) -> Result<(), String> {
+        self.call("StatusNotification", &requests::StatusNotificationRequest {
+            connector_id: 1,
+            connector_status: status,
+            custom_data: None,
+            evse_id,
+            timestamp: now(),
+        }).await.map(|_| ())
+    }
+
+    async fn heartbeats(self: Rc<Self>, interval: Duration) {
+        loop {
+            delay_for(interval).await;
+            if let Err(e) = self.call("Heartbeat", &requests::HeartbeatRequest { custom_data: None }).await {
+                println!("{}: heartbeats stopped: {}", self.id, e);
+                return;
+            }
+        }
+    }
+
+    /// TransactionEvent of the transaction on an EVSE, with the energy meter as meter value
+    fn transaction_event(&self, evse_id: i64, event_type: requests::TransactionEventEnumType,
+                         trigger_reason: requests::TriggerReasonEnumType,
+                         context: requests::ReadingContextEnumType,
+                         id_token: Option<requests::IdTokenType>, remote_start_id: Option<i64>)
+                         -> Option<requests::TransactionEventRequest> {
+        let mut transactions = self.transactions.borrow_mut();
+        let transaction = transactions.get_mut(&evse_id)?;
+        transaction.seq_no += 1;
+        let ended = matches!(event_type, requests::TransactionEventEnumType::Ended);
+        Some(requests::TransactionEventRequest {
+            cable_max_current: None,
+            custom_data: None,
+            event_type,
+            evse: Some(requests::EvseType { connector_id: Some(1), custom_data: None, id: evse_id }),
+            id_token,
+            meter_value: Some(vec![requests::MeterValueType {
+                custom_data: None,
+                sampled_value: vec![requests::SampledValueType {
+                    context: Some(context),
+                    custom_data: None,
+                    location: None,
+                    measurand: Some(requests::MeasurandEnumType::EnergyActiveImportRegister),
+                    phase: None,
+                    signed_meter_value: None,
+                    unit_of_measure: None,
+                    value: transaction.energy.round(),
+                }],
+                timestamp: now(),
+            }]),
+            number_of_phases_used: None,
+            offline: None,
+            reservation_id: None,
+            seq_no: transaction.seq_no,
+            timestamp: now(),
+            transaction_info: requests::TransactionType {
+                charging_state: Some(if ended {
+                    requests::ChargingStateEnumType::EvConnected
+                } else {
+                    requests::ChargingStateEnumType::Charging
+                }),
+                custom_data: None,
+                remote_start_id,
+                stopped_reason: if ended {
+                    transaction.stop.take().or(Some(requests::ReasonEnumType::Local))
+                } else {
+                    None
+                },
+                time_spent_charging: None,
+                transaction_id: transaction.id.clone(),
+            },
+            trigger_reason,
+        })
+    }
+
+    /// Plugs in, charges for --duration seconds with a meter value every --meter-interval
+    /// seconds, or until the CSMS stops the transaction, and unplugs
+    async fn transaction(self: Rc<Self>, start: TransactionStart) -> Result<(), String> {
+        if self.transactions.borrow().contains_key(&start.evse_id) {
+            return Err(format!("EVSE {} already has a transaction", start.evse_id));
+        }
+        self.last_transaction.set(self.last_transaction.get() + 1);
+        self.transactions.borrow_mut().insert(start.evse_id, Transaction {
+            id: format!("{}-{}", self.id, self.last_transaction.get()),
+            seq_no: -1,
+            energy: 0.0,
+            stop: None,
+        });
+        let result = self.charge(&start).await;
+        self.transactions.borrow_mut().remove(&start.evse_id);
+        result
+    }
+
+    async fn charge(&self, start: &TransactionStart) -> Result<(), String> {
+        use requests::{ReadingContextEnumType as Context, TransactionEventEnumType as Event,
+                       TriggerReasonEnumType as Trigger};
+        self.status(start.evse_id, requests::ConnectorStatusEnumType::Occupied).await?;
+        let trigger = if start.remote_start_id.is_some() { Trigger::RemoteStart } else { Trigger::Authorized };
+        let started = self.transaction_event(start.evse_id, Event::Started, trigger, Context::TransactionBegin,
+                                             Some(id_token(&start.id_token.id_token)), start.remote_start_id)
+            .ok_or("the transaction is gone")?;
+        self.call("TransactionEvent", &started).await?;
+        let began = Instant::now();
+        let mut last_meter_value = began;
+        loop {
+            delay_for(self.options.meter_interval).await;
+            let stopping = self.transactions.borrow().get(&start.evse_id)
+                .is_none_or(|transaction| transaction.stop.is_some());
+            if let Some(transaction) = self.transactions.borrow_mut().get_mut(&start.evse_id) {
+                transaction.energy += self.options.power * last_meter_value.elapsed().as_secs_f64() / 3600.0;
+            }
+            last_meter_value = Instant::now();
+            if stopping || began.elapsed() >= self.options.duration {
+                break;
+            }
+            let updated = self.transaction_event(start.evse_id, Event::Updated, Trigger::MeterValuePeriodic,
+                                                 Context::SamplePeriodic, None, None)
+                .ok_or("the transaction is gone")?;
+            self.call("TransactionEvent", &updated).await?;
+        }
+        let trigger = match self.transactions.borrow().get(&start.evse_id).and_then(|transaction| transaction.stop.as_ref()) {
+            Some(requests::ReasonEnumType::ImmediateReset) => Trigger::ResetCommand,
+            Some(_) => Trigger::RemoteStop,
+            None => Trigger::StopAuthorized,
+        };
+        let ended = self.transaction_event(start.evse_id, Event::Ended, trigger, Context::TransactionEnd,
+                                           None, None)
+            .ok_or("the transaction is gone")?;
+        self.call("TransactionEvent", &ended).await?;
+        self.status(start.evse_id, requests::ConnectorStatusEnumType::Available).await
+    }
+
+    /// Reports every connector as available
+    async fn report_connectors(&self) -> Result<(), String> {
+        for evse_id in 1..=self.options.evses {
+            self.status(evse_id, requests::ConnectorStatusEnumType::Available).await?;
+        }
+        Ok(())
+    }
+
+    /// Answers a call of the CSMS after the configured delay
+    async fn answer(self: Rc<Self>, message_id: String, action: String, payload: Value) {
+        let behaviour = &self.options.behaviour;
+        delay_for(behaviour.delay).await;
+        let reply = match action.as_str() {
+            "Reset" => serde_json::to_value(responses::ResetResponse {
+                custom_data: None,
+                status: enum_value(&behaviour.reset).unwrap(),
+                status_info: None,
+            }),
+            "SetVariables" => match serde_json::from_value::<requests::SetVariablesRequest>(payload.clone()) {
+                Ok(request) => serde_json::to_value(responses::SetVariablesResponse {
+                    custom_data: None,
+                    set_variable_result: request.set_variable_data.iter()
+                        .map(|data| responses::SetVariableResultType {
+                            attribute_status: enum_value(&behaviour.set_variables).unwrap(),
+                            attribute_status_info: None,
+                            attribute_type: data.attribute_type.as_ref().map(convert),
+                            component: convert(&data.component),
+                            custom_data: None,
+                            variable: convert(&data.variable),
+                        })
+                        .collect(),
+                }),
+                Err(e) => return self.send(json!([4, message_id, "FormatViolation", e.to_string(), {}])),
+            },
+            "RequestStartTransaction" => serde_json::to_value(responses::RequestStartTransactionResponse {
+                custom_data: None,
+                status: enum_value(&behaviour.remote_start).unwrap(),
+                status_info: None,
+                transaction_id: None,
+            }),
+            "RequestStopTransaction" => {
+                let known = payload["transactionId"].as_str().is_some_and(|transaction_id| {
+                    self.transactions.borrow().values().any(|transaction| transaction.id == transaction_id)
+                });
+                let status = if known { behaviour.remote_stop.as_str() } else { "Rejected" };
+                serde_json::to_value(responses::RequestStopTransactionResponse {
+                    custom_data: None,
+                    status: enum_value(status).unwrap(),
+                    status_info: None,
+                })
+            }
+            _ => return self.send(json!([4, message_id, "NotImplemented",
+                                         format!("ocpp-sim does not answer {}", action), {}])),
+        };
+        self.send(json!([3, message_id, reply.unwrap()]));
+        self.after_answer(&action, payload).await;
+    }
+
+    /// What the station does once it accepted a call of the CSMS
+    async fn after_answer(self: Rc<Self>, action: &str, payload: Value) {
+        let behaviour = &self.options.behaviour;
+        match action {
+            "Reset" if behaviour.reset == "Accepted" => {
+                for transaction in self.transactions.borrow_mut().values_mut() {
+                    transaction.stop = Some(requests::ReasonEnumType::ImmediateReset);
+                }
+                let booted = match self.boot(requests::BootReasonEnumType::RemoteReset).await {
+                    Ok(_) => self.report_connectors().await,
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = booted {
+                    println!("{}: reset failed: {}", self.id, e);
+                }
+            }
+            "RequestStartTransaction" if behaviour.remote_start == "Accepted" => {
+                let request: requests::RequestStartTransactionRequest = match serde_json::from_value(payload) {
+                    Ok(request) => request,
+                    Err(_) => return,
+                };
+                let start = TransactionStart {
+                    evse_id: request.evse_id.unwrap_or(1),
+                    id_token: request.id_token,
+                    remote_start_id: Some(request.remote_start_id),
+                };
+                if let Err(e) = self.clone().transaction(start).await {
+                    println!("{}: remote start failed: {}", self.id, e);
+                }
+            }
+            "RequestStopTransaction" if behaviour.remote_stop == "Accepted" => {
+                let transaction_id = payload["transactionId"].as_str().unwrap_or_default();
+                for transaction in self.transactions.borrow_mut().values_mut() {
+                    if transaction.id == transaction_id {
+                        transaction.stop = Some(requests::ReasonEnumType::Remote);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Passes CallResults and CallErrors to the waiting calls and answers the calls of the CSMS
+    fn receive(self: &Rc<Self>, text: &[u8]) {
+        let fields: Vec<Value> = match serde_json::from_slice(text) {
+            Ok(fields) => fields,
+            Err(_) => return,
+        };
+        println!("{}: incoming message: {}", self.id, Value::Array(fields.clone()));
+        let message_id = fields.get(1).and_then(|id| id.as_str()).unwrap_or_default().to_string();
+        match fields.first().and_then(|id| id.as_u64()) {
+            Some(2) => {
+                let action = fields.get(2).and_then(|action| action.as_str()).unwrap_or_default().to_string();
+                let payload = fields.get(3).cloned().unwrap_or(Value::Null);
+                spawn(self.clone().answer(message_id, action, payload));
+            }
+            Some(3) => {
+                if let Some(sender) = self.pending.borrow_mut().remove(&message_id) {
+                    let _ = sender.send(Ok(fields.get(2).cloned().unwrap_or(Value::Null)));
+                }
+            }
+            Some(4) => {
+                if let Some(sender) = self.pending.borrow_mut().remove(&message_id) {
+                    let _ = sender.send(Err(format!("CallError {} {}", fields.get(2).unwrap_or(&Value::Null),
+                                                    fields.get(3).unwrap_or(&Value::Null))));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Connects a station and runs its script: boot, connector status, heartbeats and transactions
+async fn run_station(options: Rc<Options>, id: String) -> Result<(), String> {
+    let client = client::client(&options.tls).map_err(|e| e.to_string())?;
+    let url = format!("{}/ocpp/{}", options.url.trim_end_matches('/'), id);
+    let mut request = client.ws(url.as_str()).protocols(vec!["ocpp2.0.1"]);
+    if let Some(password) = &options.password {
+        request = request.basic_auth(&id, Some(password));
+    }
+    let (_, connection) = request.connect().await
+        .map_err(|e| format!("unable to connect to {}: {}", url, e))?;
+    let (mut sink, mut stream) = connection.split();
+    let (outgoing, mut frames) = mpsc::unbounded();
+    spawn(async move {
+        while let Some(frame) = frames.next().await {
+            if sink.send(frame).await.is_err() {
+                break;
+            }
+        }
+    });
+    let station = Rc::new(Station {
+        id,
+        options: options.clone(),
+        outgoing,
+        pending: RefCell::new(HashMap::new()),
+        transactions: RefCell::new(HashMap::new()),
+        last_transaction: Cell::new(0),
+    });
+    let reader = station.clone();
+    spawn(async move {
+        while let Some(Ok(frame)) = stream.next().await {
+            match frame {
+                ws::Frame::Text(text) => reader.receive(&text),
+                ws::Frame::Ping(ping) => { let _ = reader.outgoing.unbounded_send(ws::Message::Pong(ping)); }
+                ws::Frame::Close(_) => break,
+                _ => {}
+            }
+        }
+        println!("{}: connection closed", reader.id);
+        // stops the writer and fails the calls waiting for a reply
+        reader.outgoing.close_channel();
+        reader.pending.borrow_mut().clear();
+    });
+    let interval = station.boot(requests::BootReasonEnumType::PowerUp).await?;
+    station.report_connectors().await?;
+    spawn(station.clone().heartbeats(interval));
+    // the scripted transactions take turns on the EVSEs
+    for number in 0..options.transactions {
+        let start = TransactionStart {
+            evse_id: (number as i64) % options.evses + 1,
+            id_token: id_token(&options.id_token),
+            remote_start_id: None,
+        };
+        station.clone().transaction(start).await?;
+    }
+    // keeps answering the CSMS until the connection is closed
+    while !station.outgoing.is_closed() {
+        delay_for(Duration::from_secs(1)).await;
+    }
+    Ok(())
+}
+
+#[actix_web::main]
+async fn main() {
+    let options = Rc::new(options());
+    let stations = (1..=options.stations).map(|number| {
+        let options = options.clone();
+        let id = format!("{}{:03}", options.prefix, number);
+        async move {
+            if let Err(e) = run_station(options, id.clone()).await {
+                println!("{}: {}", id, e);
+            }
+        }
+    });
+    join_all(stations).await;
+}
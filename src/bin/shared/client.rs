@@ -0,0 +1,56 @@
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use rustls::internal::pemfile::{certs, pkcs8_private_keys};
+
+// HTTP client of the command line tools (ocpp-replay, ocpp-sim) that connect to the server as
+// charging stations, over ws:// or over wss:// with an optional CA and client certificate.
+
+/// Certificates of a wss:// connection, from the --ca, --cert and --key options
+#[derive(Default)]
+pub struct TlsOptions {
+    pub ca: Option<PathBuf>,
+    pub cert: Option<PathBuf>,
+    pub key: Option<PathBuf>,
+}
+
+impl TlsOptions {
+    /// Takes --ca, --cert and --key, returns false for any other option
+    pub fn parse(&mut self, option: &str, value: impl FnOnce() -> String) -> bool {
+        match option {
+            "--ca" => self.ca = Some(PathBuf::from(value())),
+            "--cert" => self.cert = Some(PathBuf::from(value())),
+            "--key" => self.key = Some(PathBuf::from(value())),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// HTTP client trusting the --ca certificate and presenting the --cert client certificate
+pub fn client(options: &TlsOptions) -> io::Result<awc::Client> {
+    if options.ca.is_none() && options.cert.is_none() {
+        return Ok(awc::Client::default());
+    }
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+    let mut config = rustls::ClientConfig::new();
+    if let Some(ca) = &options.ca {
+        config.root_store.add_pem_file(&mut BufReader::new(File::open(ca)?))
+            .map_err(|_| invalid("unable to read the CA certificate"))?;
+    }
+    if let (Some(cert), Some(key)) = (&options.cert, &options.key) {
+        let cert_chain = certs(&mut BufReader::new(File::open(cert)?))
+            .map_err(|_| invalid("unable to read the client certificate"))?;
+        let mut keys = pkcs8_private_keys(&mut BufReader::new(File::open(key)?))
+            .map_err(|_| invalid("unable to read the client key"))?;
+        if keys.is_empty() {
+            return Err(invalid("no PKCS8 key in the client key file"));
+        }
+        config.set_single_client_cert(cert_chain, keys.remove(0))
+            .map_err(|e| invalid(&e.to_string()))?;
+    }
+    let connector = awc::Connector::new().rustls(Arc::new(config)).finish();
+    Ok(awc::Client::builder().connector(connector).finish())
+}
@@ -1,26 +1,55 @@
 use actix::prelude::*;
 use actix_web_actors::ws;
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Instant;
+use chrono::{Utc, SecondsFormat};
+use serde::Serialize;
+use serde_json::Value;
 use crate::messages::*;
 use crate::{server, messages};
 use actix_web_actors::ws::{ProtocolError};
 use crate::server::MessageFromChargeStation;
+use crate::schemas::SchemaValidator;
+use crate::messages::actions::Action;
+use crate::traffic::TrafficDirection;
+use crate::services::{self, HeartbeatPolicy, StationPeer};
+use tracing::{debug, info, warn};
 
 pub struct ChargeStationWebSocketSession {
-    /// Client must send ping at least once per 10 seconds (CLIENT_TIMEOUT)
-    /// otherwise the connection will b e dropped
+    /// Client must answer a ping, or send any frame, within the pong timeout of its heartbeat
+    /// policy, otherwise the connection will be dropped
     pub hb: Instant,
     pub name: String,
+    /// id the ocpp server gave to this session, 0 until the session is registered
+    pub session_id: u64,
+    /// ping interval, pong timeout and Heartbeat interval of the station, from the ocpp server
+    pub heartbeat: HeartbeatPolicy,
+    /// the interval that pings the station
+    pub ping: Option<SpawnHandle>,
+    /// why the session stops, reported to the ocpp server for the connectivity history
+    pub close_reason: Option<String>,
+    /// span of the log events of the session, with the station id and the session id
+    pub span: tracing::Span,
+    /// address and certificate of the station, behind trusted proxies the ones they pass on
+    pub peer: StationPeer,
     pub address: Addr<server::OcppServer>,
+    /// OCPP version negotiated during the websocket handshake
+    pub version: OcppVersion,
+    pub schemas: Arc<SchemaValidator>,
+}
+
+/// Name of an enum value in OCPP messages, e.g. "SuspendedEVSE"
+fn enum_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value).ok()
+        .and_then(|value| value.as_str().map(String::from))
+        .unwrap_or_default()
 }
 
 impl Actor for ChargeStationWebSocketSession {
     type Context = ws::WebsocketContext<Self>;
     /// Method is called on actor start. We register websocket session with charge point
     fn started(&mut self, ctx: &mut Self::Context) {
-        // we start heartbeat process on session start
-        self.hb(ctx);
-
         // register self in ocpp server. `AsyncContext::wait` register
         // future within context, but context waits until this future resolves
         // before processing any other events.
@@ -28,12 +57,22 @@ impl Actor for ChargeStationWebSocketSession {
         // across all routes within application
         let addr = ctx.address();
         self.address.send(server::ConnectCharger {
-            addr: addr.recipient(),
+            addr: addr.clone().recipient(),
+            close: addr.clone().recipient(),
+            heartbeat: addr.recipient(),
             serial_id: self.name.clone(),
+            version: self.version,
+            peer: self.peer.clone(),
         }).into_actor(self)
             .then(|res, act, ctx| {
                 match res {
-                    Ok(res) => act.name = res,
+                    Ok(registration) => {
+                        act.session_id = registration.session_id;
+                        act.span.record("session_id", registration.session_id);
+                        act.heartbeat = registration.heartbeat;
+                        // the heartbeat process starts once the policy of the station is known
+                        act.hb(ctx);
+                    }
                     // something is wrong with the ocpp server
                     _ => ctx.stop(),
                 }
@@ -42,22 +81,30 @@ impl Actor for ChargeStationWebSocketSession {
     }
 
     fn stopping(&mut self, _: &mut Self::Context) -> Running {
-        self.address.do_send(server::DisconnectCharger { serial_id: self.name.clone() });
+        self.address.do_send(server::DisconnectCharger {
+            serial_id: self.name.clone(),
+            session_id: self.session_id,
+            reason: self.close_reason.take().unwrap_or_else(|| String::from("connection lost")),
+        });
         Running::Stop
     }
 }
 
 impl ChargeStationWebSocketSession {
-    /// helper method that sends ping to client every second.
-    /// also this method checks heartbeats from client
-    fn hb(&self, ctx: &mut <Self as Actor>::Context) {
-        ctx.run_interval(HEARTBEAT_INTERVAL, |act, ctx| {
+    /// helper method that pings the client every ping interval of its heartbeat policy, and
+    /// drops it when it did not answer within the pong timeout. Restarts the pings when the
+    /// policy changed.
+    fn hb(&mut self, ctx: &mut <Self as Actor>::Context) {
+        if let Some(ping) = self.ping.take() {
+            ctx.cancel_future(ping);
+        }
+        self.ping = Some(ctx.run_interval(self.heartbeat.ping_interval(), |act, ctx| {
             // check client heartbeats
-            if Instant::now().duration_since(act.hb) > CLIENT_TIMEOUT {
+            if Instant::now().duration_since(act.hb) > act.heartbeat.pong_timeout() {
                 // heartbeat timed out
-                println!("Websocket Client heartbeat failed, disconnecting!");
-                act.address.do_send(server::DisconnectCharger { serial_id: act.name.clone() });
-                // stop actor
+                warn!(parent: &act.span, "no pong within {} seconds, disconnecting", act.heartbeat.pong_timeout);
+                act.close_reason = Some(format!("no pong within {} seconds", act.heartbeat.pong_timeout));
+                // stop actor, `stopping` tells the ocpp server
                 ctx.stop();
 
                 // don't try to send a ping
@@ -65,28 +112,389 @@ impl ChargeStationWebSocketSession {
             }
 
             ctx.ping(b"");
+        }));
+    }
+
+    /// sends a frame to the station, the traffic tap of the ocpp server gets a copy
+    fn send_frame(&self, ctx: &mut <Self as Actor>::Context, frame: String) {
+        debug!(frame = %frame, "outgoing frame");
+        self.address.do_send(server::FrameTapped {
+            charger_id: self.name.clone(),
+            direction: TrafficDirection::Outbound,
+            frame: frame.clone(),
+        });
+        ctx.text(frame);
+    }
+
+    /// checks a call of an OCPP 2.0.1 station against its JSON schema, returns the CallError to
+    /// send back when the payload violates it
+    fn schema_violation(&self, action: &str, unpacked: &HashMap<&str, String>) -> Option<String> {
+        if self.version != OcppVersion::V201 {
+            return None;
+        }
+        let message_id = unpacked.get("MessageId").unwrap();
+        let payload: serde_json::Value = match serde_json::from_str(unpacked.get("Payload").unwrap()) {
+            Ok(payload) => payload,
+            Err(e) => return Some(wrap_call_error_result(message_id, ErrorCode::FormatViolation,
+                                                         &format!("\"{}\"", e))),
+        };
+        self.schemas.validate_request(action, &payload).err().map(|violation| {
+            wrap_call_error_result(message_id, violation.error_code, &violation.details.to_string())
+        })
+    }
+
+    /// handles OCPP 2.0.1 calls initiated by the charging station
+    fn handle_call(&mut self, action: &str, unpacked: &HashMap<&str, String>, ctx: &mut <Self as Actor>::Context) {
+        let action = match Action::from_name(action) {
+            Some(action) if action.direction().is_sent_by_charging_station() => action,
+            Some(_) => {
+                let response = wrap_call_error_result(
+                    unpacked.get("MessageId").unwrap(),
+                    ErrorCode::NotSupported,
+                    &format!("\"{} is not sent by a charging station\"", action));
+                self.send_frame(ctx, response);
+                return;
+            }
+            None => {
+                let response = wrap_call_error_result(
+                    unpacked.get("MessageId").unwrap(),
+                    ErrorCode::NotImplemented,
+                    &format!("\"{} is not an OCPP 2.0.1 action\"", action));
+                self.send_frame(ctx, response);
+                return;
+            }
+        };
+        match action {
+            Action::BootNotification => {
+                if let Ok(request) = serde_json::from_str(unpacked.get("Payload").unwrap()) {
+                    self.report_boot_notification(&request);
+                }
+                let response = boot_notification_response(
+                    unpacked.get("MessageId").unwrap(),
+                    unpacked.get("Payload").unwrap(),
+                    self.heartbeat.heartbeat_interval as i64);
+                self.send_frame(ctx, response);
+            },
+            Action::StatusNotification => {
+                if let Ok(request) = serde_json::from_str(unpacked.get("Payload").unwrap()) {
+                    self.report_status_notification(&request);
+                }
+                let response = status_notification_response(
+                    unpacked.get("MessageId").unwrap(),
+                    unpacked.get("Payload").unwrap());
+                self.send_frame(ctx, response);
+            },
+            Action::Heartbeat => {
+                self.address.do_send(server::HeartbeatReceived { charger_id: self.name.clone() });
+                let response = heartbeat_response(
+                    unpacked.get("MessageId").unwrap());
+                self.send_frame(ctx, response);
+            },
+            Action::Authorize => {
+                let response = authorize_response(
+                    unpacked.get("MessageId").unwrap(),
+                    unpacked.get("Payload").unwrap());
+                self.send_frame(ctx, response);
+            },
+            Action::NotifyEvent => {
+                let response = notify_event_response(
+                    unpacked.get("MessageId").unwrap(),
+                    unpacked.get("Payload").unwrap());
+                self.send_frame(ctx, response);
+            },
+            Action::NotifyReport => {
+                let response = notify_report_response(
+                    unpacked.get("MessageId").unwrap(),
+                    unpacked.get("Payload").unwrap());
+                self.send_frame(ctx, response);
+            },
+            Action::GetCertificateStatus => {
+                let response = get_certificate_status_response(
+                    unpacked.get("MessageId").unwrap(),
+                    unpacked.get("Payload").unwrap());
+                self.send_frame(ctx, response);
+            },
+            Action::TransactionEvent => {
+                let request: Result<messages::requests::TransactionEventRequest, serde_json::Error> =
+                    serde_json::from_str(unpacked.get("Payload").unwrap());
+                if let Ok(request) = request {
+                    self.report_transaction_event(&request);
+                }
+                let response = transaction_event_response(
+                    unpacked.get("MessageId").unwrap(),
+                    unpacked.get("Payload").unwrap(),
+                    messages::responses::TransactionEventResponse {
+                        charging_priority: None,
+                        custom_data: None,
+                        id_token_info: None,
+                        total_cost: None,
+                        updated_personal_message: None,
+                    },
+                );
+                self.send_frame(ctx, response);
+            }
+            _ => {
+                let response =
+                    wrap_call_error_result(
+                        unpacked.get("MessageId").unwrap(),
+                        ErrorCode::NotSupported,
+                        &String::from(
+                            "\"Not all messages are implemented yet. \
+                            Ocpp server is still in development\""));
+                self.send_frame(ctx, response);
+            }
+        }
+    }
+
+    /// passes the identity of a booting 2.0.1 station to the station model
+    fn report_boot_notification(&self, request: &messages::requests::BootNotificationRequest) {
+        self.address.do_send(server::StationBooted {
+            charger_id: self.name.clone(),
+            vendor: request.charging_station.vendor_name.clone(),
+            model: request.charging_station.model.clone(),
+            serial_number: request.charging_station.serial_number.clone(),
+            firmware_version: request.charging_station.firmware_version.clone(),
+            reason: Some(enum_name(&request.reason)),
+        });
+    }
+
+    /// passes the status of a 2.0.1 connector to the station model
+    fn report_status_notification(&self, request: &messages::requests::StatusNotificationRequest) {
+        self.address.do_send(server::ConnectorStatusChanged {
+            charger_id: self.name.clone(),
+            evse_id: request.evse_id,
+            connector_id: request.connector_id,
+            status: enum_name(&request.connector_status),
+            timestamp: request.timestamp.clone(),
+        });
+    }
+
+    /// passes a reading of the energy register of a transaction to the ocpp server, which counts
+    /// the energy delivered
+    fn report_meter_reading(&self, transaction_id: &str, energy_wh: f64) {
+        self.address.do_send(server::MeterReading {
+            charger_id: self.name.clone(),
+            transaction_id: transaction_id.to_string(),
+            energy_wh,
         });
     }
+
+    /// passes the start and the end of a 2.0.1 transaction to the transaction service, together
+    /// with the remoteStartId of transactions started by RequestStartTransaction
+    fn report_transaction_event(&self, request: &messages::requests::TransactionEventRequest) {
+        // the reading of an Ended event comes before the stop, which forgets the readings
+        let energy_wh = request.meter_value.as_ref()
+            .and_then(|meter_value| serde_json::to_value(meter_value).ok())
+            .and_then(|meter_value| services::energy_register_wh(&meter_value));
+        if let Some(energy_wh) = energy_wh {
+            self.report_meter_reading(&request.transaction_info.transaction_id, energy_wh);
+        }
+        match request.event_type {
+            messages::requests::TransactionEventEnumType::Started => {
+                self.address.do_send(server::StartTransaction {
+                    charger_id: self.name.clone(),
+                    transaction_id: Some(request.transaction_info.transaction_id.clone()),
+                    evse_id: request.evse.as_ref().map(|evse| evse.id),
+                    id_token: request.id_token.as_ref().map(|id_token| id_token.id_token.clone()),
+                    timestamp: request.timestamp.clone(),
+                });
+            }
+            messages::requests::TransactionEventEnumType::Ended => {
+                self.address.do_send(server::StopTransaction {
+                    charger_id: self.name.clone(),
+                    transaction_id: request.transaction_info.transaction_id.clone(),
+                    timestamp: request.timestamp.clone(),
+                    reason: request.transaction_info.stopped_reason.as_ref().map(|reason| format!("{:?}", reason)),
+                });
+            }
+            messages::requests::TransactionEventEnumType::Updated => {}
+        }
+        if let Some(remote_start_id) = request.transaction_info.remote_start_id {
+            self.address.do_send(server::LinkRemoteStart {
+                charger_id: self.name.clone(),
+                transaction_id: request.transaction_info.transaction_id.clone(),
+                remote_start_id,
+            });
+        }
+    }
+
+    /// handles OCPP 1.6 calls initiated by the charge point
+    fn handle_v16_call(&mut self, action: &str, unpacked: &HashMap<&str, String>, ctx: &mut <Self as Actor>::Context) {
+        let message_id = unpacked.get("MessageId").unwrap();
+        let payload = unpacked.get("Payload").unwrap();
+        let response = match action {
+            "BootNotification" => {
+                if let Ok(request) = serde_json::from_str::<v16::requests::BootNotificationRequest>(payload) {
+                    self.address.do_send(server::StationBooted {
+                        charger_id: self.name.clone(),
+                        vendor: request.charge_point_vendor,
+                        model: request.charge_point_model,
+                        serial_number: request.charge_point_serial_number,
+                        firmware_version: request.firmware_version,
+                        reason: None,
+                    });
+                }
+                v16::boot_notification_response(message_id, payload, self.heartbeat.heartbeat_interval as i64)
+            }
+            "Heartbeat" => {
+                self.address.do_send(server::HeartbeatReceived { charger_id: self.name.clone() });
+                v16::heartbeat_response(message_id)
+            }
+            "Authorize" => v16::authorize_response(message_id, payload),
+            "StatusNotification" => {
+                // connector 0 stands for the whole charge point, it has no EVSE in the station model
+                match serde_json::from_str::<v16::requests::StatusNotificationRequest>(payload) {
+                    Ok(request) if request.connector_id > 0 => {
+                        self.address.do_send(server::ConnectorStatusChanged {
+                            charger_id: self.name.clone(),
+                            evse_id: request.connector_id,
+                            connector_id: 1,
+                            status: enum_name(&request.status),
+                            timestamp: request.timestamp.clone().unwrap_or_else(|| {
+                                Utc::now().to_rfc3339_opts(SecondsFormat::Millis, false)
+                            }),
+                        });
+                    }
+                    _ => {}
+                }
+                v16::status_notification_response(message_id, payload)
+            }
+            "MeterValues" => {
+                // only the samples of a transaction are energy delivered to an EV
+                if let Ok(request) = serde_json::from_str::<Value>(payload) {
+                    let transaction_id = request.get("transactionId").and_then(|id| id.as_i64());
+                    let energy_wh = services::energy_register_wh(&request["meterValue"]);
+                    if let (Some(transaction_id), Some(energy_wh)) = (transaction_id, energy_wh) {
+                        self.report_meter_reading(&transaction_id.to_string(), energy_wh);
+                    }
+                }
+                v16::meter_values_response(message_id, payload)
+            }
+            "DataTransfer" => v16::data_transfer_response(message_id, payload),
+            "DiagnosticsStatusNotification" => v16::diagnostics_status_notification_response(message_id, payload),
+            "FirmwareStatusNotification" => v16::firmware_status_notification_response(message_id, payload),
+            "StartTransaction" => {
+                match serde_json::from_str(payload) as Result<v16::requests::StartTransactionRequest, serde_json::Error> {
+                    Ok(request) => {
+                        // the transaction id comes from the transaction service, so the
+                        // response can only be sent once the ocpp server has answered
+                        let message_id = message_id.clone();
+                        self.address.send(server::StartTransaction {
+                            charger_id: self.name.clone(),
+                            transaction_id: None,
+                            evse_id: Some(request.connector_id),
+                            id_token: Some(request.id_tag.clone()),
+                            timestamp: request.timestamp.clone(),
+                        }).into_actor(self)
+                            .then(move |res, act, ctx| {
+                                let response = match res {
+                                    Ok(transaction_id) => {
+                                        act.report_meter_reading(&transaction_id, request.meter_start as f64);
+                                        v16::start_transaction_response(&message_id, &request, &transaction_id)
+                                    }
+                                    Err(e) => wrap_call_error_result(&message_id, ErrorCode::InternalError,
+                                                                     &format!("\"{}\"", e)),
+                                };
+                                act.send_frame(ctx, response);
+                                fut::ready(())
+                            }).wait(ctx);
+                        return;
+                    }
+                    Err(e) => wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+                }
+            }
+            "StopTransaction" => {
+                match serde_json::from_str(payload) as Result<v16::requests::StopTransactionRequest, serde_json::Error> {
+                    Ok(request) => {
+                        self.report_meter_reading(&request.transaction_id.to_string(), request.meter_stop as f64);
+                        self.address.do_send(server::StopTransaction {
+                            charger_id: self.name.clone(),
+                            transaction_id: request.transaction_id.to_string(),
+                            timestamp: request.timestamp.clone(),
+                            reason: request.reason.as_ref().map(|reason| format!("{:?}", reason)),
+                        });
+                        v16::stop_transaction_response(message_id, &request)
+                    }
+                    Err(e) => wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+                }
+            }
+            _ => {
+                wrap_call_error_result(message_id, ErrorCode::NotImplemented,
+                                       &String::from("\"Not all messages are implemented yet. \
+                                       Ocpp server is still in development\""))
+            }
+        };
+        self.send_frame(ctx, response);
+    }
+
+    /// handles OCPP 2.1 calls initiated by the charging station. Calls that already exist in
+    /// 2.0.1 are passed on to the 2.0.1 handlers.
+    fn handle_v21_call(&mut self, action: &str, unpacked: &HashMap<&str, String>, ctx: &mut <Self as Actor>::Context) {
+        let message_id = unpacked.get("MessageId").unwrap();
+        let payload = unpacked.get("Payload").unwrap();
+        let response = match action {
+            "BatterySwap" => v21::battery_swap_response(message_id, payload),
+            "ClosePeriodicEventStream" => v21::close_periodic_event_stream_response(message_id, payload),
+            "NotifyDERAlarm" => v21::notify_der_alarm_response(message_id, payload),
+            "NotifyDERStartStop" => v21::notify_der_start_stop_response(message_id, payload),
+            "NotifyPriorityCharging" => v21::notify_priority_charging_response(message_id, payload),
+            "NotifyQRCodeScanned" => v21::notify_qr_code_scanned_response(message_id, payload),
+            "NotifySettlement" => v21::notify_settlement_response(message_id, payload),
+            "OpenPeriodicEventStream" => v21::open_periodic_event_stream_response(message_id, payload),
+            "PullDynamicScheduleUpdate" => v21::pull_dynamic_schedule_update_response(message_id, payload),
+            "ReportDERControl" => v21::report_der_control_response(message_id, payload),
+            "VatNumberValidation" => v21::vat_number_validation_response(message_id, payload),
+            _ => return self.handle_call(action, unpacked, ctx),
+        };
+        self.send_frame(ctx, response);
+    }
 }
 
 impl Handler<server::MessageToChargeStation> for ChargeStationWebSocketSession {
     type Result = ();
 
     fn handle(&mut self, msg: server::MessageToChargeStation, ctx: &mut Self::Context) -> Self::Result {
-        ctx.text(msg.0);
+        let _station = self.span.enter();
+        self.send_frame(ctx, msg.0);
+    }
+}
+
+impl Handler<server::HeartbeatPolicyChanged> for ChargeStationWebSocketSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: server::HeartbeatPolicyChanged, ctx: &mut Self::Context) -> Self::Result {
+        self.heartbeat = msg.0;
+        self.hb(ctx);
+    }
+}
+
+impl Handler<server::CloseChargerSession> for ChargeStationWebSocketSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: server::CloseChargerSession, ctx: &mut Self::Context) -> Self::Result {
+        info!(parent: &self.span, reason = %msg.reason, "closing the session");
+        self.close_reason = Some(msg.reason.clone());
+        ctx.close(Some(ws::CloseReason {
+            code: ws::CloseCode::Policy,
+            description: Some(msg.reason),
+        }));
+        ctx.stop();
     }
 }
 
 impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for ChargeStationWebSocketSession {
     fn handle(&mut self, msg: Result<ws::Message, ProtocolError>, ctx: &mut Self::Context) {
+        let station = self.span.clone();
+        let _station = station.enter();
         let msg = match msg {
-            Err(_) => {
+            Err(e) => {
+                self.close_reason = Some(format!("websocket protocol error: {}", e));
                 ctx.stop();
                 return;
             }
             Ok(msg) => msg
         };
-        println!("{}: incoming message: {:?}", self.name, msg);
+        debug!(frame = ?msg, "incoming frame");
         match msg {
             ws::Message::Ping(msg) => {
                 self.hb = Instant::now();
@@ -96,95 +504,69 @@ impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for ChargeStationWebS
                 self.hb = Instant::now();
             }
             ws::Message::Text(text) => {
+                self.address.do_send(server::FrameTapped {
+                    charger_id: self.name.clone(),
+                    direction: TrafficDirection::Inbound,
+                    frame: text.to_string(),
+                });
                 match unpack_ocpp_message(&text) {
                     Ok(unpacked) => {
+                        let message = tracing::info_span!("message",
+                            message_id = unpacked.get("MessageId").map_or("", |id| id.trim_matches('"')),
+                            action = tracing::field::Empty);
+                        if let Some(action) = unpacked.get("Action") {
+                            message.record("action", action.trim_matches('"'));
+                        }
+                        let _message = message.enter();
                         let message_type_id: u8 = unpacked.get("MessageTypeId").unwrap().parse()
                             .unwrap();
                         match message_type_id {
                             2 => {
                                 let action: &str = &unpacked.get("Action").unwrap().as_str()
                                     .replace("\"", "");
-                                // let call = Call{
-                                //     unique_id: unpacked.get("MessageId").unwrap().clone(),
-                                //     action: unpacked.get("Action").unwrap().clone(),
-                                //     payload: serde_json::from_str(unpacked.get("Payload").unwrap()).unwrap()
-                                // };
-                                match action {
-                                    "BootNotification" => {
-                                        let response = boot_notification_response(
-                                            unpacked.get("MessageId").unwrap(),
-                                            unpacked.get("Payload").unwrap());
-                                        println!("{}: outgoing response: {}", self.name, response);
-                                        ctx.text(response)
-                                    },
-                                    "StatusNotification" => {
-                                        let response = status_notification_response(
-                                            unpacked.get("MessageId").unwrap(),
-                                            unpacked.get("Payload").unwrap());
-                                        println!("{}: outgoing response: {}", self.name, response);
-                                        ctx.text(response);
-                                    },
-                                    "Heartbeat" => {
-                                        let response = heartbeat_response(
-                                            unpacked.get("MessageId").unwrap());
-                                        println!("{}: outgoing response: {}", self.name, response);
-                                        ctx.text(response);
-                                    },
-                                    "Authorize" => {
-                                        let response = authorize_response(
-                                            unpacked.get("MessageId").unwrap(),
-                                            unpacked.get("Payload").unwrap());
-                                        println!("{}: outgoing response: {}", self.name, response);
-                                        ctx.text(response);
-                                    },
-                                    "NotifyEvent" => {
-                                        let response = notify_event_response(
-                                            unpacked.get("MessageId").unwrap(),
-                                            unpacked.get("Payload").unwrap());
-                                        println!("{}: outgoing response: {}", self.name, response);
-                                        ctx.text(response);
-                                    },
-                                    "NotifyReport" => {
-                                        let response = notify_report_response(
-                                            unpacked.get("MessageId").unwrap(),
-                                            unpacked.get("Payload").unwrap());
-                                        println!("{}: outgoing response: {}", self.name, response);
-                                        ctx.text(response);
-                                    },
-                                    "TransactionEvent" => {
-                                        let response = transaction_event_response(
-                                            unpacked.get("MessageId").unwrap(),
-                                            unpacked.get("Payload").unwrap(),
-                                            messages::responses::TransactionEventResponse {
-                                                charging_priority: None,
-                                                custom_data: None,
-                                                id_token_info: None,
-                                                total_cost: None,
-                                                updated_personal_message: None,
-                                            },
-                                        );
-                                        println!("{}: outgoing response: {}", self.name, response);
-                                        ctx.text(response);
-                                    }
-                                    _ => {
-                                        let response =
-                                            wrap_call_error_result(
-                                                unpacked.get("MessageId").unwrap(),
-                                                ErrorCode::NotImplemented,
-                                                &String::from(
-                                                    "\"Not all messages are implemented yet. \
-                                                    Ocpp server is still in development\""));
-                                        ctx.text(response);
+                                self.address.do_send(MessageFromChargeStation{
+                                    charger_id: self.name.clone(),
+                                    call: Some(Call{
+                                        unique_id: unpacked.get("MessageId").unwrap().trim_matches('"').to_string(),
+                                        action: action.to_string(),
+                                        payload: serde_json::from_str(unpacked.get("Payload").unwrap())
+                                            .unwrap_or(Value::Null)
+                                    }),
+                                    call_result: None,
+                                    call_error: None
+                                });
+                                if let Some(response) = self.schema_violation(action, &unpacked) {
+                                    self.send_frame(ctx, response);
+                                    return;
+                                }
+                                match self.version {
+                                    OcppVersion::V201 => self.handle_call(action, &unpacked, ctx),
+                                    OcppVersion::V16 => self.handle_v16_call(action, &unpacked, ctx),
+                                    OcppVersion::V21 => self.handle_v21_call(action, &unpacked, ctx),
+                                }
+                            }
+                            6 => {
+                                let action: &str = &unpacked.get("Action").unwrap().as_str()
+                                    .replace("\"", "");
+                                if self.version == OcppVersion::V21 {
+                                    // SEND messages are not answered, errors are only logged
+                                    if let Err(e) = v21::handle_send(action, unpacked.get("Payload").unwrap()) {
+                                        warn!("ignoring SEND message: {}", e);
                                     }
+                                } else {
+                                    let response = wrap_call_error_result(
+                                        unpacked.get("MessageId").unwrap(),
+                                        ErrorCode::MessageTypeNotSupported,
+                                        &format!("\"SEND messages require {}\"", OcppVersion::V21.sub_protocol()));
+                                    self.send_frame(ctx, response);
                                 }
                             }
                             3 => {
-                                let message_id_option = unpacked.get("MessageId");
-                                let payload_option = unpacked.get("Payload");
-                                if message_id_option.is_some() && payload_option.is_some() {
+                                if let (Some(message_id), Some(payload)) =
+                                    (unpacked.get("MessageId"), unpacked.get("Payload")) {
                                     let call_result = CallResult{
-                                        unique_id: message_id_option.unwrap().clone(),
-                                        payload: serde_json::from_str(payload_option.unwrap().clone().as_str()).unwrap()
+                                        unique_id: message_id.clone(),
+                                        payload: serde_json::from_str(payload).unwrap_or(Value::Null)
                                     };
                                     self.address.do_send(MessageFromChargeStation{
                                         charger_id: self.name.clone(),
@@ -195,17 +577,14 @@ impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for ChargeStationWebS
                                 }
                             }
                             4 => {
-                                let message_id_option = unpacked.get("MessageId");
-                                let error_code_option = unpacked.get("ErrorCode");
-                                let error_description_option = unpacked.get("ErrorDescription");
-                                let error_details_option = unpacked.get("ErrorDetails");
-                                if message_id_option.is_some() && error_code_option.is_some() &&
-                                    error_description_option.is_some() && error_details_option.is_some() {
+                                if let (Some(message_id), Some(error_code), Some(error_description), Some(error_details)) =
+                                    (unpacked.get("MessageId"), unpacked.get("ErrorCode"),
+                                     unpacked.get("ErrorDescription"), unpacked.get("ErrorDetails")) {
                                     let call_error = CallError{
-                                        unique_id: message_id_option.unwrap().clone(),
-                                        error_code: error_code_option.unwrap().clone(),
-                                        error_description: error_description_option.unwrap().clone(),
-                                        error_details: error_details_option.unwrap().clone()
+                                        unique_id: message_id.clone(),
+                                        error_code: error_code.clone(),
+                                        error_description: error_description.clone(),
+                                        error_details: error_details.clone()
                                     };
                                     self.address.do_send(MessageFromChargeStation{
                                         charger_id: self.name.clone(),
@@ -218,16 +597,26 @@ impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for ChargeStationWebS
                             _ => {}
                         }
                     }
-                    _ => {}
+                    Err(response) => {
+                        warn!("unreadable frame");
+                        self.send_frame(ctx, response);
+                    }
                 }
             }
-            ws::Message::Binary(_) => println!("Unexpected binary"),
+            ws::Message::Binary(_) => warn!("unexpected binary frame"),
             ws::Message::Close(reason) => {
+                self.close_reason = Some(match reason.as_ref().and_then(|reason| reason.description.as_ref()) {
+                    Some(description) => format!("closed by the station: {}", description),
+                    None => String::from("closed by the station"),
+                });
                 ctx.close(reason);
                 ctx.stop();
             },
             ws::Message::Nop => (),
-            _ => ctx.stop()
+            _ => {
+                self.close_reason = Some(String::from("unsupported websocket frame"));
+                ctx.stop()
+            }
         }
     }
 }
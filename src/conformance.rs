@@ -0,0 +1,114 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::auth::StationAuth;
+use crate::e2e::{self, Step};
+
+// Conformance suite modelled on the CSMS test cases of the OCPP Compliance Testing Tool: every
+// case is a JSON file in tests/conformance named after its use case, in which a scripted charging
+// station plays the part of the test tool. The steps are the ones of the end-to-end scenarios
+// (see e2e.rs); unless "booted" is false the station CS001 first connects, is accepted by a
+// BootNotification and gets the SetVariables of its heartbeat policy, the precondition of most
+// OCTT cases.
+//
+// Each case says whether it is expected to pass today. The suite fails when a case does not do
+// what is expected, in both directions: a regression, or a use case the server now handles whose
+// expectation should be moved to "pass". The outcome of every case is written to
+// target/conformance-report.md.
+
+/// Station that plays the part of the test tool
+const STATION: &str = "CS001";
+
+#[derive(Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+enum Outcome {
+    Pass,
+    Fail,
+}
+
+#[derive(Deserialize)]
+struct ConformanceCase {
+    /// OCTT use case, e.g. "B01"
+    #[serde(rename = "useCase")]
+    use_case: String,
+    title: String,
+    /// certification profile, "Core" or "Advanced Security"
+    profile: String,
+    expected: Outcome,
+    booted: Option<bool>,
+    steps: Vec<Step>,
+}
+
+/// Connects the station and boots it
+fn boot_steps() -> Vec<Step> {
+    vec![
+        Step::ConnectStation { id: STATION.to_string(), protocol: None, name: None, headers: None, refused: None },
+        Step::Send {
+            to: STATION.to_string(),
+            frame: json!([2, "octt-boot", "BootNotification", {
+                "chargingStation": {"model": "OCTT", "vendorName": "rusted_ocpp_server"},
+                "reason": "PowerUp"
+            }]),
+        },
+        Step::Expect {
+            from: STATION.to_string(),
+            frame: json!([3, "octt-boot", {"currentTime": "*", "interval": "*", "status": "Accepted"}]),
+        },
+        // the heartbeat policy pushed after the boot, left unanswered
+        Step::Expect {
+            from: STATION.to_string(),
+            frame: json!([2, "*", "SetVariables", "*"]),
+        },
+    ]
+}
+
+/// Cases of a directory, ordered by use case
+fn cases(dir: &Path) -> Vec<ConformanceCase> {
+    let mut cases: Vec<ConformanceCase> = fs::read_dir(dir).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+        .map(|path| serde_json::from_str(&fs::read_to_string(&path).unwrap())
+            .unwrap_or_else(|e| panic!("{} is not a conformance case: {}", path.display(), e)))
+        .collect();
+    cases.sort_by(|a, b| a.use_case.cmp(&b.use_case));
+    cases
+}
+
+#[actix_rt::test]
+async fn conformance_suite() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("conformance");
+    let mut report = String::from("| Use case | Title | Profile | Result | Expected | Details |\n\
+                                   |---|---|---|---|---|---|\n");
+    let mut unexpected = Vec::new();
+    let (mut passed, mut total) = (0, 0);
+    for case in cases(&dir) {
+        let mut steps = if case.booted.unwrap_or(true) { boot_steps() } else { Vec::new() };
+        steps.extend(case.steps);
+        let result = e2e::run_steps(steps, StationAuth::default()).await;
+        let outcome = if result.is_ok() { Outcome::Pass } else { Outcome::Fail };
+        let name = |outcome: Outcome| if outcome == Outcome::Pass { "pass" } else { "fail" };
+        total += 1;
+        if outcome == Outcome::Pass {
+            passed += 1;
+        }
+        if outcome != case.expected {
+            unexpected.push(format!("{} {}: {} but expected to {}", case.use_case, case.title,
+                                    name(outcome), name(case.expected)));
+        }
+        report.push_str(&format!("| {} | {} | {} | {} | {} | {} |\n", case.use_case, case.title, case.profile,
+                                 name(outcome), name(case.expected),
+                                 result.err().unwrap_or_default().replace('|', "\\|")));
+    }
+    report.push_str(&format!("\n{} of {} use cases pass\n", passed, total));
+    println!("{}", report);
+    let target = Path::new(env!("CARGO_MANIFEST_DIR")).join("target");
+    let _ = fs::create_dir_all(&target);
+    if let Err(e) = fs::write(target.join("conformance-report.md"), &report) {
+        println!("unable to write the conformance report: {}", e);
+    }
+    assert!(unexpected.is_empty(), "{} use case(s) did not do what is expected:\n{}",
+            unexpected.len(), unexpected.join("\n"));
+}
@@ -0,0 +1,415 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use actix::Actor;
+use actix_web::{test, web, App};
+use actix_web::rt::spawn;
+use actix_web::rt::time::timeout;
+use awc::ws;
+use futures::channel::{mpsc, oneshot};
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{auth, journal, logging, proxy, schemas, server, services};
+
+// End-to-end tests: the routes of the server are started on an ephemeral port, without TLS, and
+// scripted charging stations and web clients talk to it over real websockets. Every scenario is
+// a JSON file in tests/scenarios with a name, optionally "stationPasswords": {"CS001": "secret"}
+// to have the stations authenticate with basic authentication, and a list of steps:
+//   {"connectStation": {"id": "CS001", "protocol": "ocpp2.0.1"}}   opens /ocpp/CS001
+//   {"connectStation": {"id": "CS001", "as": "CS001-again"}}       a second connection of CS001,
+//                                                  named CS001-again in the other steps
+//   {"connectStation": {"id": "CS001", "headers": {"X-Forwarded-For": "203.0.113.7"}}}
+//                                                  with handshake headers; the test server trusts
+//                                                  the loopback address as a proxy, see PROXY_HEADER
+//   {"connectStation": {"id": "CS001", "refused": 401}}            the handshake must fail with 401
+//   {"connectBrowser": {"id": "B1"}}                                opens /api/webclient-socket/B1
+//   {"send": {"to": "CS001", "frame": [2, "1", "Heartbeat", {}]}}    sends a text frame
+//   {"expect": {"from": "CS001", "frame": [3, "1", {"currentTime": "*"}]}}
+//                                                  the next text frame must match
+//   {"await": {"from": "B1", "frame": {"type": "CallResultReceived", ...}}}
+//                                                  skips text frames until one matches
+//   {"request": {"method": "POST", "path": "/api/...", "body": {...}, "status": 200, "response": {...}}}
+//   {"request": {..., "as": "reset"}} and later {"response": {"name": "reset", "status": 200, "body": {...}}}
+//                                                  for requests answered once a station replied
+//   {"close": {"id": "CS001"}}
+//   {"closed": {"id": "CS001", "reason": "..."}}    skips text frames until the server closed the
+//                                                  connection, with that reason if one is given
+// Frames are compared exactly, except "*" which matches any value and "$name" which captures the
+// value the first time and must be equal afterwards. "$name" in sent frames and request bodies is
+// replaced by the captured value.
+
+/// Client certificate header of the proxy the test server trusts
+const PROXY_HEADER: &str = "X-SSL-Client-S-DN";
+
+/// How long `expect` and `await` wait for a frame
+const FRAME_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Deserialize)]
+pub struct Scenario {
+    name: String,
+    #[serde(rename = "stationPasswords")]
+    station_passwords: Option<HashMap<String, String>>,
+    steps: Vec<Step>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Step {
+    ConnectStation {
+        id: String,
+        protocol: Option<String>,
+        #[serde(rename = "as")]
+        name: Option<String>,
+        headers: Option<HashMap<String, String>>,
+        /// status the server refuses the handshake with
+        refused: Option<u16>,
+    },
+    ConnectBrowser { id: String },
+    Send { to: String, frame: Value },
+    Expect { from: String, frame: Value },
+    Await { from: String, frame: Value },
+    Request {
+        method: String,
+        path: String,
+        body: Option<Value>,
+        status: Option<u16>,
+        response: Option<Value>,
+        #[serde(rename = "as")]
+        name: Option<String>,
+    },
+    Response { name: String, status: Option<u16>, body: Option<Value> },
+    Close { id: String },
+    Closed { id: String, reason: Option<String> },
+}
+
+/// Websocket of a scripted station or web client
+struct Connection {
+    outgoing: mpsc::UnboundedSender<ws::Message>,
+    incoming: mpsc::UnboundedReceiver<Value>,
+    /// description of the close frame of the server, once it closed the connection
+    close_reason: oneshot::Receiver<Option<String>>,
+}
+
+/// Status and body of an HTTP response, the body as a string when it is not JSON
+type HttpReply = Result<(u16, Value), String>;
+
+struct Run {
+    server: test::TestServer,
+    connections: HashMap<String, Connection>,
+    requests: HashMap<String, oneshot::Receiver<HttpReply>>,
+    variables: HashMap<String, Value>,
+}
+
+/// Starts the routes of the server with a fresh OCPP server and journal
+pub fn start_server(station_auth: auth::StationAuth) -> test::TestServer {
+    let schema_validator = web::Data::new(schemas::SchemaValidator::compile(None).unwrap());
+    let journal = journal::Journal::open(journal::JournalSettings {
+        path: std::env::temp_dir().join(format!("journal-{}.jsonl", Uuid::new_v4())),
+        retention_days: None,
+        max_entries: None,
+    }).unwrap();
+    let heartbeats = services::HeartbeatService::new(services::HeartbeatPolicy::default(), HashMap::new());
+    let ocpp_server = server::OcppServer::new(schema_validator.clone().into_inner(), HashMap::new(), heartbeats,
+                                              services::AvailabilitySettings::default(), journal).start();
+    let trusted_proxies = web::Data::new(
+        proxy::TrustedProxies::new("127.0.0.1,::1", Some(String::from(PROXY_HEADER))).unwrap());
+    let station_auth = web::Data::new(station_auth);
+    test::start(move || {
+        App::new()
+            .data(ocpp_server.clone())
+            .app_data(schema_validator.clone())
+            .app_data(trusted_proxies.clone())
+            .app_data(station_auth.clone())
+            .wrap(logging::Correlation)
+            .configure(crate::routes)
+    })
+}
+
+/// Matches a frame against a pattern, capturing the "$name" values into `variables`
+fn matches(pattern: &Value, actual: &Value, variables: &mut HashMap<String, Value>) -> bool {
+    match (pattern, actual) {
+        (Value::String(wildcard), _) if wildcard == "*" => true,
+        (Value::String(variable), _) if variable.starts_with('$') => {
+            match variables.get(variable) {
+                Some(value) => value == actual,
+                None => {
+                    variables.insert(variable.clone(), actual.clone());
+                    true
+                }
+            }
+        }
+        (Value::Array(patterns), Value::Array(values)) => {
+            patterns.len() == values.len() &&
+                patterns.iter().zip(values).all(|(pattern, value)| matches(pattern, value, variables))
+        }
+        (Value::Object(patterns), Value::Object(values)) => {
+            patterns.len() == values.len() &&
+                patterns.iter().all(|(name, pattern)| {
+                    values.get(name).is_some_and(|value| matches(pattern, value, variables))
+                })
+        }
+        _ => pattern == actual,
+    }
+}
+
+/// Replaces the "$name" strings by the captured values
+fn substitute(value: &Value, variables: &HashMap<String, Value>) -> Value {
+    match value {
+        Value::String(variable) if variables.contains_key(variable) => variables[variable].clone(),
+        Value::Array(items) => Value::Array(items.iter().map(|item| substitute(item, variables)).collect()),
+        Value::Object(fields) => Value::Object(fields.iter()
+            .map(|(name, field)| (name.clone(), substitute(field, variables)))
+            .collect()),
+        _ => value.clone(),
+    }
+}
+
+impl Run {
+    async fn connect(&mut self, id: &str, path: String, protocol: Option<String>,
+                     headers: HashMap<String, String>) -> Result<(), String> {
+        let mut request = awc::Client::new().ws(self.server.url(&path));
+        if let Some(protocol) = protocol {
+            request = request.protocols(vec![protocol]);
+        }
+        for (name, value) in headers {
+            request = request.header(name.as_str(), value);
+        }
+        let (_, connection) = request.connect().await.map_err(|e| format!("unable to open {}: {}", path, e))?;
+        let (mut sink, mut stream) = connection.split();
+        let (outgoing, mut frames) = mpsc::unbounded();
+        let (received, incoming) = mpsc::unbounded();
+        let (closed, close_reason) = oneshot::channel();
+        spawn(async move {
+            while let Some(frame) = frames.next().await {
+                if sink.send(frame).await.is_err() {
+                    break;
+                }
+            }
+        });
+        let pongs = outgoing.clone();
+        spawn(async move {
+            while let Some(Ok(frame)) = stream.next().await {
+                match frame {
+                    ws::Frame::Text(text) => {
+                        let value = serde_json::from_slice(&text)
+                            .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&text).to_string()));
+                        let _ = received.unbounded_send(value);
+                    }
+                    ws::Frame::Ping(ping) => { let _ = pongs.unbounded_send(ws::Message::Pong(ping)); }
+                    ws::Frame::Close(reason) => {
+                        let _ = closed.send(reason.and_then(|reason| reason.description));
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        });
+        self.connections.insert(id.to_string(), Connection { outgoing, incoming, close_reason });
+        Ok(())
+    }
+
+    fn connection(&mut self, id: &str) -> Result<&mut Connection, String> {
+        self.connections.get_mut(id).ok_or_else(|| format!("{} is not connected", id))
+    }
+
+    async fn next_frame(&mut self, from: &str) -> Result<Value, String> {
+        let connection = self.connection(from)?;
+        match timeout(FRAME_TIMEOUT, connection.incoming.next()).await {
+            Ok(Some(frame)) => Ok(frame),
+            Ok(None) => Err(format!("{} was disconnected", from)),
+            Err(_) => Err(format!("no frame from {} within {} seconds", from, FRAME_TIMEOUT.as_secs())),
+        }
+    }
+
+    fn send_request(&self, method: &str, path: &str, body: Option<Value>) -> oneshot::Receiver<HttpReply> {
+        let request = match method {
+            "GET" => self.server.get(path),
+            "POST" => self.server.post(path),
+            "PUT" => self.server.put(path),
+            "DELETE" => self.server.delete(path),
+            _ => self.server.request(actix_web::http::Method::from_bytes(method.as_bytes()).unwrap(),
+                                     self.server.url(path)),
+        };
+        let (sender, receiver) = oneshot::channel();
+        spawn(async move {
+            let sent = match body {
+                Some(body) => request.send_json(&body).await,
+                None => request.send().await,
+            };
+            let reply = match sent {
+                Ok(mut response) => match response.body().await {
+                    Ok(body) => Ok((response.status().as_u16(), serde_json::from_slice(&body)
+                        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&body).to_string())))),
+                    Err(e) => Err(e.to_string()),
+                },
+                Err(e) => Err(e.to_string()),
+            };
+            let _ = sender.send(reply);
+        });
+        receiver
+    }
+
+    fn check_reply(&mut self, reply: Result<HttpReply, oneshot::Canceled>, status: Option<u16>,
+                   body: Option<Value>) -> Result<(), String> {
+        let (actual_status, actual_body) = reply.map_err(|_| String::from("the request was dropped"))??;
+        if let Some(status) = status {
+            if status != actual_status {
+                return Err(format!("expected status {}, got {} {}", status, actual_status, actual_body));
+            }
+        }
+        if let Some(body) = body {
+            if !matches(&body, &actual_body, &mut self.variables) {
+                return Err(format!("expected body {}, got {}", body, actual_body));
+            }
+        }
+        Ok(())
+    }
+
+    async fn refused(&self, path: String, headers: HashMap<String, String>, status: u16) -> Result<(), String> {
+        let mut request = awc::Client::new().ws(self.server.url(&path)).protocols(vec!["ocpp2.0.1"]);
+        for (name, value) in headers {
+            request = request.header(name.as_str(), value);
+        }
+        match request.connect().await {
+            Err(awc::error::WsClientError::InvalidResponseStatus(actual)) if actual.as_u16() == status => Ok(()),
+            Err(e) => Err(format!("expected {} to be refused with {}, got {}", path, status, e)),
+            Ok(_) => Err(format!("{} was not refused", path)),
+        }
+    }
+
+    async fn step(&mut self, step: Step) -> Result<(), String> {
+        match step {
+            Step::ConnectStation { id, headers, refused: Some(status), .. } =>
+                self.refused(format!("/ocpp/{}", id), headers.unwrap_or_default(), status).await,
+            Step::ConnectStation { id, protocol, name, headers, refused: None } => {
+                let protocol = Some(protocol.unwrap_or_else(|| String::from("ocpp2.0.1")));
+                self.connect(name.as_ref().unwrap_or(&id), format!("/ocpp/{}", id), protocol,
+                             headers.unwrap_or_default()).await
+            }
+            Step::ConnectBrowser { id } =>
+                self.connect(&id, format!("/api/webclient-socket/{}", id), None, HashMap::new()).await,
+            Step::Send { to, frame } => {
+                let frame = substitute(&frame, &self.variables).to_string();
+                self.connection(&to)?.outgoing.unbounded_send(ws::Message::Text(frame))
+                    .map_err(|_| format!("{} was disconnected", to))
+            }
+            Step::Expect { from, frame } => {
+                let actual = self.next_frame(&from).await?;
+                if matches(&frame, &actual, &mut self.variables) {
+                    Ok(())
+                } else {
+                    Err(format!("expected {} from {}, got {}", frame, from, actual))
+                }
+            }
+            Step::Await { from, frame } => loop {
+                let actual = self.next_frame(&from).await
+                    .map_err(|e| format!("{} while waiting for {}", e, frame))?;
+                // a frame that does not match must not leave captures behind
+                let mut variables = self.variables.clone();
+                if matches(&frame, &actual, &mut variables) {
+                    self.variables = variables;
+                    return Ok(());
+                }
+            },
+            Step::Request { method, path, body, status, response, name } => {
+                let path = substitute(&Value::String(path), &self.variables).as_str().unwrap_or_default().to_string();
+                let body = body.map(|body| substitute(&body, &self.variables));
+                let reply = self.send_request(&method.to_uppercase(), &path, body);
+                match name {
+                    Some(name) => {
+                        self.requests.insert(name, reply);
+                        Ok(())
+                    }
+                    None => {
+                        let reply = reply.await;
+                        self.check_reply(reply, status, response)
+                    }
+                }
+            }
+            Step::Response { name, status, body } => {
+                let reply = self.requests.remove(&name).ok_or_else(|| format!("no request named {}", name))?;
+                let reply = reply.await;
+                self.check_reply(reply, status, body)
+            }
+            Step::Close { id } => {
+                let connection = self.connections.remove(&id).ok_or_else(|| format!("{} is not connected", id))?;
+                let _ = connection.outgoing.unbounded_send(ws::Message::Close(None));
+                Ok(())
+            }
+            Step::Closed { id, reason } => {
+                let mut connection = self.connections.remove(&id).ok_or_else(|| format!("{} is not connected", id))?;
+                loop {
+                    match timeout(FRAME_TIMEOUT, connection.incoming.next()).await {
+                        Ok(Some(_)) => continue,
+                        Ok(None) => break,
+                        Err(_) => return Err(format!("{} was not closed within {} seconds", id, FRAME_TIMEOUT.as_secs())),
+                    }
+                }
+                let actual = connection.close_reason.try_recv().ok().flatten().flatten();
+                match reason {
+                    Some(reason) if actual.as_ref() != Some(&reason) => {
+                        Err(format!("expected {} to be closed with reason {:?}, got {:?}", id, reason, actual))
+                    }
+                    _ => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// Runs steps against a new server, stops at the first failing step
+pub async fn run_steps(steps: Vec<Step>, station_auth: auth::StationAuth) -> Result<(), String> {
+    let mut run = Run {
+        server: start_server(station_auth),
+        connections: HashMap::new(),
+        requests: HashMap::new(),
+        variables: HashMap::new(),
+    };
+    for (number, step) in steps.into_iter().enumerate() {
+        run.step(step).await.map_err(|e| format!("step {}: {}", number + 1, e))?;
+    }
+    Ok(())
+}
+
+pub async fn run(scenario: Scenario) -> Result<(), String> {
+    let station_auth = scenario.station_passwords.map(auth::StationAuth::basic).unwrap_or_default();
+    run_steps(scenario.steps, station_auth).await
+}
+
+/// Scenarios of a directory, ordered by file name
+pub fn scenarios(dir: &Path) -> Vec<(String, Scenario)> {
+    let mut files: Vec<_> = fs::read_dir(dir).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+        .collect();
+    files.sort();
+    files.into_iter()
+        .map(|path| {
+            let scenario = serde_json::from_str(&fs::read_to_string(&path).unwrap())
+                .unwrap_or_else(|e| panic!("{} is not a scenario: {}", path.display(), e));
+            (path.file_name().unwrap().to_string_lossy().to_string(), scenario)
+        })
+        .collect()
+}
+
+#[actix_rt::test]
+async fn end_to_end_scenarios() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("scenarios");
+    let mut failures = Vec::new();
+    for (file, scenario) in scenarios(&dir) {
+        let name = format!("{} ({})", scenario.name, file);
+        match run(scenario).await {
+            Ok(()) => println!("ok      {}", name),
+            Err(e) => {
+                println!("FAILED  {}: {}", name, e);
+                failures.push(format!("{}: {}", name, e));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{} scenario(s) failed:\n{}", failures.len(), failures.join("\n"));
+}
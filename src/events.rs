@@ -0,0 +1,183 @@
+use chrono::{SecondsFormat, Utc};
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::messages::OcppVersion;
+use crate::services::Transaction;
+use crate::traffic::TrafficFrame;
+
+// Contract between the server and the web clients: every websocket text frame the server sends to
+// a web client is one `WebEvent` serialized as JSON. The variant name is in the `type` field, the
+// other fields are the ones of the variant, e.g.
+//   {"type":"CallResultReceived","stationId":"CS001","messageId":"6e3a...","action":"Reset",
+//    "payload":{"status":"Accepted"},"timestamp":"2021-05-01T12:00:00.000+00:00"}
+// OCPP payloads are passed as parsed JSON, timestamps are RFC 3339 in UTC and are taken by the
+// server when the event happens. The JSON schema of the events is part of /api/openapi.json.
+// StationConnected, StationDisconnected, HeartbeatLapsed, HeartbeatResumed and
+// OfflineThresholdExceeded go to every web client, the other station events only to the web
+// clients subscribed to the station, by sending
+//   {"message":"subscribe","stations":["CS001"],"groups":["depot_a"],"all":false}
+// or "unsubscribe" with the same fields. Groups come from the STATION_GROUPS configuration. The
+// events about a call (CallSent, CallResultReceived, CallErrorReceived, ProtocolViolation and
+// CallFailed) also go to the web client that requested the call whatever its subscription, a
+// CallRejected only goes to that web client.
+
+/// An event sent to the web clients
+#[derive(Clone, Serialize, JsonSchema)]
+#[serde(tag = "type")]
+pub enum WebEvent {
+    /// the web client websocket session is open
+    SessionOpened {
+        timestamp: String,
+    },
+    /// the server is closing the web client websocket session
+    SessionClosing {
+        timestamp: String,
+    },
+    StationConnected {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        version: OcppVersion,
+        #[serde(rename = "ipAddress")]
+        ip_address: Option<String>,
+        timestamp: String,
+    },
+    StationDisconnected {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        timestamp: String,
+    },
+    /// the websocket of the station is open but no Heartbeat came within the heartbeat interval
+    /// times the grace factor, the station is marked offline
+    HeartbeatLapsed {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        #[serde(rename = "lastHeartbeat")]
+        last_heartbeat: Option<String>,
+        timestamp: String,
+    },
+    /// a station whose heartbeats had lapsed sent one again and is back online
+    HeartbeatResumed {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        timestamp: String,
+    },
+    /// a station has been offline for longer than the offline threshold, sent once per outage
+    OfflineThresholdExceeded {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        #[serde(rename = "offlineSince")]
+        offline_since: String,
+        #[serde(rename = "offlineSeconds")]
+        offline_seconds: i64,
+        reason: Option<String>,
+        timestamp: String,
+    },
+    /// a call the station started
+    CallReceived {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        #[serde(rename = "messageId")]
+        message_id: String,
+        action: String,
+        payload: Value,
+        timestamp: String,
+    },
+    /// a call was sent to the station, on behalf of a web client, of the REST API or of the server
+    CallSent {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        #[serde(rename = "messageId")]
+        message_id: String,
+        action: String,
+        payload: Value,
+        timestamp: String,
+    },
+    /// the request of the web client was not sent because the station is offline or the request
+    /// is not valid for it
+    CallRejected {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        action: String,
+        payload: Value,
+        error: String,
+        timestamp: String,
+    },
+    CallResultReceived {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        #[serde(rename = "messageId")]
+        message_id: String,
+        action: String,
+        payload: Value,
+        timestamp: String,
+    },
+    CallErrorReceived {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        #[serde(rename = "messageId")]
+        message_id: String,
+        action: String,
+        #[serde(rename = "errorCode")]
+        error_code: String,
+        #[serde(rename = "errorDescription")]
+        error_description: String,
+        #[serde(rename = "errorDetails")]
+        error_details: Value,
+        timestamp: String,
+    },
+    /// a call got no reply: the station did not answer in time, or its session ended first
+    CallFailed {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        #[serde(rename = "messageId")]
+        message_id: String,
+        action: String,
+        error: String,
+        timestamp: String,
+    },
+    /// the CallResult does not match the response of the call's action
+    ProtocolViolation {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        #[serde(rename = "messageId")]
+        message_id: String,
+        action: String,
+        payload: Value,
+        violation: String,
+        timestamp: String,
+    },
+    /// the subscription of the web client after a subscribe or unsubscribe message
+    SubscriptionChanged {
+        all: bool,
+        stations: Vec<String>,
+        groups: Vec<String>,
+        timestamp: String,
+    },
+    /// a connector reported a new status with StatusNotification
+    StatusChanged {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        #[serde(rename = "evseId")]
+        evse_id: i64,
+        #[serde(rename = "connectorId")]
+        connector_id: i64,
+        status: String,
+        timestamp: String,
+    },
+    /// a frame to or from the station, see `traffic`
+    Traffic(TrafficFrame),
+    /// a transaction started, stopped or changed, e.g. it was linked to a remote start
+    TransactionUpdated {
+        #[serde(rename = "stationId")]
+        station_id: String,
+        transaction: Transaction,
+        timestamp: String,
+    },
+}
+
+/// Timestamp of an event
+pub fn now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, false)
+}
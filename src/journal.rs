@@ -0,0 +1,236 @@
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, FixedOffset, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::traffic::{TrafficDirection, TrafficFrame};
+
+// Journal of the OCPP traffic: every frame of the traffic tap is appended to a JSON Lines file,
+// one `JournalEntry` per line. Entries older than the retention period, and the oldest entries
+// above the maximum number of entries, are pruned by the ocpp server. The same file is read by
+// the query API, its export and by the `ocpp-replay` tool.
+//
+// Reading and pruning go through the whole file, so the ocpp server runs them on a blocking
+// thread: queries with `Journal::read` on the path, pruning with the job of `start_prune`. The
+// entries appended while a prune runs are held back and written by `finish_prune` to the pruned
+// file, they would be lost with the file the prune replaces otherwise.
+
+/// A frame to or from a station as it is kept in the journal
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+pub struct JournalEntry {
+    #[serde(rename = "stationId")]
+    pub station_id: String,
+    pub direction: TrafficDirection,
+    /// 2 Call, 3 CallResult, 4 CallError, 6 Send; missing when the frame is not OCPP-J
+    #[serde(rename = "messageTypeId")]
+    pub message_type_id: Option<u64>,
+    #[serde(rename = "messageId")]
+    pub message_id: Option<String>,
+    pub action: Option<String>,
+    /// payload of the message; errorCode, errorDescription and errorDetails of a CallError;
+    /// the whole frame as a string when it is not OCPP-J
+    pub payload: Value,
+    pub timestamp: String,
+}
+
+impl JournalEntry {
+    pub fn from_frame(frame: &TrafficFrame) -> JournalEntry {
+        let fields: Vec<Value> = serde_json::from_str(&frame.frame).unwrap_or_default();
+        let field = |index: usize| fields.get(index).cloned().unwrap_or(Value::Null);
+        let payload = match frame.message_type_id {
+            Some(2) | Some(6) => field(3),
+            Some(3) => field(2),
+            Some(4) => json!({
+                "errorCode": field(2),
+                "errorDescription": field(3),
+                "errorDetails": field(4),
+            }),
+            _ => Value::String(frame.frame.clone()),
+        };
+        JournalEntry {
+            station_id: frame.station_id.clone(),
+            direction: frame.direction,
+            message_type_id: frame.message_type_id,
+            message_id: frame.message_id.clone(),
+            action: frame.action.clone(),
+            payload,
+            timestamp: frame.timestamp.clone(),
+        }
+    }
+
+    /// The entry as an OCPP-J frame, `None` for frames that were not OCPP-J
+    pub fn frame(&self) -> Option<String> {
+        let message_id = self.message_id.as_ref()?;
+        let frame = match self.message_type_id? {
+            2 | 6 => json!([self.message_type_id, message_id, self.action, self.payload]),
+            3 => json!([3, message_id, self.payload]),
+            4 => json!([4, message_id, self.payload["errorCode"], self.payload["errorDescription"],
+                        self.payload["errorDetails"]]),
+            _ => return None,
+        };
+        Some(frame.to_string())
+    }
+
+    fn time(&self) -> Option<DateTime<FixedOffset>> {
+        DateTime::parse_from_rfc3339(&self.timestamp).ok()
+    }
+}
+
+/// Filter of the journal entries, every field is optional
+#[derive(Default, Deserialize)]
+pub struct JournalQuery {
+    pub station: Option<String>,
+    pub action: Option<String>,
+    pub direction: Option<TrafficDirection>,
+    #[serde(rename = "messageTypeId")]
+    pub message_type_id: Option<u64>,
+    #[serde(rename = "messageId")]
+    pub message_id: Option<String>,
+    /// RFC 3339 timestamps, `from` included and `to` excluded
+    pub from: Option<String>,
+    pub to: Option<String>,
+    /// at most this many entries, the newest ones; the entries are always sorted oldest first
+    pub limit: Option<usize>,
+}
+
+impl JournalQuery {
+    /// Checks the timestamps of the query
+    pub fn validate(&self) -> Result<(), String> {
+        for timestamp in self.from.iter().chain(self.to.iter()) {
+            DateTime::parse_from_rfc3339(timestamp)
+                .map_err(|e| format!("{} is not an RFC 3339 timestamp: {}", timestamp, e))?;
+        }
+        Ok(())
+    }
+
+    fn matches(&self, entry: &JournalEntry) -> bool {
+        let time = entry.time();
+        let after = |bound: &Option<String>| bound.as_ref()
+            .and_then(|bound| DateTime::parse_from_rfc3339(bound).ok())
+            .map(|bound| time.is_some_and(|time| time >= bound));
+        self.station.as_ref().is_none_or(|station| station == &entry.station_id) &&
+            self.action.as_ref().is_none_or(|action| entry.action.as_ref() == Some(action)) &&
+            self.direction.is_none_or(|direction| direction == entry.direction) &&
+            self.message_type_id.is_none_or(|message_type_id| entry.message_type_id == Some(message_type_id)) &&
+            self.message_id.as_ref().is_none_or(|message_id| entry.message_id.as_ref() == Some(message_id)) &&
+            after(&self.from).unwrap_or(true) &&
+            !after(&self.to).unwrap_or(false)
+    }
+}
+
+/// Where the journal is written and how long its entries are kept
+#[derive(Clone)]
+pub struct JournalSettings {
+    pub path: PathBuf,
+    pub retention_days: Option<i64>,
+    pub max_entries: Option<usize>,
+}
+
+/// The journal file, appended to by the ocpp server
+pub struct Journal {
+    settings: JournalSettings,
+    file: File,
+    /// lines appended while a prune runs, `None` when no prune runs
+    held_back: Option<Vec<String>>,
+}
+
+impl Journal {
+    pub fn open(settings: JournalSettings) -> io::Result<Journal> {
+        let file = OpenOptions::new().create(true).append(true).open(&settings.path)?;
+        Ok(Journal { settings, file, held_back: None })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.settings.path
+    }
+
+    pub fn append(&mut self, entry: &JournalEntry) -> io::Result<()> {
+        let line = serde_json::to_string(entry)?;
+        match &mut self.held_back {
+            Some(held_back) => {
+                held_back.push(line);
+                Ok(())
+            }
+            None => writeln!(self.file, "{}", line),
+        }
+    }
+
+    /// Reads the entries of a journal file matching a query. Lines that are not entries, e.g. a
+    /// line cut short by a crash, are skipped.
+    pub fn read(path: &Path, query: &JournalQuery) -> io::Result<Vec<JournalEntry>> {
+        let mut entries = VecDeque::new();
+        for line in BufReader::new(File::open(path)?).lines() {
+            match serde_json::from_str::<JournalEntry>(&line?) {
+                Ok(entry) if query.matches(&entry) => {
+                    entries.push_back(entry);
+                    if query.limit.is_some_and(|limit| entries.len() > limit) {
+                        entries.pop_front();
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(entries.into())
+    }
+
+    /// Starts a prune and returns the job that rewrites the file, to be run on a blocking
+    /// thread and followed by `finish_prune`. `None` while another prune runs.
+    pub fn start_prune(&mut self) -> Option<impl FnOnce() -> io::Result<usize> + Send + 'static> {
+        if self.held_back.is_some() {
+            return None;
+        }
+        self.held_back = Some(Vec::new());
+        let settings = self.settings.clone();
+        Some(move || prune(&settings))
+    }
+
+    /// Opens the file the prune left and writes the entries appended in the meantime
+    pub fn finish_prune(&mut self) -> io::Result<()> {
+        self.file = OpenOptions::new().create(true).append(true).open(&self.settings.path)?;
+        for line in self.held_back.take().unwrap_or_default() {
+            writeln!(self.file, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Drops the entries older than the retention period and the oldest entries above the maximum
+/// number of entries, returns how many were dropped
+fn prune(settings: &JournalSettings) -> io::Result<usize> {
+    let lines: Vec<String> = BufReader::new(File::open(&settings.path)?).lines()
+        .collect::<io::Result<_>>()?;
+    let cutoff = settings.retention_days.map(|days| Utc::now() - Duration::days(days));
+    let mut kept: Vec<&String> = lines.iter()
+        .filter(|line| {
+            let time = serde_json::from_str::<JournalEntry>(line).ok().and_then(|entry| entry.time());
+            match (cutoff, time) {
+                (Some(cutoff), Some(time)) => time >= cutoff,
+                (Some(_), None) => false,
+                (None, _) => true,
+            }
+        })
+        .collect();
+    if let Some(max_entries) = settings.max_entries {
+        if kept.len() > max_entries {
+            kept = kept.split_off(kept.len() - max_entries);
+        }
+    }
+    let dropped = lines.len() - kept.len();
+    if dropped == 0 {
+        return Ok(0);
+    }
+    // written next to the journal and renamed, so the journal is never half written
+    let pruned = settings.path.with_extension("pruned");
+    let mut file = File::create(&pruned)?;
+    for line in kept {
+        writeln!(file, "{}", line)?;
+    }
+    file.sync_all()?;
+    fs::rename(&pruned, &settings.path)?;
+    Ok(dropped)
+}
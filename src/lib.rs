@@ -0,0 +1,9 @@
+// The OCPP messages and the modules the server shares with the tools of src/bin: the journal and
+// the traffic tap it is made of, for ocpp-replay, and the message structs, for ocpp-sim. The
+// server binary uses them from here as well, so they are compiled once.
+
+pub mod events;
+pub mod journal;
+pub mod messages;
+pub mod services;
+pub mod traffic;
@@ -0,0 +1,152 @@
+use std::task::{Context, Poll};
+
+use actix_web::dev::{Payload, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::{Error, FromRequest, HttpMessage, HttpRequest};
+use futures::future::{ok, LocalBoxFuture, Ready};
+use tracing::Instrument;
+use tracing_subscriber::EnvFilter;
+use uuid::Uuid;
+
+use crate::config::{LogConfig, LogFormat};
+
+// Logs of the server, through `tracing`. Events carry their context as fields: the station id and
+// session of a station websocket (span "station"), the message id and action of an OCPP frame
+// (span "message") and the correlation id of an HTTP request (span "http"). The correlation id
+// is the X-Correlation-Id header of the request, or a new one, and is sent back in the response;
+// the server logs it with the Call it sends for the request and with the CallResult or CallError
+// of the station, so that
+//   grep 2f1c... server.log
+// follows a browser request to the station and back. Levels are set per module by log.filter,
+// e.g. LOG.FILTER=rusted_ocpp_server::charger_client=debug,actix_web=warn.
+
+/// Header of the correlation id of a request
+pub const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+/// Filter of the log events: the level for everything, then the directives of log.filter
+pub fn filter(config: &LogConfig) -> Result<EnvFilter, String> {
+    let mut directives = config.level.to_lowercase();
+    if let Some(filter) = config.filter.as_ref().filter(|filter| !filter.trim().is_empty()) {
+        directives.push(',');
+        directives.push_str(filter.trim());
+    }
+    EnvFilter::try_new(&directives).map_err(|e| format!("{}: {}", directives, e))
+}
+
+/// Starts writing the log events to stdout, also the ones of the crates that use `log`
+pub fn init(config: &LogConfig) -> Result<(), String> {
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter(config)?);
+    match config.format {
+        LogFormat::Text => subscriber.try_init(),
+        LogFormat::Json => subscriber.json().try_init(),
+    }.map_err(|e| e.to_string())
+}
+
+/// Correlation id of an HTTP request
+#[derive(Clone)]
+pub struct CorrelationId(pub String);
+
+impl CorrelationId {
+    /// The id of the request header when it is a usable one, otherwise a new id
+    fn of(request: &ServiceRequest) -> CorrelationId {
+        request.headers().get(CORRELATION_ID_HEADER)
+            .and_then(|id| id.to_str().ok())
+            .filter(|id| !id.is_empty() && id.len() <= 128 && id.chars().all(|c| c.is_ascii_graphic()))
+            .map(|id| CorrelationId(id.to_string()))
+            .unwrap_or_else(|| CorrelationId(Uuid::new_v4().to_string()))
+    }
+}
+
+/// Handlers get the id the `Correlation` middleware gave to the request
+impl FromRequest for CorrelationId {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+    type Config = ();
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let id = req.extensions().get::<CorrelationId>().cloned()
+            .unwrap_or_else(|| CorrelationId(Uuid::new_v4().to_string()));
+        ok(id)
+    }
+}
+
+/// Middleware that gives every request a correlation id and runs it in an "http" span
+pub struct Correlation;
+
+impl<S, B> Transform<S> for Correlation
+    where S: Service<Request = ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+          S::Future: 'static,
+          B: 'static {
+    type Request = ServiceRequest;
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = CorrelationMiddleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(CorrelationMiddleware { service })
+    }
+}
+
+pub struct CorrelationMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> Service for CorrelationMiddleware<S>
+    where S: Service<Request = ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+          S::Future: 'static,
+          B: 'static {
+    type Request = ServiceRequest;
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: ServiceRequest) -> Self::Future {
+        let id = CorrelationId::of(&request);
+        let span = tracing::info_span!("http", correlation_id = %id.0, method = %request.method(),
+                                       path = %request.path());
+        request.extensions_mut().insert(id.clone());
+        let response = {
+            let _http = span.enter();
+            self.service.call(request)
+        };
+        Box::pin(async move {
+            let mut response = response.await?;
+            if let Ok(value) = HeaderValue::from_str(&id.0) {
+                response.headers_mut().insert(HeaderName::from_static(CORRELATION_ID_HEADER), value);
+            }
+            Ok(response)
+        }.instrument(span))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    #[test]
+    fn the_correlation_id_of_the_request_is_kept() {
+        let request = TestRequest::default().header(CORRELATION_ID_HEADER, "browser-42").to_srv_request();
+        assert_eq!(CorrelationId::of(&request).0, "browser-42");
+        let request = TestRequest::default().header(CORRELATION_ID_HEADER, "two words").to_srv_request();
+        assert_ne!(CorrelationId::of(&request).0, "two words");
+        assert!(Uuid::parse_str(&CorrelationId::of(&TestRequest::default().to_srv_request()).0).is_ok());
+    }
+
+    #[test]
+    fn the_filter_has_a_level_per_module() {
+        let mut config = LogConfig::default();
+        assert!(filter(&config).is_ok());
+        config.filter = Some(String::from("rusted_ocpp_server::server=debug,actix_web=warn"));
+        assert!(filter(&config).is_ok());
+        config.filter = Some(String::from("rusted_ocpp_server::server=loud"));
+        assert!(filter(&config).is_err());
+    }
+}
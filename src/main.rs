@@ -1,39 +1,78 @@
-use std::time::Instant;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use actix::{Actor, Addr};
 use actix_files::Files;
 use actix_web::{App, Error as ActixWebError, get, HttpRequest, HttpResponse, HttpServer, post, Responder, web};
+use actix_web::http::header::SEC_WEBSOCKET_PROTOCOL;
 use actix_web_actors::ws;
-use dotenv;
-use rustls::internal::pemfile::{certs, pkcs8_private_keys};
-use rustls::{AllowAnyAuthenticatedClient, RootCertStore};
 use serde::Serialize;
-use std::io::BufReader;
-use std::fs::File;
+
+use rusted_ocpp_server::{events, journal, messages, services, traffic};
 
 mod config;
-mod messages;
 mod server;
 mod charger_client;
 mod webclient;
 mod error;
+mod schemas;
+mod api;
+mod openapi;
+mod tls;
+mod proxy;
+mod auth;
+mod logging;
+mod metrics;
+#[cfg(test)]
+mod e2e;
+#[cfg(test)]
+mod conformance;
 
-const ALLOWED_SUB_PROTOCOLS: [&'static str; 1] = ["ocpp2.0.1"];
+const ALLOWED_SUB_PROTOCOLS: [&str; 3] = ["ocpp2.1", "ocpp2.0.1", "ocpp1.6"];
 
 #[derive(Serialize)]
 struct Status{
     status: &'static str
 }
 
+/// Picks the OCPP version the same way `ws::start_with_protocols` picks the sub-protocol: the first
+/// one offered by the charge station that the server supports. Stations that do not offer any
+/// sub-protocol are treated as OCPP 2.0.1 ones.
+fn negotiated_ocpp_version(r: &HttpRequest) -> messages::OcppVersion {
+    r.headers().get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|protocols| protocols.to_str().ok())
+        .and_then(|protocols| protocols.split(',')
+            .map(|protocol| protocol.trim())
+            .find(|protocol| ALLOWED_SUB_PROTOCOLS.iter().any(|allowed| allowed == protocol))
+            .and_then(messages::OcppVersion::from_sub_protocol))
+        .unwrap_or(messages::OcppVersion::V201)
+}
+
 #[get("/ocpp/{serial_id}")]
-async fn ws_ocpp_index(r: HttpRequest, stream: web::Payload, srv: web::Data<Addr<server::OcppServer>>) -> Result<HttpResponse, ActixWebError> {
+async fn ws_ocpp_index(r: HttpRequest, stream: web::Payload, srv: web::Data<Addr<server::OcppServer>>,
+                       schema_validator: web::Data<schemas::SchemaValidator>,
+                       proxies: web::Data<proxy::TrustedProxies>,
+                       station_auth: web::Data<auth::StationAuth>) -> Result<HttpResponse, ActixWebError> {
     match r.match_info().get("serial_id") {
         Some(serial_id) => {
+            if let Err(e) = station_auth.check(serial_id, &r) {
+                tracing::warn!(station_id = %serial_id, "authentication failed: {}", e);
+                return Ok(HttpResponse::Unauthorized().header("WWW-Authenticate", "Basic realm=\"OCPP\"").finish());
+            }
             let res = ws::start_with_protocols(
                 charger_client::ChargeStationWebSocketSession {
                     hb: Instant::now(),
                     name: String::from(serial_id),
+                    session_id: 0,
+                    heartbeat: services::HeartbeatPolicy::default(),
+                    ping: None,
+                    close_reason: None,
+                    span: tracing::info_span!("station", station_id = %serial_id, session_id = tracing::field::Empty),
+                    peer: proxies.peer(&r),
                     address: srv.get_ref().clone(),
+                    version: negotiated_ocpp_version(&r),
+                    schemas: schema_validator.into_inner(),
                 }, &ALLOWED_SUB_PROTOCOLS, &r, stream);
             res
         }
@@ -64,45 +103,128 @@ async fn get_chargers(srv: web::Data<Addr<server::OcppServer>>) -> Result<impl R
 }
 
 #[post("/api/post-request")]
-async fn post_request(srv: web::Data<Addr<server::OcppServer>>,
+async fn post_request(srv: web::Data<Addr<server::OcppServer>>, correlation: logging::CorrelationId,
                       item: web::Json<server::MessageFromWebBrowser>) -> HttpResponse {
-    match srv.send(item.into_inner()).await {
+    let mut message = item.into_inner();
+    message.correlation_id = correlation.0;
+    match srv.send(message).await {
         Ok(_) => HttpResponse::Ok().json(Status{ status: "0k" }),
         Err(_) => HttpResponse::Ok().json(Status{ status: "not 0k" })
     }
 }
 
+#[get("/api/openapi.json")]
+async fn openapi_document() -> HttpResponse {
+    HttpResponse::Ok().json(openapi::document())
+}
+
+/// Routes of the server, shared with the end-to-end tests
+fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_chargers)
+        .service(post_request)
+        .service(api::reset)
+        .service(api::unlock)
+        .service(api::remote_start)
+        .service(api::remote_stop)
+        .service(api::change_availability)
+        .service(api::trigger_message)
+        .service(api::reserve)
+        .service(api::cancel_reservation)
+        .service(api::get_variables)
+        .service(api::set_variables)
+        .service(api::heartbeat_policy)
+        .service(api::availability)
+        .service(api::offline_stations)
+        .service(api::set_heartbeat_policy)
+        .service(api::stations)
+        .service(api::station)
+        .service(api::traffic)
+        .service(api::journal)
+        .service(api::journal_export)
+        .service(api::metrics)
+        .service(openapi_document)
+        .service(ws_ocpp_index)
+        .service(ws_webclient_index);
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     dotenv::from_filename("settings.env").ok();
-    let config = crate::config::Config::from_env().unwrap();
-    let root_cert_store = RootCertStore::empty();
-    let mut tls_config = rustls::ServerConfig::new(
-        AllowAnyAuthenticatedClient::new(root_cert_store));
-    let cert_file = &mut BufReader::new(File::open("cert.pem").unwrap());
-    let key_file = &mut BufReader::new(File::open("key.pem").unwrap());
-    let cert_chain = certs(cert_file).unwrap();
-    let mut keys = pkcs8_private_keys(key_file).unwrap();
-    tls_config.set_single_cert(cert_chain, keys.remove(0)).unwrap();
-    println!("Server is listening.\r\n \
-              Open web-browser with the url https://{host}:{port}/\r\n \
-              Connect chargers with the url wss://{host}:{port}/ocpp/",
-             host = config.server.host, port = config.server.port);
-    let ocpp_server = server::OcppServer::new().start();
+    let config = match crate::config::Config::load(std::env::args().skip(1)) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Invalid configuration:\n{}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Err(e) = logging::init(&config.log) {
+        eprintln!("Unable to start the log: {}", e);
+        std::process::exit(1);
+    }
+    let tls_config = if config.server.tls {
+        let tls_config = tls::CertificateReloader::new(Path::new(&config.tls.cert), Path::new(&config.tls.key))
+            .map(Arc::new)
+            .and_then(|reloader| {
+                if config.tls.reload_interval > 0 {
+                    reloader.watch(Duration::from_secs(config.tls.reload_interval));
+                }
+                tls::server_config(&config.tls, &config.security, reloader)
+            });
+        match tls_config {
+            Ok(tls_config) => Some(tls_config),
+            Err(e) => {
+                eprintln!("Invalid TLS configuration: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        None
+    };
+    let (http, ws) = if tls_config.is_some() { ("https", "wss") } else { ("http", "ws") };
+    tracing::info!("Server is listening. Open web-browser with the url {http}://{host}:{port}/, \
+                    connect chargers with the url {ws}://{host}:{port}/ocpp/",
+                   http = http, ws = ws, host = config.server.host, port = config.server.port);
+    if let Some(plain_port) = config.server.plain_port {
+        tracing::info!("Connect chargers without TLS with the url ws://{host}:{port}/ocpp/",
+                       host = config.server.host, port = plain_port);
+    }
+    let schema_validator = match schemas::SchemaValidator::compile(config.schemas.directory.as_deref().map(Path::new)) {
+        Ok(schema_validator) => web::Data::new(schema_validator),
+        Err(e) => {
+            eprintln!("Invalid OCPP schemas: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let journal = journal::Journal::open(config.journal_settings())?;
+    // checked by Config::load
+    let heartbeats = config.heartbeats().expect("valid heartbeat configuration");
+    let availability = config.availability_settings().expect("valid availability configuration");
+    let trusted_proxies = web::Data::new(config.trusted_proxies().expect("valid proxy configuration"));
+    let station_auth = web::Data::new(config.station_auth());
+    let ocpp_server = server::OcppServer::new(schema_validator.clone().into_inner(), config.station_groups(),
+                                              heartbeats, availability, journal).start();
 
-    HttpServer::new(move || {
+    let static_files = config.server.static_files.clone();
+    let server = HttpServer::new(move || {
         App::new()
             .data(ocpp_server.clone())
+            .app_data(schema_validator.clone())
+            .app_data(trusted_proxies.clone())
+            .app_data(station_auth.clone())
+            .wrap(logging::Correlation)
             //.data(pool.clone())
             //.service(web::resource("/").route(web::get().to(index)))
-            .service(get_chargers)
-            .service(post_request)
-            .service(ws_ocpp_index)
-            .service(ws_webclient_index)
-            .service(Files::new("/", "./webclient/").index_file("index.html"))
-    })
-        .bind_rustls(format!("{}:{}", config.server.host, config.server.port), tls_config)?
-        //.bind(format!("{}:{}", config.server.host, config.server.port))?
-        .run()
-        .await
+            .configure(routes)
+            .service(Files::new("/", &static_files).index_file("index.html"))
+    });
+    let address = format!("{}:{}", config.server.host, config.server.port);
+    let server = match tls_config {
+        Some(tls_config) => server.bind_rustls(address, tls_config)?,
+        None => server.bind(address)?,
+    };
+    let server = match config.server.plain_port {
+        Some(plain_port) => server.bind(format!("{}:{}", config.server.host, plain_port))?,
+        None => server,
+    };
+    server.run().await
 }
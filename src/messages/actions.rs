@@ -0,0 +1,175 @@
+use schemars::gen::SchemaGenerator;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use validator::Validate;
+
+use super::{requests, responses};
+
+/// Which side of the connection may initiate a call of an action
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    ChargingStationToCsms,
+    CsmsToChargingStation,
+    Both,
+}
+
+impl Direction {
+    pub fn is_sent_by_charging_station(&self) -> bool {
+        *self != Direction::CsmsToChargingStation
+    }
+
+    pub fn is_sent_by_csms(&self) -> bool {
+        *self != Direction::ChargingStationToCsms
+    }
+}
+
+/// Links the request of an action to the action itself and to its response
+pub trait OcppRequest: Serialize + DeserializeOwned + Validate {
+    const ACTION: Action;
+    type Response: Serialize + DeserializeOwned + Validate;
+}
+
+/// Decodes a payload into `T` and checks its field constraints
+fn decode<T: DeserializeOwned + Validate>(payload: &Value) -> Result<T, String> {
+    let decoded: T = serde_json::from_value(payload.clone()).map_err(|e| e.to_string())?;
+    decoded.validate().map_err(|e| e.to_string())?;
+    Ok(decoded)
+}
+
+// Every OCPP 2.0.1 action is declared once in the `actions!` invocation below. It generates the
+// `Action` enum, its name lookup and direction, the `OcppRequest` implementations of the request
+// structs, the typed `CallResultPayload` and the list of message schemas of the OpenAPI document,
+// so adding an action only means adding a line there.
+macro_rules! actions {
+    ($($action:ident = $name:literal, $direction:ident, $request:ident, $response:ident;)*) => {
+        /// An OCPP 2.0.1 action
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum Action {
+            $($action,)*
+        }
+
+        impl Action {
+            /// Name of the action in OCPP-J messages
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Action::$action => $name,)*
+                }
+            }
+
+            pub fn from_name(name: &str) -> Option<Action> {
+                match name {
+                    $($name => Some(Action::$action),)*
+                    _ => None
+                }
+            }
+
+            pub fn direction(&self) -> Direction {
+                match self {
+                    $(Action::$action => Direction::$direction,)*
+                }
+            }
+
+            /// Checks that a payload is a valid request of this action
+            pub fn validate_request(&self, payload: &Value) -> Result<(), String> {
+                match self {
+                    $(Action::$action => decode::<requests::$request>(payload).map(|_| ()),)*
+                }
+            }
+
+            /// Decodes the payload of a CallResult to a call of this action
+            pub fn decode_response(&self, payload: &Value) -> Result<CallResultPayload, String> {
+                match self {
+                    $(Action::$action => decode::<responses::$response>(payload).map(CallResultPayload::$action),)*
+                }
+            }
+        }
+
+        /// The payload of a CallResult, decoded into the response type of the call's action
+        pub enum CallResultPayload {
+            $($action(responses::$response),)*
+        }
+
+        /// Adds the schemas of the request and the response of every action to `generator`
+        pub fn message_schemas(generator: &mut SchemaGenerator) {
+            $(
+                generator.subschema_for::<requests::$request>();
+                generator.subschema_for::<responses::$response>();
+            )*
+        }
+
+        $(
+            impl OcppRequest for requests::$request {
+                const ACTION: Action = Action::$action;
+                type Response = responses::$response;
+            }
+        )*
+    };
+}
+
+actions! {
+    Authorize = "Authorize", ChargingStationToCsms, AuthorizeRequest, AuthorizeResponse;
+    BootNotification = "BootNotification", ChargingStationToCsms, BootNotificationRequest, BootNotificationResponse;
+    CancelReservation = "CancelReservation", CsmsToChargingStation, CancelReservationRequest, CancelReservationResponse;
+    CertificateSigned = "CertificateSigned", CsmsToChargingStation, CertificateSignedRequest, CertificateSignedResponse;
+    ChangeAvailability = "ChangeAvailability", CsmsToChargingStation, ChangeAvailabilityRequest, ChangeAvailabilityResponse;
+    ClearCache = "ClearCache", CsmsToChargingStation, ClearCacheRequest, ClearCacheResponse;
+    ClearChargingProfile = "ClearChargingProfile", CsmsToChargingStation, ClearChargingProfileRequest, ClearChargingProfileResponse;
+    ClearDisplayMessage = "ClearDisplayMessage", CsmsToChargingStation, ClearDisplayMessageRequest, ClearDisplayMessageResponse;
+    ClearedChargingLimit = "ClearedChargingLimit", ChargingStationToCsms, ClearedChargingLimitRequest, ClearedChargingLimitResponse;
+    ClearVariableMonitoring = "ClearVariableMonitoring", CsmsToChargingStation, ClearVariableMonitoringRequest, ClearVariableMonitoringResponse;
+    CostUpdated = "CostUpdated", CsmsToChargingStation, CostUpdatedRequest, CostUpdatedResponse;
+    CustomerInformation = "CustomerInformation", CsmsToChargingStation, CustomerInformationRequest, CustomerInformationResponse;
+    DataTransfer = "DataTransfer", Both, DataTransferRequest, DataTransferResponse;
+    DeleteCertificate = "DeleteCertificate", CsmsToChargingStation, DeleteCertificateRequest, DeleteCertificateResponse;
+    FirmwareStatusNotification = "FirmwareStatusNotification", ChargingStationToCsms, FirmwareStatusNotificationRequest, FirmwareStatusNotificationResponse;
+    Get15118EvCertificate = "Get15118EVCertificate", ChargingStationToCsms, Get15118EvCertificateRequest, Get15118EvCertificateResponse;
+    GetBaseReport = "GetBaseReport", CsmsToChargingStation, GetBaseReportRequest, GetBaseReportResponse;
+    GetCertificateStatus = "GetCertificateStatus", ChargingStationToCsms, GetCertificateStatusRequest, GetCertificateStatusResponse;
+    GetChargingProfiles = "GetChargingProfiles", CsmsToChargingStation, GetChargingProfilesRequest, GetChargingProfilesResponse;
+    GetCompositeSchedule = "GetCompositeSchedule", CsmsToChargingStation, GetCompositeScheduleRequest, GetCompositeScheduleResponse;
+    GetDisplayMessages = "GetDisplayMessages", CsmsToChargingStation, GetDisplayMessagesRequest, GetDisplayMessagesResponse;
+    GetInstalledCertificateIds = "GetInstalledCertificateIds", CsmsToChargingStation, GetInstalledCertificateIdsRequest, GetInstalledCertificateIdsResponse;
+    GetLocalListVersion = "GetLocalListVersion", CsmsToChargingStation, GetLocalListVersionRequest, GetLocalListVersionResponse;
+    GetLog = "GetLog", CsmsToChargingStation, GetLogRequest, GetLogResponse;
+    GetMonitoringReport = "GetMonitoringReport", CsmsToChargingStation, GetMonitoringReportRequest, GetMonitoringReportResponse;
+    GetReport = "GetReport", CsmsToChargingStation, GetReportRequest, GetReportResponse;
+    GetTransactionStatus = "GetTransactionStatus", CsmsToChargingStation, GetTransactionStatusRequest, GetTransactionStatusResponse;
+    GetVariables = "GetVariables", CsmsToChargingStation, GetVariablesRequest, GetVariablesResponse;
+    Heartbeat = "Heartbeat", ChargingStationToCsms, HeartbeatRequest, HeartbeatResponse;
+    InstallCertificate = "InstallCertificate", CsmsToChargingStation, InstallCertificateRequest, InstallCertificateResponse;
+    LogStatusNotification = "LogStatusNotification", ChargingStationToCsms, LogStatusNotificationRequest, LogStatusNotificationResponse;
+    MeterValues = "MeterValues", ChargingStationToCsms, MeterValuesRequest, MeterValuesResponse;
+    NotifyChargingLimit = "NotifyChargingLimit", ChargingStationToCsms, NotifyChargingLimitRequest, NotifyChargingLimitResponse;
+    NotifyCustomerInformation = "NotifyCustomerInformation", ChargingStationToCsms, NotifyCustomerInformationRequest, NotifyCustomerInformationResponse;
+    NotifyDisplayMessages = "NotifyDisplayMessages", ChargingStationToCsms, NotifyDisplayMessagesRequest, NotifyDisplayMessagesResponse;
+    NotifyEvChargingNeeds = "NotifyEVChargingNeeds", ChargingStationToCsms, NotifyEvChargingNeedsRequest, NotifyEvChargingNeedsResponse;
+    NotifyEvChargingSchedule = "NotifyEVChargingSchedule", ChargingStationToCsms, NotifyEvChargingScheduleRequest, NotifyEvChargingScheduleResponse;
+    NotifyEvent = "NotifyEvent", ChargingStationToCsms, NotifyEventRequest, NotifyEventResponse;
+    NotifyMonitoringReport = "NotifyMonitoringReport", ChargingStationToCsms, NotifyMonitoringReportRequest, NotifyMonitoringReportResponse;
+    NotifyReport = "NotifyReport", ChargingStationToCsms, NotifyReportRequest, NotifyReportResponse;
+    PublishFirmware = "PublishFirmware", CsmsToChargingStation, PublishFirmwareRequest, PublishFirmwareResponse;
+    PublishFirmwareStatusNotification = "PublishFirmwareStatusNotification", ChargingStationToCsms, PublishFirmwareStatusNotificationRequest, PublishFirmwareStatusNotificationResponse;
+    ReportChargingProfiles = "ReportChargingProfiles", ChargingStationToCsms, ReportChargingProfilesRequest, ReportChargingProfilesResponse;
+    RequestStartTransaction = "RequestStartTransaction", CsmsToChargingStation, RequestStartTransactionRequest, RequestStartTransactionResponse;
+    RequestStopTransaction = "RequestStopTransaction", CsmsToChargingStation, RequestStopTransactionRequest, RequestStopTransactionResponse;
+    ReservationStatusUpdate = "ReservationStatusUpdate", ChargingStationToCsms, ReservationStatusUpdateRequest, ReservationStatusUpdateResponse;
+    ReserveNow = "ReserveNow", CsmsToChargingStation, ReserveNowRequest, ReserveNowResponse;
+    Reset = "Reset", CsmsToChargingStation, ResetRequest, ResetResponse;
+    SecurityEventNotification = "SecurityEventNotification", ChargingStationToCsms, SecurityEventNotificationRequest, SecurityEventNotificationResponse;
+    SendLocalList = "SendLocalList", CsmsToChargingStation, SendLocalListRequest, SendLocalListResponse;
+    SetChargingProfile = "SetChargingProfile", CsmsToChargingStation, SetChargingProfileRequest, SetChargingProfileResponse;
+    SetDisplayMessage = "SetDisplayMessage", CsmsToChargingStation, SetDisplayMessageRequest, SetDisplayMessageResponse;
+    SetMonitoringBase = "SetMonitoringBase", CsmsToChargingStation, SetMonitoringBaseRequest, SetMonitoringBaseResponse;
+    SetMonitoringLevel = "SetMonitoringLevel", CsmsToChargingStation, SetMonitoringLevelRequest, SetMonitoringLevelResponse;
+    SetNetworkProfile = "SetNetworkProfile", CsmsToChargingStation, SetNetworkProfileRequest, SetNetworkProfileResponse;
+    SetVariableMonitoring = "SetVariableMonitoring", CsmsToChargingStation, SetVariableMonitoringRequest, SetVariableMonitoringResponse;
+    SetVariables = "SetVariables", CsmsToChargingStation, SetVariablesRequest, SetVariablesResponse;
+    SignCertificate = "SignCertificate", ChargingStationToCsms, SignCertificateRequest, SignCertificateResponse;
+    StatusNotification = "StatusNotification", ChargingStationToCsms, StatusNotificationRequest, StatusNotificationResponse;
+    TransactionEvent = "TransactionEvent", ChargingStationToCsms, TransactionEventRequest, TransactionEventResponse;
+    TriggerMessage = "TriggerMessage", CsmsToChargingStation, TriggerMessageRequest, TriggerMessageResponse;
+    UnlockConnector = "UnlockConnector", CsmsToChargingStation, UnlockConnectorRequest, UnlockConnectorResponse;
+    UnpublishFirmware = "UnpublishFirmware", CsmsToChargingStation, UnpublishFirmwareRequest, UnpublishFirmwareResponse;
+    UpdateFirmware = "UpdateFirmware", CsmsToChargingStation, UpdateFirmwareRequest, UpdateFirmwareResponse;
+}
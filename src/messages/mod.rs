@@ -2,15 +2,53 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc, SecondsFormat};
-use serde::Deserialize;
-use serde_json::Value;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use validator::{Validate, ValidationError, ValidationErrors, ValidationErrorsKind};
 
+use crate::services;
+
+pub mod actions;
 pub mod requests;
 pub mod responses;
+pub mod v16;
+pub mod v21;
 
 pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(60);
 pub const CLIENT_TIMEOUT: Duration = Duration::from_secs(600);
 
+/// OCPP version negotiated with the charge station through the websocket sub-protocol
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, JsonSchema)]
+pub enum OcppVersion {
+    #[serde(rename = "ocpp1.6")]
+    V16,
+    #[serde(rename = "ocpp2.0.1")]
+    V201,
+    #[serde(rename = "ocpp2.1")]
+    V21,
+}
+
+impl OcppVersion {
+    pub fn from_sub_protocol(sub_protocol: &str) -> Option<OcppVersion> {
+        match sub_protocol {
+            "ocpp1.6" => Some(OcppVersion::V16),
+            "ocpp2.0.1" => Some(OcppVersion::V201),
+            "ocpp2.1" => Some(OcppVersion::V21),
+            _ => None
+        }
+    }
+
+    pub fn sub_protocol(&self) -> &'static str {
+        match self {
+            OcppVersion::V16 => "ocpp1.6",
+            OcppVersion::V201 => "ocpp2.0.1",
+            OcppVersion::V21 => "ocpp2.1",
+        }
+    }
+}
+
 pub enum ErrorCode {
     FormatViolation,
     // Payload for Action is syntactically incorrect
@@ -57,24 +95,64 @@ pub struct CallError{
     pub error_details: String
 }
 
-pub fn wrap_call(message_id: &String, action: &String, payload: &String) -> String {
+pub fn wrap_call(message_id: &String, action: &String, payload: &str) -> String {
     let m = if message_id.starts_with("\"") && message_id.ends_with("\"") {
-        format!("{}", message_id)
+        message_id.to_string()
     } else {
         format!("\"{}\"", message_id)
     };
     let a = if action.starts_with("\"") && action.ends_with("\"") {
-        format!("{}", action)
+        action.to_string()
     } else {
         format!("\"{}\"", action)
     };
     format!("[2, {}, {}, {}]", m, a, payload)
 }
 
+impl ErrorCode {
+    /// The error code of a CallError, also the OCPP 1.6 spellings FormationViolation and
+    /// OccurenceConstraintViolation
+    pub fn from_name(name: &str) -> Option<ErrorCode> {
+        match name {
+            "FormatViolation" | "FormationViolation" => Some(ErrorCode::FormatViolation),
+            "GenericError" => Some(ErrorCode::GenericError),
+            "InternalError" => Some(ErrorCode::InternalError),
+            "MessageTypeNotSupported" => Some(ErrorCode::MessageTypeNotSupported),
+            "NotImplemented" => Some(ErrorCode::NotImplemented),
+            "NotSupported" => Some(ErrorCode::NotSupported),
+            "OccurrenceConstraintViolation" | "OccurenceConstraintViolation" =>
+                Some(ErrorCode::OccurrenceConstraintViolation),
+            "PropertyConstraintViolation" => Some(ErrorCode::PropertyConstraintViolation),
+            "ProtocolError" => Some(ErrorCode::ProtocolError),
+            "RpcFrameworkError" => Some(ErrorCode::RpcFrameworkError),
+            "SecurityError" => Some(ErrorCode::SecurityError),
+            "TypeConstraintViolation" => Some(ErrorCode::TypeConstraintViolation),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ErrorCode::FormatViolation => "FormatViolation",
+            ErrorCode::GenericError => "GenericError",
+            ErrorCode::InternalError => "InternalError",
+            ErrorCode::MessageTypeNotSupported => "MessageTypeNotSupported",
+            ErrorCode::NotImplemented => "NotImplemented",
+            ErrorCode::NotSupported => "NotSupported",
+            ErrorCode::OccurrenceConstraintViolation => "OccurrenceConstraintViolation",
+            ErrorCode::PropertyConstraintViolation => "PropertyConstraintViolation",
+            ErrorCode::ProtocolError => "ProtocolError",
+            ErrorCode::RpcFrameworkError => "RpcFrameworkError",
+            ErrorCode::SecurityError => "SecurityError",
+            ErrorCode::TypeConstraintViolation => "TypeConstraintViolation",
+        }
+    }
+}
+
 // [<MessageTypeId>, "<UniqueId>", {<Payload>}]
 pub fn wrap_call_result(message_id: &String, payload: String) -> String {
     let m = if message_id.starts_with("\"") && message_id.ends_with("\"") {
-        format!("{}", message_id)
+        message_id.to_string()
     } else {
         format!("\"{}\"", message_id)
     };
@@ -86,7 +164,7 @@ pub fn wrap_call_result(message_id: &String, payload: String) -> String {
 pub fn wrap_call_error_result(msg_id: &String, error_code: ErrorCode, error_details: &String) -> String {
     match error_code {
         ErrorCode::FormatViolation => {
-            format!("[4, {}, \"FormationViolation\", \"Payload for Action is syntactically \
+            format!("[4, {}, \"FormatViolation\", \"Payload for Action is syntactically \
                 incorrect or not conform the PDU structure for Action\", {}]", msg_id, error_details)
         }
         ErrorCode::GenericError => {
@@ -117,9 +195,8 @@ pub fn wrap_call_error_result(msg_id: &String, error_code: ErrorCode, error_deta
                 constraints\", {}]", msg_id, error_details)
         }
         ErrorCode::PropertyConstraintViolation => {
-            format!("[4, {}, \"PropertyConstraintViolation\", \"Payload for Action is \
-                syntactically correct but at least one of the fields violates occurrence \
-                constraints\", {}]", msg_id, error_details)
+            format!("[4, {}, \"PropertyConstraintViolation\", \"Payload is syntactically \
+                correct but at least one field contains an invalid value\", {}]", msg_id, error_details)
         }
         ErrorCode::ProtocolError => {
             format!("[4, {}, \"ProtocolError\", \"Payload for Action is not conform the PDU \
@@ -142,71 +219,135 @@ pub fn wrap_call_error_result(msg_id: &String, error_code: ErrorCode, error_deta
     }
 }
 
-pub fn unpack_ocpp_message(msg: &String) -> Result<HashMap<&str, String>, String> {
+/// Splits an OCPP-J frame into its fields. A frame that is not a JSON array with the fields of its
+/// MessageTypeId is answered with an RpcFrameworkError, with MessageId "-1" when not even the
+/// MessageId can be read.
+pub fn unpack_ocpp_message(msg: &str) -> Result<HashMap<&str, String>, String> {
+    let rpc_framework_error = |message_id: &String, description: &str| {
+        wrap_call_error_result(message_id, ErrorCode::RpcFrameworkError,
+                               &json!({ "description": description }).to_string())
+    };
+    let unknown_message_id = String::from("\"-1\"");
+    let json: Vec<Value> = match serde_json::from_str(msg) {
+        Ok(json) => json,
+        Err(e) => return Err(rpc_framework_error(&unknown_message_id,
+                                                 &format!("the frame is not a JSON array: {}", e))),
+    };
+    let message_id = match json.get(1) {
+        Some(Value::String(message_id)) => json!(message_id).to_string(),
+        _ => return Err(rpc_framework_error(&unknown_message_id, "the MessageId is not a string")),
+    };
+    let field = |index: usize, name: &str| json.get(index).map(Value::to_string)
+        .ok_or_else(|| rpc_framework_error(&message_id, &format!("the {} is missing", name)));
     let mut hash: HashMap<&str, String> = HashMap::new();
-    let json: Value = serde_json::from_str(msg).expect("JSON string is wrong");
-    let message_type_id = json.get(0).unwrap().as_u64();
-    match message_type_id {
-        Some(2) => {
-            hash.insert("MessageTypeId", "2".to_string());
-            hash.insert("MessageId", (json.get(1).unwrap()).to_string());
-            hash.insert("Action", (json.get(2).unwrap()).to_string());
-            hash.insert("Payload", (json.get(3).unwrap()).to_string());
-            Ok(hash.clone())
+    match json.first().and_then(Value::as_u64) {
+        // SEND, OCPP 2.1 only: [6, "<MessageId>", "<Action>", {<Payload>}], never answered
+        Some(message_type_id @ 2) | Some(message_type_id @ 6) => {
+            if !json.get(2).is_some_and(Value::is_string) {
+                return Err(rpc_framework_error(&message_id, "the Action is not a string"));
+            }
+            hash.insert("MessageTypeId", message_type_id.to_string());
+            hash.insert("Action", field(2, "Action")?);
+            hash.insert("Payload", field(3, "Payload")?);
         }
         Some(3) => {
             hash.insert("MessageTypeId", "3".to_string());
-            hash.insert("MessageId", (json.get(1).unwrap()).to_string());
-            hash.insert("Payload", (json.get(2).unwrap()).to_string());
-            Ok(hash.clone())
+            hash.insert("Payload", field(2, "Payload")?);
         }
         Some(4) => {
             hash.insert("MessageTypeId", "4".to_string());
-            hash.insert("MessageId", (json.get(1).unwrap()).to_string());
-            hash.insert("ErrorCode", (json.get(2).unwrap()).to_string());
-            hash.insert("ErrorDescription", (json.get(3).unwrap()).to_string());
-            hash.insert("ErrorDetails", (json.get(4).unwrap()).to_string());
-            Ok(hash.clone())
-        }
-        None => {
-            let message_id = (json.get(1).unwrap()).to_string();
-            Err(wrap_call_error_result(&message_id, ErrorCode::MessageTypeNotSupported, msg))
+            hash.insert("ErrorCode", field(2, "ErrorCode")?);
+            hash.insert("ErrorDescription", field(3, "ErrorDescription")?);
+            hash.insert("ErrorDetails", field(4, "ErrorDetails")?);
         }
         _ => {
-            let message_id = (json.get(1).unwrap()).to_string();
-            Err(wrap_call_error_result(&message_id, ErrorCode::MessageTypeNotSupported, msg))
+            let description = format!("MessageTypeId {} is not supported", json[0]);
+            return Err(wrap_call_error_result(&message_id, ErrorCode::MessageTypeNotSupported,
+                                              &json!({ "description": description }).to_string()));
         }
     }
+    hash.insert("MessageId", message_id);
+    Ok(hash)
+}
+
+/// Decodes the payload of a call from a charging station and checks the field constraints of
+/// its message struct. On failure the CallError to send back is returned.
+pub fn decode_call<T: DeserializeOwned + Validate>(message_id: &String, payload: &str) -> Result<T, String> {
+    let request: T = serde_json::from_str(payload).map_err(|e| {
+        wrap_call_error_result(message_id, ErrorCode::FormatViolation,
+                               &json!({ "description": e.to_string() }).to_string())
+    })?;
+    request.validate().map_err(|errors| {
+        let (error_code, details) = constraint_violation(&errors);
+        wrap_call_error_result(message_id, error_code, &details.to_string())
+    })?;
+    Ok(request)
+}
+
+/// Turns the first failed field constraint into an error code and CallError details naming the
+/// field by its JSON pointer, e.g. `{"pointer": "/idToken/idToken", ...}`
+pub fn constraint_violation(errors: &ValidationErrors) -> (ErrorCode, Value) {
+    let (pointer, error) = match first_violation(errors) {
+        Some(violation) => violation,
+        None => return (ErrorCode::PropertyConstraintViolation, json!({})),
+    };
+    let mut params = error.params.clone();
+    let value = params.remove("value");
+    // a length constraint on an array limits the number of elements
+    let error_code = match value {
+        Some(Value::Array(_)) => ErrorCode::OccurrenceConstraintViolation,
+        _ => ErrorCode::PropertyConstraintViolation,
+    };
+    (error_code, json!({
+        "pointer": pointer,
+        "description": format!("{} constraint violated: {}", error.code, json!(params)),
+    }))
 }
 
-pub fn boot_notification_response(message_id: &String, payload: &String) -> String {
-    match serde_json::from_str(&payload) as Result<requests::BootNotificationRequest, serde_json::Error> {
+fn first_violation(errors: &ValidationErrors) -> Option<(String, &ValidationError)> {
+    // fields are sorted, so the same payload always reports the same field
+    let mut fields: Vec<_> = errors.errors().iter().collect();
+    fields.sort_by_key(|(field, _)| **field);
+    let (field, kind) = fields.into_iter().next()?;
+    match kind {
+        ValidationErrorsKind::Field(errors) => {
+            errors.first().map(|error| (format!("/{}", field), error))
+        }
+        ValidationErrorsKind::Struct(errors) => {
+            first_violation(errors).map(|(pointer, error)| (format!("/{}{}", field, pointer), error))
+        }
+        ValidationErrorsKind::List(items) => {
+            let (index, errors) = items.iter().next()?;
+            first_violation(errors).map(|(pointer, error)| (format!("/{}/{}{}", field, index, pointer), error))
+        }
+    }
+}
+
+/// Accepts the station, which is to send a Heartbeat every `interval` seconds
+pub fn boot_notification_response(message_id: &String, payload: &str, interval: i64) -> String {
+    match decode_call::<requests::BootNotificationRequest>(message_id, payload) {
         Ok(_) => {
             let at_now:DateTime<Utc> = Utc::now();
             let boot_response: responses::BootNotificationResponse = responses::BootNotificationResponse {
                 current_time: at_now.to_rfc3339_opts(SecondsFormat::Millis, false),
                 custom_data: None,
-                interval: 3600, // 1 hour
+                interval,
                 status: responses::RegistrationStatusEnumType::Accepted,
                 status_info: None
             };
             wrap_call_result(message_id, serde_json::to_string(&boot_response).unwrap())
         }
-        Err(e) => {
-            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
-        }
+        Err(call_error) => call_error
     }
 }
 
-pub fn status_notification_response(message_id: &String, payload: &String) -> String {
-    match serde_json::from_str(&payload) as Result<requests::StatusNotificationRequest, serde_json::Error> {
+pub fn status_notification_response(message_id: &String, payload: &str) -> String {
+    match decode_call::<requests::StatusNotificationRequest>(message_id, payload) {
         Ok(_) => {
             let response = responses::StatusNotificationResponse{ custom_data: None };
             wrap_call_result(message_id, serde_json::to_string(&response).unwrap())
         }
-        Err(e) => {
-            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
-        }
+        Err(call_error) => call_error
     }
 }
 
@@ -219,9 +360,21 @@ pub fn heartbeat_response(message_id: &String) -> String {
     wrap_call_result(message_id, serde_json::to_string(&heartbeat_resp).unwrap())
 }
 
-pub fn authorize_response(message_id: &String, payload: &String) -> String {
-    match serde_json::from_str(&payload) as Result<requests::AuthorizeRequest, serde_json::Error> {
-        Ok(_) => {
+impl From<services::AuthorizationStatus> for responses::AuthorizationStatusEnumType {
+    fn from(status: services::AuthorizationStatus) -> Self {
+        match status {
+            services::AuthorizationStatus::Accepted => responses::AuthorizationStatusEnumType::Accepted,
+            services::AuthorizationStatus::Blocked => responses::AuthorizationStatusEnumType::Blocked,
+            services::AuthorizationStatus::Expired => responses::AuthorizationStatusEnumType::Expired,
+            services::AuthorizationStatus::Invalid => responses::AuthorizationStatusEnumType::Invalid,
+            services::AuthorizationStatus::Unknown => responses::AuthorizationStatusEnumType::Unknown,
+        }
+    }
+}
+
+pub fn authorize_response(message_id: &String, payload: &str) -> String {
+    match decode_call::<requests::AuthorizeRequest>(message_id, payload) {
+        Ok(request) => {
             let authorize_resp: responses::AuthorizeResponse = responses::AuthorizeResponse{
                 certificate_status: None,
                 custom_data: None,
@@ -234,56 +387,98 @@ pub fn authorize_response(message_id: &String, payload: &String) -> String {
                     language1: None,
                     language2: None,
                     personal_message: None,
-                    status: responses::AuthorizationStatusEnumType::Accepted
+                    status: services::authorize(&request.id_token.id_token).into()
                 }
             };
             wrap_call_result(message_id, serde_json::to_string(&authorize_resp).unwrap())
         },
-        Err(e) => {
-            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
-        }
+        Err(call_error) => call_error
     }
 }
 
-pub fn notify_event_response(message_id: &String, payload: &String) -> String {
-    match serde_json::from_str(&payload) as Result<requests::NotifyEventRequest, serde_json::Error> {
+pub fn notify_event_response(message_id: &String, payload: &str) -> String {
+    match decode_call::<requests::NotifyEventRequest>(message_id, payload) {
         Ok(_) => {
             let notify_event_response = responses::NotifyEventResponse{
                 custom_data: None
             };
             wrap_call_result(message_id, serde_json::to_string(&notify_event_response).unwrap())
         },
-        Err(e) => {
-            wrap_call_error_result(message_id, ErrorCode::FormatViolation,
-                                   &format!("{:#?}", e))
-        }
+        Err(call_error) => call_error
     }
 }
 
-pub fn notify_report_response(message_id: &String, payload: &String) -> String {
-    match serde_json::from_str(&payload) as Result<requests::NotifyReportRequest, serde_json::Error> {
+pub fn notify_report_response(message_id: &String, payload: &str) -> String {
+    match decode_call::<requests::NotifyReportRequest>(message_id, payload) {
         Ok(_) => {
             let notify_report_response = responses::NotifyReportResponse{ custom_data: None };
             wrap_call_result(message_id,
                              serde_json::to_string(&notify_report_response).unwrap())
         },
-        Err(e) => {
-            wrap_call_error_result(message_id, ErrorCode::FormatViolation,
-                                   &format!("{:#?}", e))
-        }
+        Err(call_error) => call_error
     }
 }
 
-pub fn transaction_event_response(message_id: &String, payload: &String,
+pub fn transaction_event_response(message_id: &String, payload: &str,
                                   response: responses::TransactionEventResponse) -> String {
-    match serde_json::from_str(&payload) as Result<requests::TransactionEventRequest, serde_json::Error> {
+    match decode_call::<requests::TransactionEventRequest>(message_id, payload) {
         Ok(_) => {
             wrap_call_result(message_id,
                              serde_json::to_string(&response).unwrap())
         },
-        Err(e) => {
-            wrap_call_error_result(message_id, ErrorCode::FormatViolation,
-                                   &format!("{:#?}", e))
-        }
+        Err(call_error) => call_error
     }
-}
\ No newline at end of file
+}
+pub fn get_certificate_status_response(message_id: &String, payload: &str) -> String {
+    match decode_call::<requests::GetCertificateStatusRequest>(message_id, payload) {
+        Ok(_) => {
+            // the server has no OCSP responder to forward the request to
+            let get_certificate_status_response = responses::GetCertificateStatusResponse{
+                custom_data: None,
+                ocsp_result: None,
+                status: responses::GetCertificateStatusEnumType::Failed,
+                status_info: Some(responses::StatusInfoType{
+                    additional_info: Some(String::from("No OCSP responder is configured")),
+                    custom_data: None,
+                    reason_code: String::from("NoOcspResponder"),
+                }),
+            };
+            wrap_call_result(message_id,
+                             serde_json::to_string(&get_certificate_status_response).unwrap())
+        },
+        Err(call_error) => call_error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_code_of(frame: &str) -> (String, String) {
+        let error: Vec<Value> = serde_json::from_str(&unpack_ocpp_message(frame).unwrap_err()).unwrap();
+        (error[1].as_str().unwrap().to_string(), error[2].as_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn frames_are_split_into_their_fields() {
+        let call = unpack_ocpp_message(r#"[2, "19223201", "Heartbeat", {}]"#).unwrap();
+        assert_eq!(call.get("MessageTypeId").unwrap(), "2");
+        assert_eq!(call.get("MessageId").unwrap(), "\"19223201\"");
+        assert_eq!(call.get("Action").unwrap(), "\"Heartbeat\"");
+        assert_eq!(call.get("Payload").unwrap(), "{}");
+        let call_error = unpack_ocpp_message(r#"[4, "1", "NotSupported", "", {}]"#).unwrap();
+        assert_eq!(call_error.get("ErrorCode").unwrap(), "\"NotSupported\"");
+    }
+
+    #[test]
+    fn broken_frames_are_answered_with_a_call_error() {
+        assert_eq!(error_code_of("not json"), (String::from("-1"), String::from("RpcFrameworkError")));
+        assert_eq!(error_code_of(r#"{"a": 1}"#), (String::from("-1"), String::from("RpcFrameworkError")));
+        assert_eq!(error_code_of("[2]"), (String::from("-1"), String::from("RpcFrameworkError")));
+        assert_eq!(error_code_of(r#"[2, "7", "Heartbeat"]"#), (String::from("7"), String::from("RpcFrameworkError")));
+        assert_eq!(error_code_of(r#"[2, "7", 5, {}]"#), (String::from("7"), String::from("RpcFrameworkError")));
+        assert_eq!(error_code_of(r#"[3, "7"]"#), (String::from("7"), String::from("RpcFrameworkError")));
+        assert_eq!(error_code_of(r#"["2", "7", "Heartbeat", {}]"#),
+                   (String::from("7"), String::from("MessageTypeNotSupported")));
+    }
+}
@@ -1,40 +1,59 @@
 use serde::{Serialize, Deserialize};
-use serde_json::Value;
+use validator::Validate;
+use schemars::JsonSchema;
 
 // structures created with the help of https://app.quicktype.io/ and json schema provided by
 // https://www.openchargealliance.org/
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct AuthorizeRequest {
     /// The X.509 certificated presented by EV and encoded in PEM format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 5500))]
     pub certificate: Option<String>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "idToken")]
+    #[validate]
     pub id_token: IdTokenType,
     #[serde(rename = "iso15118CertificateHashData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub iso15118_certificate_hash_data: Option<Vec<OcspRequestDataType>>,
 }
 
 /// This class does not get 'AdditionalProperties = false' in the schema generation, so it
 /// can be extended with arbitrary JSON properties to allow adding custom data.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct CustomDataType {
     #[serde(rename = "vendorId")]
+    #[validate(length(max = 255))]
     pub vendor_id: String,
+    /// the vendor specific properties, kept as they are
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains a case insensitive identifier to use for the authorization and the type of
 /// authorization to support multiple forms of identifiers.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct IdTokenType {
     #[serde(rename = "additionalInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub additional_info: Option<Vec<AdditionalInfoType>>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// IdToken is case insensitive. Might hold the hidden id of an RFID tag, but can for example
     /// also contain a UUID.
     #[serde(rename = "idToken")]
+    #[validate(length(max = 36))]
     pub id_token: String,
     #[serde(rename = "type")]
     pub id_token_type_type: IdTokenEnumType,
@@ -42,41 +61,51 @@ pub struct IdTokenType {
 
 /// Contains a case insensitive identifier to use for the authorization and the type of
 /// authorization to support multiple forms of identifiers.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct AdditionalInfoType {
     /// This field specifies the additional IdToken.
     #[serde(rename = "additionalIdToken")]
+    #[validate(length(max = 36))]
     pub additional_id_token: String,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// This defines the type of the additionalIdToken. This is a custom type, so the
     /// implementation needs to be agreed upon by all involved parties.
     #[serde(rename = "type")]
+    #[validate(length(max = 50))]
     pub additional_info_type_type: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct OcspRequestDataType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "hashAlgorithm")]
     pub hash_algorithm: HashAlgorithmEnumType,
     /// Hashed value of the issuers public key
     #[serde(rename = "issuerKeyHash")]
+    #[validate(length(max = 128))]
     pub issuer_key_hash: String,
     /// Hashed value of the Issuer DN (Distinguished Name).
     #[serde(rename = "issuerNameHash")]
+    #[validate(length(max = 128))]
     pub issuer_name_hash: String,
     /// This contains the responder URL (Case insensitive).
     #[serde(rename = "responderURL")]
+    #[validate(length(max = 512))]
     pub responder_url: String,
     /// The serial number of the certificate.
     #[serde(rename = "serialNumber")]
+    #[validate(length(max = 40))]
     pub serial_number: String,
 }
 
 /// Enumeration of possible idToken types.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum IdTokenEnumType {
     Central,
     #[serde(rename = "eMAID")]
@@ -92,7 +121,7 @@ pub enum IdTokenEnumType {
 }
 
 /// Used algorithms for the hashes provided.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum HashAlgorithmEnumType {
     #[serde(rename = "SHA256")]
     Sha256,
@@ -103,64 +132,82 @@ pub enum HashAlgorithmEnumType {
 }
 
 //=================================================================================================
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct BootNotificationRequest {
     #[serde(rename = "chargingStation")]
+    #[validate]
     pub charging_station: ChargingStationType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     pub reason: BootReasonEnumType,
 }
 
 /// Charge_ Point
 /// urn:x-oca:ocpp:uid:2:233122
 /// The physical system where an Electrical Vehicle (EV) can be charged.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ChargingStationType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// This contains the firmware version of the Charging Station.
     #[serde(rename = "firmwareVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 50))]
     pub firmware_version: Option<String>,
     /// Device. Model. CI20_ Text
     /// urn:x-oca:ocpp:uid:1:569325
     /// Defines the model of the device.
+    #[validate(length(max = 20))]
     pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub modem: Option<ModemType>,
     /// Device. Serial_ Number. Serial_ Number
     /// urn:x-oca:ocpp:uid:1:569324
     /// Vendor-specific device identifier.
     #[serde(rename = "serialNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 25))]
     pub serial_number: Option<String>,
     /// Identifies the vendor (not necessarily in a unique manner).
     #[serde(rename = "vendorName")]
+    #[validate(length(max = 50))]
     pub vendor_name: String,
 }
 
-/// This class does not get 'AdditionalProperties = false' in the schema generation, so it
-/// can be extended with arbitrary JSON properties to allow adding custom data.
-
+// This class does not get 'AdditionalProperties = false' in the schema generation, so it
+// can be extended with arbitrary JSON properties to allow adding custom data.
 
 /// Wireless_ Communication_ Module
 /// urn:x-oca:ocpp:uid:2:233306
 /// Defines parameters required for initiating and maintaining wireless communication with
 /// other devices.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ModemType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Wireless_ Communication_ Module. ICCID. CI20_ Text
     /// urn:x-oca:ocpp:uid:1:569327
     /// This contains the ICCID of the modem’s SIM card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 20))]
     pub iccid: Option<String>,
     /// Wireless_ Communication_ Module. IMSI. CI20_ Text
     /// urn:x-oca:ocpp:uid:1:569328
     /// This contains the IMSI of the modem’s SIM card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 20))]
     pub imsi: Option<String>,
 }
 
 /// This contains the reason for sending this message to the CSMS.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum BootReasonEnumType {
     ApplicationReset,
     FirmwareUpdate,
@@ -175,17 +222,19 @@ pub enum BootReasonEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct CancelReservationRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Id of the reservation to cancel.
     #[serde(rename = "reservationId")]
     pub reservation_id: i64,
 }
 
 //=================================================================================================
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct CertificateSignedRequest {
     /// The signed PEM encoded X.509 certificate. This can also contain the necessary sub CA
     /// certificates. In that case, the order of the bundle should follow the certificate chain,
@@ -195,11 +244,15 @@ pub struct CertificateSignedRequest {
     /// &lt;&lt;configkey-max-certificate-chain-size,MaxCertificateChainSize&gt;&gt; can be used
     /// to limit the maximum size of this field.
     #[serde(rename = "certificateChain")]
+    #[validate(length(max = 10000))]
     pub certificate_chain: String,
     #[serde(rename = "certificateType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub certificate_type: Option<CertificateSigningUseEnumType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
 }
 
 /// Indicates the type of the signed certificate that is returned. When omitted the
@@ -208,7 +261,7 @@ pub struct CertificateSignedRequest {
 /// in the &lt;&lt;signcertificaterequest,SignCertificateRequest&gt;&gt; that requested this
 /// certificate to be signed AND both the 15118 connection and the Charging Station
 /// connection are implemented.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum CertificateSigningUseEnumType {
     ChargingStationCertificate,
     V2GCertificate,
@@ -216,10 +269,14 @@ pub enum CertificateSigningUseEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ChangeAvailabilityRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub evse: Option<EvseType>,
     #[serde(rename = "operationalStatus")]
     pub operational_status: OperationalStatusEnumType,
@@ -228,13 +285,16 @@ pub struct ChangeAvailabilityRequest {
 /// EVSE
 /// urn:x-oca:ocpp:uid:2:233123
 /// Electric Vehicle Supply Equipment
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct EvseType {
     /// An id to designate a specific connector (on an EVSE) by connector index number.
     #[serde(rename = "connectorId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub connector_id: Option<i64>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Identified_ Object. MRID. Numeric_ Identifier
     /// urn:x-enexis:ecdm:uid:1:569198
     /// EVSE Identifier. This contains a number (&gt; 0) designating an EVSE of the Charging
@@ -243,7 +303,7 @@ pub struct EvseType {
 }
 
 /// This contains the type of availability change that the Charging Station should perform.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum OperationalStatusEnumType {
     Inoperative,
     Operative,
@@ -251,35 +311,45 @@ pub enum OperationalStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ClearCacheRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ClearChargingProfileRequest {
     #[serde(rename = "chargingProfileCriteria")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub charging_profile_criteria: Option<ClearChargingProfileType>,
     /// The Id of the charging profile to clear.
     #[serde(rename = "chargingProfileId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charging_profile_id: Option<i64>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
 }
 
 /// Charging_ Profile
 /// urn:x-oca:ocpp:uid:2:233255
 /// A ChargingProfile consists of a ChargingSchedule, describing the amount of power or
 /// current that can be delivered per time interval.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ClearChargingProfileType {
     #[serde(rename = "chargingProfilePurpose")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charging_profile_purpose: Option<ChargingProfilePurposeEnumType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Identified_ Object. MRID. Numeric_ Identifier
     /// urn:x-enexis:ecdm:uid:1:569198
     /// Specifies the id of the EVSE for which to clear charging profiles. An evseId of zero (0)
@@ -287,12 +357,15 @@ pub struct ClearChargingProfileType {
     /// parameter means the clearing applies to all charging profiles that match the other
     /// criteria in the request.
     #[serde(rename = "evseId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub evse_id: Option<i64>,
     /// Charging_ Profile. Stack_ Level. Counter
     /// urn:x-oca:ocpp:uid:1:569230
     /// Specifies the stackLevel for which charging profiles will be cleared, if they meet the
     /// other criteria in the request.
     #[serde(rename = "stackLevel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0))]
     pub stack_level: Option<i64>,
 }
 
@@ -300,7 +373,7 @@ pub struct ClearChargingProfileType {
 /// urn:x-oca:ocpp:uid:1:569231
 /// Specifies to purpose of the charging profiles that will be cleared, if they meet the
 /// other criteria in the request.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ChargingProfilePurposeEnumType {
     ChargingStationExternalConstraints,
     ChargingStationMaxProfile,
@@ -310,29 +383,34 @@ pub enum ChargingProfilePurposeEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ClearDisplayMessageRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Id of the message that SHALL be removed from the Charging Station.
     pub id: i64,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ClearedChargingLimitRequest {
     #[serde(rename = "chargingLimitSource")]
     pub charging_limit_source: ChargingLimitSourceEnumType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// EVSE Identifier.
     #[serde(rename = "evseId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub evse_id: Option<i64>,
 }
 
 /// Source of the charging limit.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ChargingLimitSourceEnumType {
     #[serde(rename = "CSO")]
     Cso,
@@ -345,20 +423,25 @@ pub enum ChargingLimitSourceEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ClearVariableMonitoringRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// List of the monitors to be cleared, identified by there Id.
+    #[validate(length(min = 1))]
     pub id: Vec<i64>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct CostUpdatedRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Current total cost, based on the information known by the CSMS, of the transaction
     /// including taxes. In the currency configured with the configuration Variable:
     /// [&lt;&lt;configkey-currency, Currency&gt;&gt;]
@@ -366,27 +449,36 @@ pub struct CostUpdatedRequest {
     pub total_cost: f64,
     /// Transaction Id of the transaction the current cost are asked for.
     #[serde(rename = "transactionId")]
+    #[validate(length(max = 36))]
     pub transaction_id: String,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct CustomerInformationRequest {
     /// Flag indicating whether the Charging Station should clear all information about the
     /// customer referred to.
     pub clear: bool,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "customerCertificate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub customer_certificate: Option<CertificateHashDataType>,
     /// A (e.g. vendor specific) identifier of the customer this request refers to. This field
     /// contains a custom identifier other than IdToken and Certificate.
     /// One of the possible identifiers (customerIdentifier, customerIdToken or
     /// customerCertificate) should be in the request message.
     #[serde(rename = "customerIdentifier")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 64))]
     pub customer_identifier: Option<String>,
     #[serde(rename = "idToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub id_token: Option<IdTokenType>,
     /// Flag indicating whether the Charging Station should return
     /// NotifyCustomerInformationRequest messages containing information about the customer
@@ -397,67 +489,84 @@ pub struct CustomerInformationRequest {
     pub request_id: i64,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct CertificateHashDataType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "hashAlgorithm")]
     pub hash_algorithm: HashAlgorithmEnumType,
     /// Hashed value of the issuers public key
     #[serde(rename = "issuerKeyHash")]
+    #[validate(length(max = 128))]
     pub issuer_key_hash: String,
     /// Hashed value of the Issuer DN (Distinguished Name).
     #[serde(rename = "issuerNameHash")]
+    #[validate(length(max = 128))]
     pub issuer_name_hash: String,
     /// The serial number of the certificate.
     #[serde(rename = "serialNumber")]
+    #[validate(length(max = 40))]
     pub serial_number: String,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct DataTransferRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Data without specified length or format. This needs to be decided by both parties (Open
     /// to implementation).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<serde_json::Value>,
     /// May be used to indicate a specific message or implementation.
     #[serde(rename = "messageId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 50))]
     pub message_id: Option<String>,
     /// This identifies the Vendor specific implementation
     #[serde(rename = "vendorId")]
+    #[validate(length(max = 255))]
     pub vendor_id: String,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct DeleteCertificateRequest {
     #[serde(rename = "certificateHashData")]
+    #[validate]
     pub certificate_hash_data: CertificateHashDataType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct FirmwareStatusNotificationRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// The request id that was provided in the
     /// UpdateFirmwareRequest that started this firmware update.
     /// This field is mandatory, unless the message was triggered by a TriggerMessageRequest AND
     /// there is no firmware update ongoing.
     #[serde(rename = "requestId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<i64>,
     pub status: FirmwareStatusEnumType,
 }
 
 /// This contains the progress status of the firmware installation.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum FirmwareStatusEnumType {
     DownloadFailed,
     DownloadPaused,
@@ -477,22 +586,26 @@ pub enum FirmwareStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct Get15118EvCertificateRequest {
     pub action: CertificateActionEnumType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Raw CertificateInstallationReq request from EV, Base64 encoded.
     #[serde(rename = "exiRequest")]
+    #[validate(length(max = 5600))]
     pub exi_request: String,
     /// Schema version currently used for the 15118 session between EV and Charging Station.
     /// Needed for parsing of the EXI stream by the CSMS.
     #[serde(rename = "iso15118SchemaVersion")]
+    #[validate(length(max = 50))]
     pub iso15118_schema_version: String,
 }
 
 /// Defines whether certificate needs to be installed or updated.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum CertificateActionEnumType {
     Install,
     Update,
@@ -500,10 +613,12 @@ pub enum CertificateActionEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetBaseReportRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "reportBase")]
     pub report_base: ReportBaseEnumType,
     /// The Id of the request.
@@ -512,7 +627,7 @@ pub struct GetBaseReportRequest {
 }
 
 /// This field specifies the report base.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ReportBaseEnumType {
     ConfigurationInventory,
     FullInventory,
@@ -521,26 +636,33 @@ pub enum ReportBaseEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetCertificateStatusRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "ocspRequestData")]
+    #[validate]
     pub ocsp_request_data: OcspRequestDataType,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetChargingProfilesRequest {
     #[serde(rename = "chargingProfile")]
+    #[validate]
     pub charging_profile: ChargingProfileCriterionType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// For which EVSE installed charging profiles SHALL be reported. If 0, only charging
     /// profiles installed on the Charging Station itself (the grid connection) SHALL be
     /// reported. If omitted, all installed charging profiles SHALL be reported.
     #[serde(rename = "evseId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub evse_id: Option<i64>,
     /// Reference identification that is to be used by the Charging Station in the
     /// &lt;&lt;reportchargingprofilesrequest, ReportChargingProfilesRequest&gt;&gt; when
@@ -553,38 +675,50 @@ pub struct GetChargingProfilesRequest {
 /// urn:x-oca:ocpp:uid:2:233255
 /// A ChargingProfile consists of ChargingSchedule, describing the amount of power or current
 /// that can be delivered per time interval.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ChargingProfileCriterionType {
     /// For which charging limit sources, charging profiles SHALL be reported. If omitted, the
     /// Charging Station SHALL not filter on chargingLimitSource.
     #[serde(rename = "chargingLimitSource")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
     pub charging_limit_source: Option<Vec<ChargingLimitSourceEnumType>>,
     /// List of all the chargingProfileIds requested. Any ChargingProfile that matches one of
     /// these profiles will be reported. If omitted, the Charging Station SHALL not filter on
     /// chargingProfileId. This field SHALL NOT contain more ids than set in
     /// &lt;&lt;configkey-charging-profile-entries,ChargingProfileEntries.maxLimit&gt;&gt;
     #[serde(rename = "chargingProfileId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
     pub charging_profile_id: Option<Vec<i64>>,
     #[serde(rename = "chargingProfilePurpose")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charging_profile_purpose: Option<ChargingProfilePurposeEnumType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Charging_ Profile. Stack_ Level. Counter
     /// urn:x-oca:ocpp:uid:1:569230
     /// Value determining level in hierarchy stack of profiles. Higher values have precedence
     /// over lower values. Lowest level is 0.
     #[serde(rename = "stackLevel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0))]
     pub stack_level: Option<i64>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetCompositeScheduleRequest {
     #[serde(rename = "chargingRateUnit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charging_rate_unit: Option<ChargingRateUnitEnumType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Length of the requested schedule in seconds.
     pub duration: i64,
     /// The ID of the EVSE for which the schedule is requested. When evseid=0, the Charging
@@ -594,7 +728,7 @@ pub struct GetCompositeScheduleRequest {
 }
 
 /// Can be used to force a power or current profile.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ChargingRateUnitEnumType {
     A,
     W,
@@ -602,24 +736,30 @@ pub enum ChargingRateUnitEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetDisplayMessagesRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// If provided the Charging Station shall return Display Messages of the given ids. This
     /// field SHALL NOT contain more ids than set in
     /// &lt;&lt;configkey-number-of-display-messages,NumberOfDisplayMessages.maxLimit&gt;&gt;
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
     pub id: Option<Vec<i64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<MessagePriorityEnumType>,
     /// The Id of this request.
     #[serde(rename = "requestId")]
     pub request_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<MessageStateEnumType>,
 }
 
 /// If provided the Charging Station shall return Display Messages with the given priority
 /// only.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum MessagePriorityEnumType {
     AlwaysFront,
     InFront,
@@ -627,7 +767,7 @@ pub enum MessagePriorityEnumType {
 }
 
 /// If provided the Charging Station shall return Display Messages with the given state only.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum MessageStateEnumType {
     Charging,
     Faulted,
@@ -637,17 +777,21 @@ pub enum MessageStateEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetInstalledCertificateIdsRequest {
     /// Indicates the type of certificates requested. When omitted, all certificate types are
     /// requested.
     #[serde(rename = "certificateType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
     pub certificate_type: Option<Vec<GetCertificateIdUseEnumType>>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum GetCertificateIdUseEnumType {
     #[serde(rename = "CSMSRootCertificate")]
     CsmsRootCertificate,
@@ -660,18 +804,23 @@ pub enum GetCertificateIdUseEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetLocalListVersionRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetLogRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[validate]
     pub log: LogParametersType,
     #[serde(rename = "logType")]
     pub log_type: LogEnumType,
@@ -681,42 +830,49 @@ pub struct GetLogRequest {
     /// This specifies how many times the Charging Station must try to upload the log before
     /// giving up. If this field is not present, it is left to Charging Station to decide how
     /// many times it wants to retry.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub retries: Option<i64>,
     /// The interval in seconds after which a retry may be attempted. If this field is not
     /// present, it is left to Charging Station to decide how long to wait between attempts.
     #[serde(rename = "retryInterval")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub retry_interval: Option<i64>,
 }
 
 /// Log
 /// urn:x-enexis:ecdm:uid:2:233373
 /// Generic class for the configuration of logging entries.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct LogParametersType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Log. Latest_ Timestamp. Date_ Time
     /// urn:x-enexis:ecdm:uid:1:569482
     /// This contains the date and time of the latest logging information to include in the
     /// diagnostics.
     #[serde(rename = "latestTimestamp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub latest_timestamp: Option<String>,
     /// Log. Oldest_ Timestamp. Date_ Time
     /// urn:x-enexis:ecdm:uid:1:569477
     /// This contains the date and time of the oldest logging information to include in the
     /// diagnostics.
     #[serde(rename = "oldestTimestamp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub oldest_timestamp: Option<String>,
     /// Log. Remote_ Location. URI
     /// urn:x-enexis:ecdm:uid:1:569484
     /// The URL of the location at the remote system where the log should be stored.
     #[serde(rename = "remoteLocation")]
+    #[validate(length(max = 512))]
     pub remote_location: String,
 }
 
 /// This contains the type of log file that the Charging Station
 /// should send.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum LogEnumType {
     DiagnosticsLog,
     SecurityLog,
@@ -724,14 +880,21 @@ pub enum LogEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetMonitoringReportRequest {
     #[serde(rename = "componentVariable")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub component_variable: Option<Vec<ComponentVariableType>>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// This field contains criteria for components for which a monitoring report is requested
     #[serde(rename = "monitoringCriteria")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
     pub monitoring_criteria: Option<Vec<MonitoringCriterionEnumType>>,
     /// The Id of the request.
     #[serde(rename = "requestId")]
@@ -739,42 +902,59 @@ pub struct GetMonitoringReportRequest {
 }
 
 /// Class to report components, variables and variable attributes and characteristics.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ComponentVariableType {
+    #[validate]
     pub component: ComponentType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub variable: Option<VariableType>,
 }
 
 /// A physical or logical component
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ComponentType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub evse: Option<EvseType>,
     /// Name of instance in case the component exists as multiple instances. Case Insensitive.
     /// strongly advised to use Camel Case.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 50))]
     pub instance: Option<String>,
     /// Name of the component. Name should be taken from the list of standardized component names
     /// whenever possible. Case Insensitive. strongly advised to use Camel Case.
+    #[validate(length(max = 50))]
     pub name: String,
 }
 
 /// Reference key to a component-variable.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct VariableType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Name of instance in case the variable exists as multiple instances. Case Insensitive.
     /// strongly advised to use Camel Case.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 50))]
     pub instance: Option<String>,
     /// Name of the variable. Name should be taken from the list of standardized variable names
     /// whenever possible. Case Insensitive. strongly advised to use Camel Case.
+    #[validate(length(max = 50))]
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum MonitoringCriterionEnumType {
     DeltaMonitoring,
     PeriodicMonitoring,
@@ -783,21 +963,28 @@ pub enum MonitoringCriterionEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetReportRequest {
     /// This field contains criteria for components for which a report is requested
     #[serde(rename = "componentCriteria")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
     pub component_criteria: Option<Vec<ComponentCriterionEnumType>>,
     #[serde(rename = "componentVariable")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub component_variable: Option<Vec<ComponentVariableType>>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// The Id of the request.
     #[serde(rename = "requestId")]
     pub request_id: i64,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ComponentCriterionEnumType {
     Active,
     Available,
@@ -807,38 +994,51 @@ pub enum ComponentCriterionEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetTransactionStatusRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// The Id of the transaction for which the status is requested.
     #[serde(rename = "transactionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 36))]
     pub transaction_id: Option<String>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetVariablesRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "getVariableData")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub get_variable_data: Vec<GetVariableDataType>,
 }
 
 /// Class to hold parameters for GetVariables request.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct GetVariableDataType {
     #[serde(rename = "attributeType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attribute_type: Option<AttributeEnumType>,
+    #[validate]
     pub component: ComponentType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[validate]
     pub variable: VariableType,
 }
 
 /// Attribute type for which value is requested. When absent, default Actual is assumed.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum AttributeEnumType {
     Actual,
     MaxSet,
@@ -848,26 +1048,31 @@ pub enum AttributeEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct HeartbeatRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct InstallCertificateRequest {
     /// A PEM encoded X.509 certificate.
+    #[validate(length(max = 5500))]
     pub certificate: String,
     #[serde(rename = "certificateType")]
     pub certificate_type: InstallCertificateUseEnumType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
 }
 
 /// Indicates the certificate type that is sent.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum InstallCertificateUseEnumType {
     #[serde(rename = "CSMSRootCertificate")]
     CsmsRootCertificate,
@@ -879,21 +1084,24 @@ pub enum InstallCertificateUseEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct LogStatusNotificationRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// The request id that was provided in GetLogRequest that started this log upload. This
     /// field is mandatory,
     /// unless the message was triggered by a TriggerMessageRequest AND there is no log upload
     /// ongoing.
     #[serde(rename = "requestId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<i64>,
     pub status: UploadLogStatusEnumType,
 }
 
 /// This contains the status of the log upload.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum UploadLogStatusEnumType {
     AcceptedCanceled,
     BadMessage,
@@ -909,10 +1117,12 @@ pub enum UploadLogStatusEnumType {
 
 /// Request_ Body
 /// urn:x-enexis:ecdm:uid:2:234744
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct MeterValuesRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Request_ Body. EVSEID. Numeric_ Identifier
     /// urn:x-enexis:ecdm:uid:1:571101
     /// This contains a number (&gt;0) designating an EVSE of the Charging Station. ‘0’ (zero) is
@@ -920,6 +1130,8 @@ pub struct MeterValuesRequest {
     #[serde(rename = "evseId")]
     pub evse_id: i64,
     #[serde(rename = "meterValue")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub meter_value: Vec<MeterValueType>,
 }
 
@@ -927,11 +1139,15 @@ pub struct MeterValuesRequest {
 /// urn:x-oca:ocpp:uid:2:233265
 /// Collection of one or more sampled values in MeterValuesRequest and TransactionEvent. All
 /// sampled values in a MeterValue are sampled at the same point in time.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct MeterValueType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "sampledValue")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub sampled_value: Vec<SampledValueType>,
     /// Meter_ Value. Timestamp. Date_ Time
     /// urn:x-oca:ocpp:uid:1:569259
@@ -946,17 +1162,27 @@ pub struct MeterValueType {
 /// To save on mobile data usage, default values of all of the optional fields are such that.
 /// The value without any additional fields will be interpreted, as a register reading of
 /// active import energy in Wh (Watt-hour) units.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SampledValueType {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub context: Option<ReadingContextEnumType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<LocationEnumType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub measurand: Option<MeasurandEnumType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub phase: Option<PhaseEnumType>,
     #[serde(rename = "signedMeterValue")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub signed_meter_value: Option<SignedMeterValueType>,
     #[serde(rename = "unitOfMeasure")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub unit_of_measure: Option<UnitOfMeasureType>,
     /// Sampled_ Value. Value. Measure
     /// urn:x-oca:ocpp:uid:1:569260
@@ -965,44 +1191,55 @@ pub struct SampledValueType {
 }
 
 /// Represent a signed version of the meter value.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SignedMeterValueType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Method used to encode the meter values before applying the digital signature algorithm.
     #[serde(rename = "encodingMethod")]
+    #[validate(length(max = 50))]
     pub encoding_method: String,
     /// Base64 encoded, sending depends on configuration variable _PublicKeyWithSignedMeterValue_.
     #[serde(rename = "publicKey")]
+    #[validate(length(max = 2500))]
     pub public_key: String,
     /// Base64 encoded, contains the signed data which might contain more then just the meter
     /// value. It can contain information like timestamps, reference to a customer etc.
     #[serde(rename = "signedMeterData")]
+    #[validate(length(max = 2500))]
     pub signed_meter_data: String,
     /// Method used to create the digital signature.
     #[serde(rename = "signingMethod")]
+    #[validate(length(max = 50))]
     pub signing_method: String,
 }
 
 /// Represents a UnitOfMeasure with a multiplier
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct UnitOfMeasureType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Multiplier, this value represents the exponent to base 10. I.e. multiplier 3 means 10
     /// raised to the 3rd power. Default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub multiplier: Option<i64>,
     /// Unit of the value. Default = "Wh" if the (default) measurand is an "Energy" type.
     /// This field SHALL use a value from the list Standardized Units of Measurements in Part 2
     /// Appendices.
     /// If an applicable unit is available in that list, otherwise a "custom" unit might be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 20))]
     pub unit: Option<String>,
 }
 
 /// Sampled_ Value. Context. Reading_ Context_ Code
 /// urn:x-oca:ocpp:uid:1:569261
 /// Type of detail value: start, end or sample. Default = "Sample.Periodic"
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ReadingContextEnumType {
     #[serde(rename = "Interruption.Begin")]
     InterruptionBegin,
@@ -1023,7 +1260,7 @@ pub enum ReadingContextEnumType {
 /// Sampled_ Value. Location. Location_ Code
 /// urn:x-oca:ocpp:uid:1:569265
 /// Indicates where the measured value has been sampled. Default =  "Outlet"
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum LocationEnumType {
     Body,
     Cable,
@@ -1036,7 +1273,7 @@ pub enum LocationEnumType {
 /// Sampled_ Value. Measurand. Measurand_ Code
 /// urn:x-oca:ocpp:uid:1:569263
 /// Type of measurement. Default = "Energy.Active.Import.Register"
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum MeasurandEnumType {
     #[serde(rename = "Current.Export")]
     CurrentExport,
@@ -1092,7 +1329,7 @@ pub enum MeasurandEnumType {
 /// Indicates how the measured value is to be interpreted. For instance between L1 and
 /// neutral (L1-N) Please note that not all values of phase are applicable to all Measurands.
 /// When phase is absent, the measured value is interpreted as an overall value.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum PhaseEnumType {
     L1,
     #[serde(rename = "L1-L2")]
@@ -1114,32 +1351,42 @@ pub enum PhaseEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct NotifyChargingLimitRequest {
     #[serde(rename = "chargingLimit")]
+    #[validate]
     pub charging_limit: ChargingLimitType,
     #[serde(rename = "chargingSchedule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1, max = 3))]
+    #[validate]
     pub charging_schedule: Option<Vec<ChargingScheduleType>>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// The charging schedule contained in this notification applies to an EVSE. evseId must be
     /// &gt; 0.
     #[serde(rename = "evseId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub evse_id: Option<i64>,
 }
 
 /// Charging_ Limit
 /// urn:x-enexis:ecdm:uid:2:234489
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ChargingLimitType {
     #[serde(rename = "chargingLimitSource")]
     pub charging_limit_source: ChargingLimitSourceEnumType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Charging_ Limit. Is_ Grid_ Critical. Indicator
     /// urn:x-enexis:ecdm:uid:1:570847
     /// Indicates whether the charging limit is critical for the grid.
     #[serde(rename = "isGridCritical")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_grid_critical: Option<bool>,
 }
 
@@ -1147,19 +1394,24 @@ pub struct ChargingLimitType {
 /// urn:x-oca:ocpp:uid:2:233256
 /// Charging schedule structure defines a list of charging periods, as used in:
 /// GetCompositeSchedule.conf and ChargingProfile.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ChargingScheduleType {
     #[serde(rename = "chargingRateUnit")]
     pub charging_rate_unit: ChargingRateUnitEnumType,
     #[serde(rename = "chargingSchedulePeriod")]
+    #[validate(length(min = 1, max = 1024))]
+    #[validate]
     pub charging_schedule_period: Vec<ChargingSchedulePeriodType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Charging_ Schedule. Duration. Elapsed_ Time
     /// urn:x-oca:ocpp:uid:1:569236
     /// Duration of the charging schedule in seconds. If the duration is left empty, the last
     /// period will continue indefinitely or until end of the transaction if
     /// chargingProfilePurpose = TxProfile.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i64>,
     /// Identifies the ChargingSchedule.
     pub id: i64,
@@ -1170,24 +1422,30 @@ pub struct ChargingScheduleType {
     /// algorithm to optimize the power allocation for in the case a charging process is
     /// inefficient at lower charging rates. Accepts at most one digit fraction (e.g. 8.1)
     #[serde(rename = "minChargingRate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub min_charging_rate: Option<f64>,
     #[serde(rename = "salesTariff")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub sales_tariff: Option<SalesTariffType>,
     /// Charging_ Schedule. Start_ Schedule. Date_ Time
     /// urn:x-oca:ocpp:uid:1:569237
     /// Starting point of an absolute schedule. If absent the schedule will be relative to start
     /// of charging.
     #[serde(rename = "startSchedule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub start_schedule: Option<String>,
 }
 
 /// Charging_ Schedule_ Period
 /// urn:x-oca:ocpp:uid:2:233257
 /// Charging schedule period structure defines a time period in a charging schedule.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ChargingSchedulePeriodType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Charging_ Schedule_ Period. Limit. Measure
     /// urn:x-oca:ocpp:uid:1:569241
     /// Charging rate limit during the schedule period, in the applicable chargingRateUnit, for
@@ -1198,12 +1456,14 @@ pub struct ChargingSchedulePeriodType {
     /// The number of phases that can be used for charging. If a number of phases is needed,
     /// numberPhases=3 will be assumed unless another number is given.
     #[serde(rename = "numberPhases")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub number_phases: Option<i64>,
     /// Values: 1..3, Used if numberPhases=1 and if the EVSE is capable of switching the phase
     /// connected to the EV, i.e. ACPhaseSwitchingSupported is defined and true. It’s not allowed
     /// unless both conditions above are true. If both conditions are true, and phaseToUse is
     /// omitted, the Charging Station / EVSE will make the selection on its own.
     #[serde(rename = "phaseToUse")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub phase_to_use: Option<i64>,
     /// Charging_ Schedule_ Period. Start_ Period. Elapsed_ Time
     /// urn:x-oca:ocpp:uid:1:569240
@@ -1217,10 +1477,12 @@ pub struct ChargingSchedulePeriodType {
 /// urn:x-oca:ocpp:uid:2:233272
 /// NOTE: This dataType is based on dataTypes from &lt;&lt;ref-ISOIEC15118-2,ISO
 /// 15118-2&gt;&gt;.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SalesTariffType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Identified_ Object. MRID. Numeric_ Identifier
     /// urn:x-enexis:ecdm:uid:1:569198
     /// SalesTariff identifier used to identify one sales tariff. An SAID remains a unique
@@ -1231,43 +1493,59 @@ pub struct SalesTariffType {
     /// Defines the overall number of distinct price levels used across all provided SalesTariff
     /// elements.
     #[serde(rename = "numEPriceLevels")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub num_e_price_levels: Option<i64>,
     /// Sales_ Tariff. Sales. Tariff_ Description
     /// urn:x-oca:ocpp:uid:1:569283
     /// A human readable title/short description of the sales tariff e.g. for HMI display
     /// purposes.
     #[serde(rename = "salesTariffDescription")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 32))]
     pub sales_tariff_description: Option<String>,
     #[serde(rename = "salesTariffEntry")]
+    #[validate(length(min = 1, max = 1024))]
+    #[validate]
     pub sales_tariff_entry: Vec<SalesTariffEntryType>,
 }
 
 /// Sales_ Tariff_ Entry
 /// urn:x-oca:ocpp:uid:2:233271
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SalesTariffEntryType {
     #[serde(rename = "consumptionCost")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1, max = 3))]
+    #[validate]
     pub consumption_cost: Option<Vec<ConsumptionCostType>>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Sales_ Tariff_ Entry. E_ Price_ Level. Unsigned_ Integer
     /// urn:x-oca:ocpp:uid:1:569281
     /// Defines the price level of this SalesTariffEntry (referring to NumEPriceLevels). Small
     /// values for the EPriceLevel represent a cheaper TariffEntry. Large values for the
     /// EPriceLevel represent a more expensive TariffEntry.
     #[serde(rename = "ePriceLevel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub e_price_level: Option<i64>,
     #[serde(rename = "relativeTimeInterval")]
+    #[validate]
     pub relative_time_interval: RelativeTimeIntervalType,
 }
 
 /// Consumption_ Cost
 /// urn:x-oca:ocpp:uid:2:233259
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ConsumptionCostType {
+    #[validate(length(min = 1, max = 3))]
+    #[validate]
     pub cost: Vec<CostType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Consumption_ Cost. Start_ Value. Numeric
     /// urn:x-oca:ocpp:uid:1:569246
     /// The lowest level of consumption that defines the starting point of this consumption
@@ -1278,7 +1556,7 @@ pub struct ConsumptionCostType {
 
 /// Cost
 /// urn:x-oca:ocpp:uid:2:233258
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct CostType {
     /// Cost. Amount. Amount
     /// urn:x-oca:ocpp:uid:1:569244
@@ -1289,22 +1567,28 @@ pub struct CostType {
     /// Values: -3..3, The amountMultiplier defines the exponent to base 10 (dec). The final
     /// value is determined by: amount * 10 ^ amountMultiplier
     #[serde(rename = "amountMultiplier")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub amount_multiplier: Option<i64>,
     #[serde(rename = "costKind")]
     pub cost_kind: CostKindEnumType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
 }
 
 /// Relative_ Timer_ Interval
 /// urn:x-oca:ocpp:uid:2:233270
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct RelativeTimeIntervalType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Relative_ Timer_ Interval. Duration. Elapsed_ Time
     /// urn:x-oca:ocpp:uid:1:569280
     /// Duration of the interval, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i64>,
     /// Relative_ Timer_ Interval. Start. Elapsed_ Time
     /// urn:x-oca:ocpp:uid:1:569279
@@ -1315,7 +1599,7 @@ pub struct RelativeTimeIntervalType {
 /// Cost. Cost_ Kind. Cost_ Kind_ Code
 /// urn:x-oca:ocpp:uid:1:569243
 /// The kind of cost referred to in the message element amount
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum CostKindEnumType {
     CarbonDioxideEmission,
     RelativePricePercentage,
@@ -1324,12 +1608,15 @@ pub enum CostKindEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct NotifyCustomerInformationRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// (Part of) the requested data. No format specified in which the data is returned. Should
     /// be human readable.
+    #[validate(length(max = 512))]
     pub data: String,
     /// Timestamp of the moment this message was generated at the Charging Station.
     #[serde(rename = "generatedAt")]
@@ -1342,16 +1629,22 @@ pub struct NotifyCustomerInformationRequest {
     pub seq_no: i64,
     /// “to be continued” indicator. Indicates whether another part of the monitoringData follows
     /// in an upcoming notifyMonitoringReportRequest message. Default value when omitted is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tbc: Option<bool>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct NotifyDisplayMessagesRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "messageInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub message_info: Option<Vec<MessageInfoType>>,
     /// The id of the &lt;&lt;getdisplaymessagesrequest,GetDisplayMessagesRequest&gt;&gt; that
     /// requested this message.
@@ -1359,66 +1652,82 @@ pub struct NotifyDisplayMessagesRequest {
     pub request_id: i64,
     /// "to be continued" indicator. Indicates whether another part of the report follows in an
     /// upcoming NotifyDisplayMessagesRequest message. Default value when omitted is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tbc: Option<bool>,
 }
 
 /// Message_ Info
 /// urn:x-enexis:ecdm:uid:2:233264
 /// Contains message details, for a message to be displayed on a Charging Station.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct MessageInfoType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub display: Option<ComponentType>,
     /// Message_ Info. End. Date_ Time
     /// urn:x-enexis:ecdm:uid:1:569257
     /// Until what date-time should this message be shown, after this date/time this message
     /// SHALL be removed.
     #[serde(rename = "endDateTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub end_date_time: Option<String>,
     /// Identified_ Object. MRID. Numeric_ Identifier
     /// urn:x-enexis:ecdm:uid:1:569198
     /// Master resource identifier, unique within an exchange context. It is defined within the
     /// OCPP context as a positive Integer value (greater or equal to zero).
     pub id: i64,
+    #[validate]
     pub message: MessageContentType,
     pub priority: MessagePriorityEnumType,
     /// Message_ Info. Start. Date_ Time
     /// urn:x-enexis:ecdm:uid:1:569256
     /// From what date-time should this message be shown. If omitted: directly.
     #[serde(rename = "startDateTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub start_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<MessageStateEnumType>,
     /// During which transaction shall this message be shown.
     /// Message SHALL be removed by the Charging Station after transaction has
     /// ended.
     #[serde(rename = "transactionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 36))]
     pub transaction_id: Option<String>,
 }
 
 /// Message_ Content
 /// urn:x-enexis:ecdm:uid:2:234490
 /// Contains message details, for a message to be displayed on a Charging Station.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct MessageContentType {
     /// Message_ Content. Content. Message
     /// urn:x-enexis:ecdm:uid:1:570852
     /// Message contents.
+    #[validate(length(max = 512))]
     pub content: String,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     pub format: MessageFormatEnumType,
     /// Message_ Content. Language. Language_ Code
     /// urn:x-enexis:ecdm:uid:1:570849
     /// Message language identifier. Contains a language code as defined in
     /// &lt;&lt;ref-RFC5646,[RFC5646]&gt;&gt;.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 8))]
     pub language: Option<String>,
 }
 
 /// Message_ Content. Format. Message_ Format_ Code
 /// urn:x-enexis:ecdm:uid:1:570848
 /// Format of the message.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum MessageFormatEnumType {
     #[serde(rename = "ASCII")]
     Ascii,
@@ -1432,34 +1741,45 @@ pub enum MessageFormatEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct NotifyEvChargingNeedsRequest {
     #[serde(rename = "chargingNeeds")]
+    #[validate]
     pub charging_needs: ChargingNeedsType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Defines the EVSE and connector to which the EV is connected. EvseId may not be 0.
     #[serde(rename = "evseId")]
     pub evse_id: i64,
     /// Contains the maximum schedule tuples the car supports per schedule.
     #[serde(rename = "maxScheduleTuples")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_schedule_tuples: Option<i64>,
 }
 
 /// Charging_ Needs
 /// urn:x-oca:ocpp:uid:2:233249
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ChargingNeedsType {
     #[serde(rename = "acChargingParameters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub ac_charging_parameters: Option<AcChargingParametersType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "dcChargingParameters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub dc_charging_parameters: Option<DcChargingParametersType>,
     /// Charging_ Needs. Departure_ Time. Date_ Time
     /// urn:x-oca:ocpp:uid:1:569223
     /// Estimated departure time of the EV.
     #[serde(rename = "departureTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub departure_time: Option<String>,
     #[serde(rename = "requestedEnergyTransfer")]
     pub requested_energy_transfer: EnergyTransferModeEnumType,
@@ -1468,10 +1788,12 @@ pub struct ChargingNeedsType {
 /// AC_ Charging_ Parameters
 /// urn:x-oca:ocpp:uid:2:233250
 /// EV AC charging parameters.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct AcChargingParametersType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// AC_ Charging_ Parameters. Energy_ Amount. Energy_ Amount
     /// urn:x-oca:ocpp:uid:1:569211
     /// Amount of energy requested (in Wh). This includes energy required for preconditioning.
@@ -1498,25 +1820,30 @@ pub struct AcChargingParametersType {
 /// DC_ Charging_ Parameters
 /// urn:x-oca:ocpp:uid:2:233251
 /// EV DC charging parameters
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct DcChargingParametersType {
     /// DC_ Charging_ Parameters. Bulk_ SOC. Percentage
     /// urn:x-oca:ocpp:uid:1:569222
     /// Percentage of SoC at which the EV considers a fast charging process to end. (possible
     /// values: 0 - 100)
     #[serde(rename = "bulkSoC")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bulk_so_c: Option<i64>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// DC_ Charging_ Parameters. Energy_ Amount. Energy_ Amount
     /// urn:x-oca:ocpp:uid:1:569217
     /// Amount of energy requested (in Wh). This inludes energy required for preconditioning.
     #[serde(rename = "energyAmount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub energy_amount: Option<i64>,
     /// DC_ Charging_ Parameters. EV_ Energy_ Capacity. Numeric
     /// urn:x-oca:ocpp:uid:1:569220
     /// Capacity of the electric vehicle battery (in Wh)
     #[serde(rename = "evEnergyCapacity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ev_energy_capacity: Option<i64>,
     /// DC_ Charging_ Parameters. EV_ Max. Current
     /// urn:x-oca:ocpp:uid:1:569215
@@ -1527,6 +1854,7 @@ pub struct DcChargingParametersType {
     /// urn:x-oca:ocpp:uid:1:569218
     /// Maximum power (in W) supported by the electric vehicle. Required for DC charging.
     #[serde(rename = "evMaxPower")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ev_max_power: Option<i64>,
     /// DC_ Charging_ Parameters. EV_ Max. Voltage
     /// urn:x-oca:ocpp:uid:1:569216
@@ -1538,18 +1866,20 @@ pub struct DcChargingParametersType {
     /// Percentage of SoC at which the EV considers the battery fully charged. (possible values:
     /// 0 - 100)
     #[serde(rename = "fullSoC")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub full_so_c: Option<i64>,
     /// DC_ Charging_ Parameters. State_ Of_ Charge. Numeric
     /// urn:x-oca:ocpp:uid:1:569219
     /// Energy available in the battery (in percent of the battery capacity)
     #[serde(rename = "stateOfCharge")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub state_of_charge: Option<i64>,
 }
 
 /// Charging_ Needs. Requested. Energy_ Transfer_ Mode_ Code
 /// urn:x-oca:ocpp:uid:1:569209
 /// Mode of energy transfer requested by the EV.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum EnergyTransferModeEnumType {
     #[serde(rename = "AC_single_phase")]
     AcSinglePhase,
@@ -1563,12 +1893,15 @@ pub enum EnergyTransferModeEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct NotifyEvChargingScheduleRequest {
     #[serde(rename = "chargingSchedule")]
+    #[validate]
     pub charging_schedule: ChargingScheduleType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// The charging schedule contained in this notification applies to an EVSE. EvseId must be
     /// &gt; 0.
     #[serde(rename = "evseId")]
@@ -1580,11 +1913,15 @@ pub struct NotifyEvChargingScheduleRequest {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct NotifyEventRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "eventData")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub event_data: Vec<EventDataType>,
     /// Timestamp of the moment this message was generated at the Charging Station.
     #[serde(rename = "generatedAt")]
@@ -1594,11 +1931,12 @@ pub struct NotifyEventRequest {
     pub seq_no: i64,
     /// “to be continued” indicator. Indicates whether another part of the report follows in an
     /// upcoming notifyEventRequest message. Default value when omitted is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tbc: Option<bool>,
 }
 
 /// Class to report an event notification for a component-variable.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct EventDataType {
     /// Actual value (_attributeType_ Actual) of the variable.
     ///
@@ -1607,15 +1945,21 @@ pub struct EventDataType {
     /// GetVariableResult.attributeValue, VariableAttribute.value and EventData.actualValue. The
     /// max size of these values will always remain equal.
     #[serde(rename = "actualValue")]
+    #[validate(length(max = 2500))]
     pub actual_value: String,
     /// Refers to the Id of an event that is considered to be the cause for this event.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cause: Option<i64>,
     /// _Cleared_ is set to true to report the clearing of a monitored situation, i.e. a 'return
     /// to normal'.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cleared: Option<bool>,
+    #[validate]
     pub component: ComponentType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Identifies the event. This field can be referred to as a cause by other events.
     #[serde(rename = "eventId")]
     pub event_id: i64,
@@ -1623,25 +1967,33 @@ pub struct EventDataType {
     pub event_notification_type: EventNotificationEnumType,
     /// Technical (error) code as reported by component.
     #[serde(rename = "techCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 50))]
     pub tech_code: Option<String>,
     /// Technical detail information as reported by component.
     #[serde(rename = "techInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 500))]
     pub tech_info: Option<String>,
     /// Timestamp of the moment the report was generated.
     pub timestamp: String,
     /// If an event notification is linked to a specific transaction, this field can be used to
     /// specify its transactionId.
     #[serde(rename = "transactionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 36))]
     pub transaction_id: Option<String>,
     pub trigger: EventTriggerEnumType,
+    #[validate]
     pub variable: VariableType,
     /// Identifies the VariableMonitoring which triggered the event.
     #[serde(rename = "variableMonitoringId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub variable_monitoring_id: Option<i64>,
 }
 
 /// Specifies the event notification type of the message.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum EventNotificationEnumType {
     CustomMonitor,
     HardWiredMonitor,
@@ -1650,7 +2002,7 @@ pub enum EventNotificationEnumType {
 }
 
 /// Type of monitor that triggered this event, e.g. exceeding a threshold value.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum EventTriggerEnumType {
     Alerting,
     Delta,
@@ -1659,13 +2011,18 @@ pub enum EventTriggerEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct NotifyMonitoringReportRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Timestamp of the moment this message was generated at the Charging Station.
     #[serde(rename = "generatedAt")]
     pub generated_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub monitor: Option<Vec<MonitoringDataType>>,
     /// The id of the GetMonitoringRequest that requested this report.
     #[serde(rename = "requestId")]
@@ -1675,25 +2032,34 @@ pub struct NotifyMonitoringReportRequest {
     pub seq_no: i64,
     /// “to be continued” indicator. Indicates whether another part of the monitoringData follows
     /// in an upcoming notifyMonitoringReportRequest message. Default value when omitted is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tbc: Option<bool>,
 }
 
 /// Class to hold parameters of SetVariableMonitoring request.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct MonitoringDataType {
+    #[validate]
     pub component: ComponentType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[validate]
     pub variable: VariableType,
     #[serde(rename = "variableMonitoring")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub variable_monitoring: Vec<VariableMonitoringType>,
 }
 
 /// A monitoring setting for a variable.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct VariableMonitoringType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Identifies the monitor.
     pub id: i64,
     /// The severity that will be assigned to an event that is triggered by this monitor. The
@@ -1736,7 +2102,7 @@ pub struct VariableMonitoringType {
 }
 
 /// The type of this monitor, e.g. a threshold, delta or periodic monitor.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum MonitorEnumType {
     Delta,
     LowerThreshold,
@@ -1747,14 +2113,19 @@ pub enum MonitorEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct NotifyReportRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Timestamp of the moment this message was generated at the Charging Station.
     #[serde(rename = "generatedAt")]
     pub generated_at: String,
     #[serde(rename = "reportData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub report_data: Option<Vec<ReportDataType>>,
     /// The id of the GetReportRequest  or GetBaseReportRequest that requested this report
     #[serde(rename = "requestId")]
@@ -1764,35 +2135,50 @@ pub struct NotifyReportRequest {
     pub seq_no: i64,
     /// “to be continued” indicator. Indicates whether another part of the report follows in an
     /// upcoming notifyReportRequest message. Default value when omitted is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tbc: Option<bool>,
 }
 
 /// Class to report components, variables and variable attributes and characteristics.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ReportDataType {
+    #[validate]
     pub component: ComponentType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[validate]
     pub variable: VariableType,
     #[serde(rename = "variableAttribute")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub variable_attribute: Vec<VariableAttributeType>,
     #[serde(rename = "variableCharacteristics")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub variable_characteristics: Option<VariableCharacteristicsType>,
 }
 
 /// Attribute data of a variable.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct VariableAttributeType {
     /// If true, value that will never be changed by the Charging Station at runtime. Default
     /// when omitted is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub constant: Option<bool>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mutability: Option<MutabilityEnumType>,
     /// If true, value will be persistent across system reboots or power down. Default when
     /// omitted is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub persistent: Option<bool>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub variable_attribute_type_type: Option<AttributeEnumType>,
     /// Value of the attribute. May only be omitted when mutability is set to 'WriteOnly'.
     ///
@@ -1800,39 +2186,47 @@ pub struct VariableAttributeType {
     /// &lt;&lt;configkey-reporting-value-size,ReportingValueSize&gt;&gt; can be used to limit
     /// GetVariableResult.attributeValue, VariableAttribute.value and EventData.actualValue. The
     /// max size of these values will always remain equal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 2500))]
     pub value: Option<String>,
 }
 
 /// Fixed read-only parameters of a variable.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct VariableCharacteristicsType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "dataType")]
     pub data_type: DataEnumType,
     /// Maximum possible value of this variable. When the datatype of this Variable is String,
     /// OptionList, SequenceList or MemberList, this field defines the maximum length of the
     /// (CSV) string.
     #[serde(rename = "maxLimit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_limit: Option<f64>,
     /// Minimum possible value of this variable.
     #[serde(rename = "minLimit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub min_limit: Option<f64>,
     /// Flag indicating if this variable supports monitoring.
     #[serde(rename = "supportsMonitoring")]
     pub supports_monitoring: bool,
     /// Unit of the variable. When the transmitted value has a unit, this field SHALL be included.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 16))]
     pub unit: Option<String>,
     /// Allowed values when variable is Option/Member/SequenceList.
     ///
     /// * OptionList: The (Actual) Variable value must be a single value from the reported (CSV)
-    /// enumeration list.
+    ///   enumeration list.
     ///
     /// * MemberList: The (Actual) Variable value  may be an (unordered) (sub-)set of the
-    /// reported (CSV) valid values list.
+    ///   reported (CSV) valid values list.
     ///
     /// * SequenceList: The (Actual) Variable value  may be an ordered (priority, etc)  (sub-)set
-    /// of the reported (CSV) valid values.
+    ///   of the reported (CSV) valid values.
     ///
     /// This is a comma separated list.
     ///
@@ -1841,11 +2235,13 @@ pub struct VariableCharacteristicsType {
     /// limit SetVariableData.attributeValue and VariableCharacteristics.valueList. The max size
     /// of these values will always remain equal.
     #[serde(rename = "valuesList")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 1000))]
     pub values_list: Option<String>,
 }
 
 /// Defines the mutability of this attribute. Default is ReadWrite when omitted.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum MutabilityEnumType {
     ReadOnly,
     ReadWrite,
@@ -1853,7 +2249,7 @@ pub enum MutabilityEnumType {
 }
 
 /// Data type of this variable.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum DataEnumType {
     #[serde(rename = "boolean")]
     Boolean,
@@ -1872,15 +2268,18 @@ pub enum DataEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct PublishFirmwareRequest {
     /// The MD5 checksum over the entire firmware file as a hexadecimal string of length 32.
+    #[validate(length(max = 32))]
     pub checksum: String,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// This contains a string containing a URI pointing to a
     /// location from which to retrieve the firmware.
+    #[validate(length(max = 512))]
     pub location: String,
     /// The Id of the request.
     #[serde(rename = "requestId")]
@@ -1904,25 +2303,30 @@ pub struct PublishFirmwareRequest {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct PublishFirmwareStatusNotificationRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Required if status is Published. Can be multiple URI’s, if the Local Controller supports
     /// e.g. HTTP, HTTPS, and FTP.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
     pub location: Option<Vec<String>>,
     /// The request id that was
     /// provided in the
     /// PublishFirmwareRequest which
     /// triggered this action.
     #[serde(rename = "requestId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<i64>,
     pub status: PublishFirmwareStatusEnumType,
 }
 
 /// This contains the progress status of the publishfirmware
 /// installation.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum PublishFirmwareStatusEnumType {
     ChecksumVerified,
     DownloadFailed,
@@ -1938,14 +2342,18 @@ pub enum PublishFirmwareStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ReportChargingProfilesRequest {
     #[serde(rename = "chargingLimitSource")]
     pub charging_limit_source: ChargingLimitSourceEnumType,
     #[serde(rename = "chargingProfile")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub charging_profile: Vec<ChargingProfileType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// The evse to which the charging profile applies. If evseId = 0, the message contains an
     /// overall limit for the Charging Station.
     #[serde(rename = "evseId")]
@@ -1959,6 +2367,7 @@ pub struct ReportChargingProfilesRequest {
     pub request_id: i64,
     /// To Be Continued. Default value when omitted: false. false indicates that there are no
     /// further messages as part of this report.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tbc: Option<bool>,
 }
 
@@ -1966,50 +2375,60 @@ pub struct ReportChargingProfilesRequest {
 /// urn:x-oca:ocpp:uid:2:233255
 /// A ChargingProfile consists of ChargingSchedule, describing the amount of power or current
 /// that can be delivered per time interval.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ChargingProfileType {
     #[serde(rename = "chargingProfileKind")]
     pub charging_profile_kind: ChargingProfileKindEnumType,
     #[serde(rename = "chargingProfilePurpose")]
     pub charging_profile_purpose: ChargingProfilePurposeEnumType,
     #[serde(rename = "chargingSchedule")]
+    #[validate(length(min = 1, max = 3))]
+    #[validate]
     pub charging_schedule: Vec<ChargingScheduleType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Identified_ Object. MRID. Numeric_ Identifier
     /// urn:x-enexis:ecdm:uid:1:569198
     /// Id of ChargingProfile.
     pub id: i64,
     #[serde(rename = "recurrencyKind")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub recurrency_kind: Option<RecurrencyKindEnumType>,
     /// Charging_ Profile. Stack_ Level. Counter
     /// urn:x-oca:ocpp:uid:1:569230
     /// Value determining level in hierarchy stack of profiles. Higher values have precedence
     /// over lower values. Lowest level is 0.
     #[serde(rename = "stackLevel")]
+    #[validate(range(min = 0))]
     pub stack_level: i64,
     /// SHALL only be included if ChargingProfilePurpose is set to TxProfile. The transactionId
     /// is used to match the profile to a specific transaction.
     #[serde(rename = "transactionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 36))]
     pub transaction_id: Option<String>,
     /// Charging_ Profile. Valid_ From. Date_ Time
     /// urn:x-oca:ocpp:uid:1:569234
     /// Point in time at which the profile starts to be valid. If absent, the profile is valid as
     /// soon as it is received by the Charging Station.
     #[serde(rename = "validFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub valid_from: Option<String>,
     /// Charging_ Profile. Valid_ To. Date_ Time
     /// urn:x-oca:ocpp:uid:1:569235
     /// Point in time at which the profile stops to be valid. If absent, the profile is valid
     /// until it is replaced by another profile.
     #[serde(rename = "validTo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub valid_to: Option<String>,
 }
 
 /// Charging_ Profile. Charging_ Profile_ Kind. Charging_ Profile_ Kind_ Code
 /// urn:x-oca:ocpp:uid:1:569232
 /// Indicates the kind of schedule.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ChargingProfileKindEnumType {
     Absolute,
     Recurring,
@@ -2019,7 +2438,7 @@ pub enum ChargingProfileKindEnumType {
 /// Charging_ Profile. Recurrency_ Kind. Recurrency_ Kind_ Code
 /// urn:x-oca:ocpp:uid:1:569233
 /// Indicates the start point of a recurrence.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum RecurrencyKindEnumType {
     Daily,
     Weekly,
@@ -2027,18 +2446,26 @@ pub enum RecurrencyKindEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct RequestStartTransactionRequest {
     #[serde(rename = "chargingProfile")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub charging_profile: Option<ChargingProfileType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Number of the EVSE on which to start the transaction. EvseId SHALL be &gt; 0
     #[serde(rename = "evseId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub evse_id: Option<i64>,
     #[serde(rename = "groupIdToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub group_id_token: Option<IdTokenType>,
     #[serde(rename = "idToken")]
+    #[validate]
     pub id_token: IdTokenType,
     /// Id given by the server to this start request. The Charging Station might return this in
     /// the &lt;&lt;transactioneventrequest, TransactionEventRequest&gt;&gt;, letting the server
@@ -2049,21 +2476,26 @@ pub struct RequestStartTransactionRequest {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct RequestStopTransactionRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// The identifier of the transaction which the Charging Station is requested to stop.
     #[serde(rename = "transactionId")]
+    #[validate(length(max = 36))]
     pub transaction_id: String,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ReservationStatusUpdateRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// The ID of the reservation.
     #[serde(rename = "reservationId")]
     pub reservation_id: i64,
@@ -2072,7 +2504,7 @@ pub struct ReservationStatusUpdateRequest {
 }
 
 /// The updated reservation status.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ReservationUpdateStatusEnumType {
     Expired,
     Removed,
@@ -2080,28 +2512,35 @@ pub enum ReservationUpdateStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ReserveNowRequest {
     #[serde(rename = "connectorType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub connector_type: Option<ConnectorEnumType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// This contains ID of the evse to be reserved.
     #[serde(rename = "evseId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub evse_id: Option<i64>,
     /// Date and time at which the reservation expires.
     #[serde(rename = "expiryDateTime")]
     pub expiry_date_time: String,
     #[serde(rename = "groupIdToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub group_id_token: Option<IdTokenType>,
     /// Id of reservation.
     pub id: i64,
     #[serde(rename = "idToken")]
+    #[validate]
     pub id_token: IdTokenType,
 }
 
 /// This field specifies the connector type.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ConnectorEnumType {
     #[serde(rename = "cCCS1")]
     CCcs1,
@@ -2145,20 +2584,23 @@ pub enum ConnectorEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ResetRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// This contains the ID of a specific EVSE that needs to be reset, instead of the entire
     /// Charging Station.
     #[serde(rename = "evseId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub evse_id: Option<i64>,
     #[serde(rename = "type")]
     pub reset_request_type: ResetEnumType,
 }
 
 /// This contains the type of reset that the Charging Station or EVSE should perform.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ResetEnumType {
     Immediate,
     OnIdle,
@@ -2166,27 +2608,37 @@ pub enum ResetEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SecurityEventNotificationRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Additional information about the occurred security event.
     #[serde(rename = "techInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 255))]
     pub tech_info: Option<String>,
     /// Date and time at which the event occurred.
     pub timestamp: String,
     /// Type of the security event. This value should be taken from the Security events list.
     #[serde(rename = "type")]
+    #[validate(length(max = 50))]
     pub security_event_notification_request_type: String,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SendLocalListRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "localAuthorizationList")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub local_authorization_list: Option<Vec<AuthorizationData>>,
     #[serde(rename = "updateType")]
     pub update_type: UpdateEnumType,
@@ -2198,13 +2650,18 @@ pub struct SendLocalListRequest {
 }
 
 /// Contains the identifier to use for authorization.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct AuthorizationData {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "idToken")]
+    #[validate]
     pub id_token: IdTokenType,
     #[serde(rename = "idTokenInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub id_token_info: Option<IdTokenInfoType>,
 }
 
@@ -2214,38 +2671,52 @@ pub struct AuthorizationData {
 /// It is advised to not stop charging for a token that expires during charging, as
 /// ExpiryDate is only used for caching purposes. If ExpiryDate is not given, the status has
 /// no end date.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct IdTokenInfoType {
     /// ID_ Token. Expiry. Date_ Time
     /// urn:x-oca:ocpp:uid:1:569373
     /// Date and Time after which the token must be considered invalid.
     #[serde(rename = "cacheExpiryDateTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cache_expiry_date_time: Option<String>,
     /// Priority from a business point of view. Default priority is 0, The range is from -9 to 9.
     /// Higher values indicate a higher priority. The chargingPriority in
     /// &lt;&lt;transactioneventresponse,TransactionEventResponse&gt;&gt; overrules this one.
     #[serde(rename = "chargingPriority")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charging_priority: Option<i64>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Only used when the IdToken is only valid for one or more specific EVSEs, not for the
     /// entire Charging Station.
     #[serde(rename = "evseId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
     pub evse_id: Option<Vec<i64>>,
     #[serde(rename = "groupIdToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub group_id_token: Option<IdTokenType>,
     /// ID_ Token. Language1. Language_ Code
     /// urn:x-oca:ocpp:uid:1:569374
     /// Preferred user interface language of identifier user. Contains a language code as defined
     /// in &lt;&lt;ref-RFC5646,[RFC5646]&gt;&gt;.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 8))]
     pub language1: Option<String>,
     /// ID_ Token. Language2. Language_ Code
     /// urn:x-oca:ocpp:uid:1:569375
     /// Second preferred user interface language of identifier user. Don’t use when language1 is
     /// omitted, has to be different from language1. Contains a language code as defined in
     /// &lt;&lt;ref-RFC5646,[RFC5646]&gt;&gt;.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 8))]
     pub language2: Option<String>,
     #[serde(rename = "personalMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub personal_message: Option<MessageContentType>,
     pub status: AuthorizationStatusEnumType,
 }
@@ -2253,7 +2724,7 @@ pub struct IdTokenInfoType {
 /// ID_ Token. Status. Authorization_ Status
 /// urn:x-oca:ocpp:uid:1:569372
 /// Current status of the ID Token.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum AuthorizationStatusEnumType {
     Accepted,
     Blocked,
@@ -2269,7 +2740,7 @@ pub enum AuthorizationStatusEnumType {
 }
 
 /// This contains the type of update (full or differential) of this request.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum UpdateEnumType {
     Differential,
     Full,
@@ -2277,12 +2748,15 @@ pub enum UpdateEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SetChargingProfileRequest {
     #[serde(rename = "chargingProfile")]
+    #[validate]
     pub charging_profile: ChargingProfileType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// For TxDefaultProfile an evseId=0 applies the profile to each individual evse. For
     /// ChargingStationMaxProfile and ChargingStationExternalConstraints an evseId=0 contains an
     /// overal limit for the whole Charging Station.
@@ -2292,25 +2766,30 @@ pub struct SetChargingProfileRequest {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SetDisplayMessageRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[validate]
     pub message: MessageInfoType,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SetMonitoringBaseRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "monitoringBase")]
     pub monitoring_base: MonitoringBaseEnumType,
 }
 
 /// Specify which monitoring base will be set
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum MonitoringBaseEnumType {
     All,
     FactoryDefault,
@@ -2319,10 +2798,12 @@ pub enum MonitoringBaseEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SetMonitoringLevelRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// The Charging Station SHALL only report events with a severity number lower than or equal
     /// to this severity.
     /// The severity range is 0-9, with 0 as the highest and 9 as the lowest severity level.
@@ -2357,26 +2838,33 @@ pub struct SetMonitoringLevelRequest {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SetNetworkProfileRequest {
     /// Slot in which the configuration should be stored.
     #[serde(rename = "configurationSlot")]
     pub configuration_slot: i64,
     #[serde(rename = "connectionData")]
+    #[validate]
     pub connection_data: NetworkConnectionProfileType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
 }
 
 /// Communication_ Function
 /// urn:x-oca:ocpp:uid:2:233304
 /// The NetworkConnectionProfile defines the functional and technical parameters of a
 /// communication link.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct NetworkConnectionProfileType {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub apn: Option<ApnType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Duration in seconds before a message send by the Charging Station via this network
     /// connection times-out.
     /// The best setting depends on the underlying network and response times of the CSMS.
@@ -2387,6 +2875,7 @@ pub struct NetworkConnectionProfileType {
     /// urn:x-oca:ocpp:uid:1:569357
     /// URL of the CSMS(s) that this Charging Station  communicates with.
     #[serde(rename = "ocppCsmsUrl")]
+    #[validate(length(max = 512))]
     pub ocpp_csms_url: String,
     #[serde(rename = "ocppInterface")]
     pub ocpp_interface: OcppInterfaceEnumType,
@@ -2398,6 +2887,8 @@ pub struct NetworkConnectionProfileType {
     /// NetworkConnectionProfile.
     #[serde(rename = "securityProfile")]
     pub security_profile: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub vpn: Option<VpnType>,
 }
 
@@ -2413,11 +2904,12 @@ pub struct NetworkConnectionProfileType {
 /// allows to specify a preferred network, which means, if this network is not available, a
 /// different network is used. If you specify UseOnlyPreferredNetwork and this network is not
 /// available, the modem will not dial in.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct ApnType {
     /// APN. APN. URI
     /// urn:x-oca:ocpp:uid:1:568814
     /// The Access Point Name as an URL.
+    #[validate(length(max = 512))]
     pub apn: String,
     #[serde(rename = "apnAuthentication")]
     pub apn_authentication: ApnAuthenticationEnumType,
@@ -2425,67 +2917,85 @@ pub struct ApnType {
     /// urn:x-oca:ocpp:uid:1:568819
     /// APN Password.
     #[serde(rename = "apnPassword")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 20))]
     pub apn_password: Option<String>,
     /// APN. APN. User_ Name
     /// urn:x-oca:ocpp:uid:1:568818
     /// APN username.
     #[serde(rename = "apnUserName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 20))]
     pub apn_user_name: Option<String>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// APN. Preferred_ Network. Mobile_ Network_ ID
     /// urn:x-oca:ocpp:uid:1:568822
     /// Preferred network, written as MCC and MNC concatenated. See note.
     #[serde(rename = "preferredNetwork")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 6))]
     pub preferred_network: Option<String>,
     /// APN. SIMPIN. PIN_ Code
     /// urn:x-oca:ocpp:uid:1:568821
     /// SIM card pin code.
     #[serde(rename = "simPin")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sim_pin: Option<i64>,
     /// APN. Use_ Only_ Preferred_ Network. Indicator
     /// urn:x-oca:ocpp:uid:1:568824
     /// Default: false. Use only the preferred Network, do
     /// not dial in when not available. See Note.
     #[serde(rename = "useOnlyPreferredNetwork")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub use_only_preferred_network: Option<bool>,
 }
 
 /// VPN
 /// urn:x-oca:ocpp:uid:2:233268
 /// VPN Configuration settings
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct VpnType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// VPN. Group. Group_ Name
     /// urn:x-oca:ocpp:uid:1:569274
     /// VPN group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 20))]
     pub group: Option<String>,
     /// VPN. Key. VPN_ Key
     /// urn:x-oca:ocpp:uid:1:569276
     /// VPN shared secret.
+    #[validate(length(max = 255))]
     pub key: String,
     /// VPN. Password. Password
     /// urn:x-oca:ocpp:uid:1:569275
     /// VPN Password.
+    #[validate(length(max = 20))]
     pub password: String,
     /// VPN. Server. URI
     /// urn:x-oca:ocpp:uid:1:569272
     /// VPN Server Address
+    #[validate(length(max = 512))]
     pub server: String,
     #[serde(rename = "type")]
     pub vpn_type_type: VpnEnumType,
     /// VPN. User. User_ Name
     /// urn:x-oca:ocpp:uid:1:569273
     /// VPN User
+    #[validate(length(max = 20))]
     pub user: String,
 }
 
 /// APN. APN_ Authentication. APN_ Authentication_ Code
 /// urn:x-oca:ocpp:uid:1:568828
 /// Authentication method.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ApnAuthenticationEnumType {
     #[serde(rename = "AUTO")]
     Auto,
@@ -2498,7 +3008,7 @@ pub enum ApnAuthenticationEnumType {
 }
 
 /// Applicable Network Interface.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum OcppInterfaceEnumType {
     Wired0,
     Wired1,
@@ -2514,7 +3024,7 @@ pub enum OcppInterfaceEnumType {
 /// urn:x-oca:ocpp:uid:1:569356
 /// Defines the transport protocol (e.g. SOAP or JSON). Note: SOAP is not supported in OCPP
 /// 2.0, but is supported by other versions of OCPP.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum OcppTransportEnumType {
     #[serde(rename = "JSON")]
     Json,
@@ -2525,7 +3035,7 @@ pub enum OcppTransportEnumType {
 /// Communication_ Function. OCPP_ Version. OCPP_ Version_ Code
 /// urn:x-oca:ocpp:uid:1:569355
 /// Defines the OCPP version used for this communication function.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum OcppVersionEnumType {
     #[serde(rename = "OCPP12")]
     Ocpp12,
@@ -2540,7 +3050,7 @@ pub enum OcppVersionEnumType {
 /// VPN. Type. VPN_ Code
 /// urn:x-oca:ocpp:uid:1:569277
 /// Type of VPN
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum VpnEnumType {
     #[serde(rename = "IKEv2")]
     IkEv2,
@@ -2554,22 +3064,30 @@ pub enum VpnEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SetVariableMonitoringRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "setMonitoringData")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub set_monitoring_data: Vec<SetMonitoringDataType>,
 }
 
 /// Class to hold parameters of SetVariableMonitoring request.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SetMonitoringDataType {
+    #[validate]
     pub component: ComponentType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// An id SHALL only be given to replace an existing monitor. The Charging Station handles
     /// the generation of id's for new monitors.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
     /// The severity that will be assigned to an event that is triggered by this monitor. The
     /// severity range is 0-9, with 0 as the highest and 9 as the lowest severity level.
@@ -2602,28 +3120,35 @@ pub struct SetMonitoringDataType {
     pub severity: i64,
     /// Monitor only active when a transaction is ongoing on a component relevant to this
     /// transaction. Default = false.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction: Option<bool>,
     #[serde(rename = "type")]
     pub set_monitoring_data_type_type: MonitorEnumType,
     /// Value for threshold or delta monitoring.
     /// For Periodic or PeriodicClockAligned this is the interval in seconds.
     pub value: f64,
+    #[validate]
     pub variable: VariableType,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SetVariablesRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "setVariableData")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub set_variable_data: Vec<SetVariableDataType>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SetVariableDataType {
     #[serde(rename = "attributeType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attribute_type: Option<AttributeEnumType>,
     /// Value to be assigned to attribute of variable.
     ///
@@ -2632,30 +3157,39 @@ pub struct SetVariableDataType {
     /// limit SetVariableData.attributeValue and VariableCharacteristics.valueList. The max size
     /// of these values will always remain equal.
     #[serde(rename = "attributeValue")]
+    #[validate(length(max = 1000))]
     pub attribute_value: String,
+    #[validate]
     pub component: ComponentType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[validate]
     pub variable: VariableType,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct SignCertificateRequest {
     #[serde(rename = "certificateType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub certificate_type: Option<CertificateSigningUseEnumType>,
     /// The Charging Station SHALL send the public key in form of a Certificate Signing Request
     /// (CSR) as described in RFC 2986 [22] and then PEM encoded, using the
     /// &lt;&lt;signcertificaterequest,SignCertificateRequest&gt;&gt; message.
+    #[validate(length(max = 5500))]
     pub csr: String,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct StatusNotificationRequest {
     /// The id of the connector within the EVSE for which the status is reported.
     #[serde(rename = "connectorId")]
@@ -2663,7 +3197,9 @@ pub struct StatusNotificationRequest {
     #[serde(rename = "connectorStatus")]
     pub connector_status: ConnectorStatusEnumType,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// The id of the EVSE to which the connector belongs for which the the status is reported.
     #[serde(rename = "evseId")]
     pub evse_id: i64,
@@ -2673,7 +3209,7 @@ pub struct StatusNotificationRequest {
 }
 
 /// This contains the current status of the Connector.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ConnectorStatusEnumType {
     Available,
     Faulted,
@@ -2684,30 +3220,43 @@ pub enum ConnectorStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct TransactionEventRequest {
     /// The maximum current of the connected cable in Ampere (A).
     #[serde(rename = "cableMaxCurrent")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cable_max_current: Option<i64>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     #[serde(rename = "eventType")]
     pub event_type: TransactionEventEnumType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub evse: Option<EvseType>,
     #[serde(rename = "idToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub id_token: Option<IdTokenType>,
     #[serde(rename = "meterValue")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub meter_value: Option<Vec<MeterValueType>>,
     /// If the Charging Station is able to report the number of phases used, then it SHALL
     /// provide it. When omitted the CSMS may be able to determine the number of phases used via
     /// device management.
     #[serde(rename = "numberOfPhasesUsed")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub number_of_phases_used: Option<i64>,
     /// Indication that this transaction event happened when the Charging Station was offline.
     /// Default = false, meaning: the event occurred when the Charging Station was online.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub offline: Option<bool>,
     /// This contains the Id of the reservation that terminates as a result of this transaction.
     #[serde(rename = "reservationId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reservation_id: Option<i64>,
     /// Incremental sequence number, helps with determining if all messages of a transaction have
     /// been received.
@@ -2716,6 +3265,7 @@ pub struct TransactionEventRequest {
     /// The date and time at which this transaction event occurred.
     pub timestamp: String,
     #[serde(rename = "transactionInfo")]
+    #[validate]
     pub transaction_info: TransactionType,
     #[serde(rename = "triggerReason")]
     pub trigger_reason: TriggerReasonEnumType,
@@ -2723,18 +3273,23 @@ pub struct TransactionEventRequest {
 
 /// Transaction
 /// urn:x-oca:ocpp:uid:2:233318
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct TransactionType {
     #[serde(rename = "chargingState")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charging_state: Option<ChargingStateEnumType>,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// The ID given to remote start request (&lt;&lt;requeststarttransactionrequest,
     /// RequestStartTransactionRequest&gt;&gt;. This enables to CSMS to match the started
     /// transaction to the given start request.
     #[serde(rename = "remoteStartId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remote_start_id: Option<i64>,
     #[serde(rename = "stoppedReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub stopped_reason: Option<ReasonEnumType>,
     /// Transaction. Time_ Spent_ Charging. Elapsed_ Time
     /// urn:x-oca:ocpp:uid:1:569415
@@ -2742,9 +3297,11 @@ pub struct TransactionType {
     /// seconds). Note that timeSpentCharging is smaller or equal to the duration of the
     /// transaction.
     #[serde(rename = "timeSpentCharging")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub time_spent_charging: Option<i64>,
     /// This contains the Id of the transaction.
     #[serde(rename = "transactionId")]
+    #[validate(length(max = 36))]
     pub transaction_id: String,
 }
 
@@ -2752,7 +3309,7 @@ pub struct TransactionType {
 /// The first TransactionEvent of a transaction SHALL contain: "Started" The last
 /// TransactionEvent of a transaction SHALL contain: "Ended" All others SHALL contain:
 /// "Updated"
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum TransactionEventEnumType {
     Ended,
     Started,
@@ -2763,7 +3320,7 @@ pub enum TransactionEventEnumType {
 /// urn:x-oca:ocpp:uid:1:569419
 /// Current charging state, is required when state
 /// has changed.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ChargingStateEnumType {
     Charging,
     #[serde(rename = "EVConnected")]
@@ -2779,7 +3336,7 @@ pub enum ChargingStateEnumType {
 /// urn:x-oca:ocpp:uid:1:569413
 /// This contains the reason why the transaction was stopped. MAY only be omitted when Reason
 /// is "Local".
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ReasonEnumType {
     DeAuthorized,
     EmergencyStop,
@@ -2806,7 +3363,7 @@ pub enum ReasonEnumType {
 }
 
 /// Reason the Charging Station sends this message to the CSMS
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum TriggerReasonEnumType {
     AbnormalCondition,
     Authorized,
@@ -2837,17 +3394,21 @@ pub enum TriggerReasonEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct TriggerMessageRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub evse: Option<EvseType>,
     #[serde(rename = "requestedMessage")]
     pub requested_message: MessageTriggerEnumType,
 }
 
 /// Type of message to be triggered.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum MessageTriggerEnumType {
     BootNotification,
     FirmwareStatusNotification,
@@ -2864,13 +3425,15 @@ pub enum MessageTriggerEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct UnlockConnectorRequest {
     /// This contains the identifier of the connector that needs to be unlocked.
     #[serde(rename = "connectorId")]
     pub connector_id: i64,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// This contains the identifier of the EVSE for which a connector needs to be unlocked.
     #[serde(rename = "evseId")]
     pub evse_id: i64,
@@ -2878,20 +3441,26 @@ pub struct UnlockConnectorRequest {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct UnpublishFirmwareRequest {
     /// The MD5 checksum over the entire firmware file as a hexadecimal string of length 32.
+    #[validate(length(max = 32))]
     pub checksum: String,
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct UpdateFirmwareRequest {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
+    #[validate]
     pub firmware: FirmwareType,
     /// The Id of this request
     #[serde(rename = "requestId")]
@@ -2899,28 +3468,34 @@ pub struct UpdateFirmwareRequest {
     /// This specifies how many times Charging Station must try to download the firmware before
     /// giving up. If this field is not present, it is left to Charging Station to decide how
     /// many times it wants to retry.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub retries: Option<i64>,
     /// The interval in seconds after which a retry may be attempted. If this field is not
     /// present, it is left to Charging Station to decide how long to wait between attempts.
     #[serde(rename = "retryInterval")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub retry_interval: Option<i64>,
 }
 
 /// Firmware
 /// urn:x-enexis:ecdm:uid:2:233291
 /// Represents a copy of the firmware that can be loaded/updated on the Charging Station.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Validate, JsonSchema)]
 pub struct FirmwareType {
     #[serde(rename = "customData")]
-    pub custom_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub custom_data: Option<CustomDataType>,
     /// Firmware. Install. Date_ Time
     /// urn:x-enexis:ecdm:uid:1:569462
     /// Date and time at which the firmware shall be installed.
     #[serde(rename = "installDateTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub install_date_time: Option<String>,
     /// Firmware. Location. URI
     /// urn:x-enexis:ecdm:uid:1:569460
     /// URI defining the origin of the firmware.
+    #[validate(length(max = 512))]
     pub location: String,
     /// Firmware. Retrieve. Date_ Time
     /// urn:x-enexis:ecdm:uid:1:569461
@@ -2930,10 +3505,14 @@ pub struct FirmwareType {
     /// Firmware. Signature. Signature
     /// urn:x-enexis:ecdm:uid:1:569464
     /// Base64 encoded firmware signature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 800))]
     pub signature: Option<String>,
     /// Certificate with which the firmware was signed.
     /// PEM encoded X.509 certificate.
     #[serde(rename = "signingCertificate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 5500))]
     pub signing_certificate: Option<String>,
 }
 
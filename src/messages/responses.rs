@@ -1,26 +1,34 @@
 use serde::{Serialize, Deserialize};
+use validator::Validate;
+use schemars::JsonSchema;
 
 // structures created with the help of https://app.quicktype.io/ and json schema provided by
 // https://www.openchargealliance.org/
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct AuthorizeResponse {
     #[serde(rename = "certificateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub certificate_status: Option<AuthorizeCertificateStatusEnumType>,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     #[serde(rename = "idTokenInfo")]
+    #[validate]
     pub id_token_info: IdTokenInfoType,
 }
 
 /// This class does not get 'AdditionalProperties = false' in the schema generation, so it
 /// can be extended with arbitrary JSON properties to allow adding custom data.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct CustomDataType {
     #[serde(rename = "vendorId")]
+    #[validate(length(max = 255))]
     pub vendor_id: String,
+    /// the vendor specific properties, kept as they are
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// ID_ Token
@@ -29,7 +37,7 @@ pub struct CustomDataType {
 /// It is advised to not stop charging for a token that expires during charging, as
 /// ExpiryDate is only used for caching purposes. If ExpiryDate is not given, the status has
 /// no end date.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct IdTokenInfoType {
     /// ID_ Token. Expiry. Date_ Time
     /// urn:x-oca:ocpp:uid:1:569373
@@ -45,20 +53,24 @@ pub struct IdTokenInfoType {
     pub charging_priority: Option<i64>,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// Only used when the IdToken is only valid for one or more specific EVSEs, not for the
     /// entire Charging Station.
     #[serde(rename = "evseId")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
     pub evse_id: Option<Vec<i64>>,
     #[serde(rename = "groupIdToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub group_id_token: Option<IdTokenType>,
     /// ID_ Token. Language1. Language_ Code
     /// urn:x-oca:ocpp:uid:1:569374
     /// Preferred user interface language of identifier user. Contains a language code as defined
     /// in &lt;&lt;ref-RFC5646,[RFC5646]&gt;&gt;.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 8))]
     pub language1: Option<String>,
     /// ID_ Token. Language2. Language_ Code
     /// urn:x-oca:ocpp:uid:1:569375
@@ -66,26 +78,32 @@ pub struct IdTokenInfoType {
     /// omitted, has to be different from language1. Contains a language code as defined in
     /// &lt;&lt;ref-RFC5646,[RFC5646]&gt;&gt;.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 8))]
     pub language2: Option<String>,
     #[serde(rename = "personalMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub personal_message: Option<MessageContentType>,
     pub status: AuthorizationStatusEnumType,
 }
 
 /// Contains a case insensitive identifier to use for the authorization and the type of
 /// authorization to support multiple forms of identifiers.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct IdTokenType {
     #[serde(rename = "additionalInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub additional_info: Option<Vec<AdditionalInfoType>>,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// IdToken is case insensitive. Might hold the hidden id of an RFID tag, but can for example
     /// also contain a UUID.
     #[serde(rename = "idToken")]
+    #[validate(length(max = 36))]
     pub id_token: String,
     #[serde(rename = "type")]
     pub id_token_type_type: IdTokenEnumType,
@@ -93,31 +111,36 @@ pub struct IdTokenType {
 
 /// Contains a case insensitive identifier to use for the authorization and the type of
 /// authorization to support multiple forms of identifiers.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct AdditionalInfoType {
     /// This field specifies the additional IdToken.
     #[serde(rename = "additionalIdToken")]
+    #[validate(length(max = 36))]
     pub additional_id_token: String,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// This defines the type of the additionalIdToken. This is a custom type, so the
     /// implementation needs to be agreed upon by all involved parties.
     #[serde(rename = "type")]
+    #[validate(length(max = 50))]
     pub additional_info_type_type: String,
 }
 
 /// Message_ Content
 /// urn:x-enexis:ecdm:uid:2:234490
 /// Contains message details, for a message to be displayed on a Charging Station.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct MessageContentType {
     /// Message_ Content. Content. Message
     /// urn:x-enexis:ecdm:uid:1:570852
     /// Message contents.
+    #[validate(length(max = 512))]
     pub content: String,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub format: MessageFormatEnumType,
     /// Message_ Content. Language. Language_ Code
@@ -125,13 +148,14 @@ pub struct MessageContentType {
     /// Message language identifier. Contains a language code as defined in
     /// &lt;&lt;ref-RFC5646,[RFC5646]&gt;&gt;.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 8))]
     pub language: Option<String>,
 }
 
 /// Certificate status information.
 /// - if all certificates are valid: return 'Accepted'.
 /// - if one of the certificates was revoked, return 'CertificateRevoked'.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum AuthorizeCertificateStatusEnumType {
     Accepted,
     CertChainError,
@@ -143,7 +167,7 @@ pub enum AuthorizeCertificateStatusEnumType {
 }
 
 /// Enumeration of possible idToken types.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum IdTokenEnumType {
     Central,
     #[serde(rename = "eMAID")]
@@ -161,7 +185,7 @@ pub enum IdTokenEnumType {
 /// Message_ Content. Format. Message_ Format_ Code
 /// urn:x-enexis:ecdm:uid:1:570848
 /// Format of the message.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum MessageFormatEnumType {
     #[serde(rename = "ASCII")]
     Ascii,
@@ -176,7 +200,7 @@ pub enum MessageFormatEnumType {
 /// ID_ Token. Status. Authorization_ Status
 /// urn:x-oca:ocpp:uid:1:569372
 /// Current status of the ID Token.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum AuthorizationStatusEnumType {
     Accepted,
     Blocked,
@@ -193,13 +217,14 @@ pub enum AuthorizationStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct BootNotificationResponse {
     /// This contains the CSMS’s current time.
     #[serde(rename = "currentTime")]
     pub current_time: String,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// When &lt;&lt;cmn_registrationstatusenumtype,Status&gt;&gt; is Accepted, this contains the
     /// heartbeat interval in seconds. If the CSMS returns something other than Accepted, the
@@ -209,28 +234,32 @@ pub struct BootNotificationResponse {
     pub status: RegistrationStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Element providing more information about the status.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct StatusInfoType {
     /// Additional text to provide detailed information.
     #[serde(rename = "additionalInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 512))]
     pub additional_info: Option<String>,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// A predefined code for the reason why the status is returned in this response. The string
     /// is case-insensitive.
     #[serde(rename = "reasonCode")]
+    #[validate(length(max = 20))]
     pub reason_code: String,
 }
 
 /// This contains whether the Charging Station has been registered
 /// within the CSMS.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum RegistrationStatusEnumType {
     Accepted,
     Pending,
@@ -239,19 +268,21 @@ pub enum RegistrationStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct CancelReservationResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: CancelReservationStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This indicates the success or failure of the canceling of a reservation by CSMS.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum CancelReservationStatusEnumType {
     Accepted,
     Rejected,
@@ -259,19 +290,21 @@ pub enum CancelReservationStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct CertificateSignedResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: CertificateSignedStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Returns whether certificate signing has been accepted, otherwise rejected.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum CertificateSignedStatusEnumType {
     Accepted,
     Rejected,
@@ -279,19 +312,21 @@ pub enum CertificateSignedStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ChangeAvailabilityResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: ChangeAvailabilityStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This indicates whether the Charging Station is able to perform the availability change.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum ChangeAvailabilityStatusEnumType {
     Accepted,
     Rejected,
@@ -300,19 +335,21 @@ pub enum ChangeAvailabilityStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ClearCacheResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: ClearCacheStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Accepted if the Charging Station has executed the request, otherwise rejected.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum ClearCacheStatusEnumType {
     Accepted,
     Rejected,
@@ -320,19 +357,21 @@ pub enum ClearCacheStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ClearChargingProfileResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: ClearChargingProfileStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Indicates if the Charging Station was able to execute the request.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum ClearChargingProfileStatusEnumType {
     Accepted,
     Unknown,
@@ -340,19 +379,21 @@ pub enum ClearChargingProfileStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ClearDisplayMessageResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: ClearMessageStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Returns whether the Charging Station has been able to remove the message.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum ClearMessageStatusEnumType {
     Accepted,
     Unknown,
@@ -360,39 +401,45 @@ pub enum ClearMessageStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ClearedChargingLimitResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ClearVariableMonitoringResponse {
     #[serde(rename = "clearMonitoringResult")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub clear_monitoring_result: Vec<ClearMonitoringResultType>,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ClearMonitoringResultType {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// Id of the monitor of which a clear was requested.
     pub id: i64,
     pub status: ClearMonitoringStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Result of the clear request for this monitor, identified by its Id.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum ClearMonitoringStatusEnumType {
     Accepted,
     NotFound,
@@ -401,28 +448,31 @@ pub enum ClearMonitoringStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct CostUpdatedResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct CustomerInformationResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: CustomerInformationStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Indicates whether the request was accepted.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum CustomerInformationStatusEnumType {
     Accepted,
     Invalid,
@@ -431,10 +481,11 @@ pub enum CustomerInformationStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct DataTransferResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// Data without specified length or format, in response to request.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -442,11 +493,12 @@ pub struct DataTransferResponse {
     pub status: DataTransferStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This indicates the success or failure of the data transfer.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum DataTransferStatusEnumType {
     Accepted,
     Rejected,
@@ -456,19 +508,21 @@ pub enum DataTransferStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct DeleteCertificateResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: DeleteCertificateStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Charging Station indicates if it can process the request.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum DeleteCertificateStatusEnumType {
     Accepted,
     Failed,
@@ -477,31 +531,35 @@ pub enum DeleteCertificateStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct FirmwareStatusNotificationResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct Get15118EvCertificateResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// Raw CertificateInstallationRes response for the EV, Base64 encoded.
     #[serde(rename = "exiResponse")]
+    #[validate(length(max = 5600))]
     pub exi_response: String,
     pub status: Iso15118EvCertificateStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Indicates whether the message was processed properly.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum Iso15118EvCertificateStatusEnumType {
     Accepted,
     Failed,
@@ -509,19 +567,21 @@ pub enum Iso15118EvCertificateStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetBaseReportResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: GenericDeviceModelStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This indicates whether the Charging Station is able to accept this request.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum GenericDeviceModelStatusEnumType {
     Accepted,
     EmptyResultSet,
@@ -531,26 +591,29 @@ pub enum GenericDeviceModelStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetCertificateStatusResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// OCSPResponse class as defined in &lt;&lt;ref-ocpp_security_24, IETF RFC 6960&gt;&gt;. DER
     /// encoded (as defined in &lt;&lt;ref-ocpp_security_24, IETF RFC 6960&gt;&gt;), and then
     /// base64 encoded. MAY only be omitted when status is not Accepted.
     #[serde(rename = "ocspResult")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 5500))]
     pub ocsp_result: Option<String>,
     pub status: GetCertificateStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This indicates whether the charging station was able to retrieve the OCSP certificate
 /// status.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum GetCertificateStatusEnumType {
     Accepted,
     Failed,
@@ -558,20 +621,22 @@ pub enum GetCertificateStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetChargingProfilesResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: GetChargingProfileStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This indicates whether the Charging Station is able to process this request and will send
 /// &lt;&lt;reportchargingprofilesrequest, ReportChargingProfilesRequest&gt;&gt; messages.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum GetChargingProfileStatusEnumType {
     Accepted,
     NoProfiles,
@@ -579,29 +644,35 @@ pub enum GetChargingProfileStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetCompositeScheduleResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub schedule: Option<CompositeScheduleType>,
     pub status: GenericStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Composite_ Schedule
 /// urn:x-oca:ocpp:uid:2:233362
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct CompositeScheduleType {
     #[serde(rename = "chargingRateUnit")]
     pub charging_rate_unit: ChargingRateUnitEnumType,
     #[serde(rename = "chargingSchedulePeriod")]
+    #[validate(length(min = 1, max = 1024))]
+    #[validate]
     pub charging_schedule_period: Vec<ChargingSchedulePeriodType>,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// Duration of the schedule in seconds.
     pub duration: i64,
@@ -622,10 +693,11 @@ pub struct CompositeScheduleType {
 /// Charging_ Schedule_ Period
 /// urn:x-oca:ocpp:uid:2:233257
 /// Charging schedule period structure defines a time period in a charging schedule.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ChargingSchedulePeriodType {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// Charging_ Schedule_ Period. Limit. Measure
     /// urn:x-oca:ocpp:uid:1:569241
@@ -656,7 +728,7 @@ pub struct ChargingSchedulePeriodType {
 
 /// The unit of measure Limit is
 /// expressed in.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum ChargingRateUnitEnumType {
     A,
     W,
@@ -664,7 +736,7 @@ pub enum ChargingRateUnitEnumType {
 
 /// The Charging Station will indicate if it was
 /// able to process the request
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum GenericStatusEnumType {
     Accepted,
     Rejected,
@@ -672,20 +744,22 @@ pub enum GenericStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetDisplayMessagesResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: GetDisplayMessagesStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Indicates if the Charging Station has Display Messages that match the request criteria in
 /// the &lt;&lt;getdisplaymessagesrequest,GetDisplayMessagesRequest&gt;&gt;
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum GetDisplayMessagesStatusEnumType {
     Accepted,
     Unknown,
@@ -693,54 +767,66 @@ pub enum GetDisplayMessagesStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetInstalledCertificateIdsResponse {
     #[serde(rename = "certificateHashDataChain")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub certificate_hash_data_chain: Option<Vec<CertificateHashDataChainType>>,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: GetInstalledCertificateStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct CertificateHashDataChainType {
     #[serde(rename = "certificateHashData")]
+    #[validate]
     pub certificate_hash_data: CertificateHashDataType,
     #[serde(rename = "certificateType")]
     pub certificate_type: GetCertificateIdUseEnumType,
     #[serde(rename = "childCertificateHashData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub child_certificate_hash_data: Option<Vec<CertificateHashDataType>>,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct CertificateHashDataType {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     #[serde(rename = "hashAlgorithm")]
     pub hash_algorithm: HashAlgorithmEnumType,
     /// Hashed value of the issuers public key
     #[serde(rename = "issuerKeyHash")]
+    #[validate(length(max = 128))]
     pub issuer_key_hash: String,
     /// Hashed value of the Issuer DN (Distinguished Name).
     #[serde(rename = "issuerNameHash")]
+    #[validate(length(max = 128))]
     pub issuer_name_hash: String,
     /// The serial number of the certificate.
     #[serde(rename = "serialNumber")]
+    #[validate(length(max = 40))]
     pub serial_number: String,
 }
 
 /// Used algorithms for the hashes provided.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum HashAlgorithmEnumType {
     #[serde(rename = "SHA256")]
     Sha256,
@@ -751,7 +837,7 @@ pub enum HashAlgorithmEnumType {
 }
 
 /// Indicates the type of the requested certificate(s).
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum GetCertificateIdUseEnumType {
     #[serde(rename = "CSMSRootCertificate")]
     CsmsRootCertificate,
@@ -763,7 +849,7 @@ pub enum GetCertificateIdUseEnumType {
 }
 
 /// Charging Station indicates if it can process the request.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum GetInstalledCertificateStatusEnumType {
     Accepted,
     NotFound,
@@ -771,10 +857,11 @@ pub enum GetInstalledCertificateStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetLocalListVersionResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// This contains the current version number of the local authorization list in the Charging
     /// Station.
@@ -784,23 +871,26 @@ pub struct GetLocalListVersionResponse {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetLogResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// This contains the name of the log file that will be uploaded. This field is not present
     /// when no logging information is available.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 255))]
     pub filename: Option<String>,
     pub status: LogStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This field indicates whether the Charging Station was able to accept the request.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum LogStatusEnumType {
     Accepted,
     AcceptedCanceled,
@@ -809,36 +899,41 @@ pub enum LogStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetMonitoringReportResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: GenericDeviceModelStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetReportResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: GenericDeviceModelStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetTransactionStatusResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// Whether there are still message to be delivered.
     #[serde(rename = "messagesInQueue")]
@@ -851,22 +946,26 @@ pub struct GetTransactionStatusResponse {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetVariablesResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     #[serde(rename = "getVariableResult")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub get_variable_result: Vec<GetVariableResultType>,
 }
 
 /// Class to hold results of GetVariables request.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct GetVariableResultType {
     #[serde(rename = "attributeStatus")]
     pub attribute_status: GetVariableStatusEnumType,
     #[serde(rename = "attributeStatusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub attribute_status_info: Option<StatusInfoType>,
     #[serde(rename = "attributeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -880,35 +979,43 @@ pub struct GetVariableResultType {
     /// max size of these values will always remain equal.
     #[serde(rename = "attributeValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 2500))]
     pub attribute_value: Option<String>,
+    #[validate]
     pub component: ComponentType,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
+    #[validate]
     pub variable: VariableType,
 }
 
 /// A physical or logical component
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ComponentType {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub evse: Option<EvseType>,
     /// Name of instance in case the component exists as multiple instances. Case Insensitive.
     /// strongly advised to use Camel Case.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 50))]
     pub instance: Option<String>,
     /// Name of the component. Name should be taken from the list of standardized component names
     /// whenever possible. Case Insensitive. strongly advised to use Camel Case.
+    #[validate(length(max = 50))]
     pub name: String,
 }
 
 /// EVSE
 /// urn:x-oca:ocpp:uid:2:233123
 /// Electric Vehicle Supply Equipment
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct EvseType {
     /// An id to designate a specific connector (on an EVSE) by connector index number.
     #[serde(rename = "connectorId")]
@@ -916,6 +1023,7 @@ pub struct EvseType {
     pub connector_id: Option<i64>,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// Identified_ Object. MRID. Numeric_ Identifier
     /// urn:x-enexis:ecdm:uid:1:569198
@@ -925,22 +1033,25 @@ pub struct EvseType {
 }
 
 /// Reference key to a component-variable.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct VariableType {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// Name of instance in case the variable exists as multiple instances. Case Insensitive.
     /// strongly advised to use Camel Case.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 50))]
     pub instance: Option<String>,
     /// Name of the variable. Name should be taken from the list of standardized variable names
     /// whenever possible. Case Insensitive. strongly advised to use Camel Case.
+    #[validate(length(max = 50))]
     pub name: String,
 }
 
 /// Result status of getting the variable.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum GetVariableStatusEnumType {
     Accepted,
     NotSupportedAttributeType,
@@ -950,7 +1061,7 @@ pub enum GetVariableStatusEnumType {
 }
 
 /// Attribute type for which value is requested. When absent, default Actual is assumed.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum AttributeEnumType {
     Actual,
     MaxSet,
@@ -960,31 +1071,34 @@ pub enum AttributeEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct HeartbeatResponse {
     /// Contains the current time of the CSMS.
     #[serde(rename = "currentTime")]
     pub current_time: String,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct InstallCertificateResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: InstallCertificateStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Charging Station indicates if installation was successful.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum InstallCertificateStatusEnumType {
     Accepted,
     Failed,
@@ -993,64 +1107,71 @@ pub enum InstallCertificateStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct LogStatusNotificationResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct MeterValuesResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct NotifyChargingLimitResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct NotifyCustomerInformationResponse {
     #[serde(rename = "customData")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct NotifyDisplayMessagesResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct NotifyEvChargingNeedsResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: NotifyEvChargingNeedsStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Returns whether the CSMS has been able to process the message successfully. It does not
 /// imply that the evChargingNeeds can be met with the current charging profile.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum NotifyEvChargingNeedsStatusEnumType {
     Accepted,
     Processing,
@@ -1059,96 +1180,108 @@ pub enum NotifyEvChargingNeedsStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct NotifyEvChargingScheduleResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: GenericStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct NotifyEventResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct NotifyMonitoringReportResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct NotifyReportResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct PublishFirmwareResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: GenericStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct PublishFirmwareStatusNotificationResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ReportChargingProfilesResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct RequestStartTransactionResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: RequestStartStopStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
     /// When the transaction was already started by the Charging Station before the
     /// RequestStartTransactionRequest was received, for example: cable plugged in first. This
     /// contains the transactionId of the already started transaction.
     #[serde(rename = "transactionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 36))]
     pub transaction_id: Option<String>,
 }
 
 /// Status indicating whether the Charging Station accepts the request to start a transaction.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum RequestStartStopStatusEnumType {
     Accepted,
     Rejected,
@@ -1156,41 +1289,46 @@ pub enum RequestStartStopStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct RequestStopTransactionResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: RequestStartStopStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ReservationStatusUpdateResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ReserveNowResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: ReserveNowStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This indicates the success or failure of the reservation.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum ReserveNowStatusEnumType {
     Accepted,
     Faulted,
@@ -1201,19 +1339,21 @@ pub enum ReserveNowStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct ResetResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: ResetStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This indicates whether the Charging Station is able to perform the reset.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum ResetStatusEnumType {
     Accepted,
     Rejected,
@@ -1222,29 +1362,32 @@ pub enum ResetStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct SecurityEventNotificationResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct SendLocalListResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: SendLocalListStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This indicates whether the Charging Station has successfully received and applied the
 /// update of the Local Authorization List.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum SendLocalListStatusEnumType {
     Accepted,
     Failed,
@@ -1253,21 +1396,23 @@ pub enum SendLocalListStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct SetChargingProfileResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: ChargingProfileStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Returns whether the Charging Station has been able to process the message successfully.
 /// This does not guarantee the schedule will be followed to the letter. There might be other
 /// constraints the Charging Station may need to take into account.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum ChargingProfileStatusEnumType {
     Accepted,
     Rejected,
@@ -1275,19 +1420,21 @@ pub enum ChargingProfileStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct SetDisplayMessageResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: DisplayMessageStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This indicates whether the Charging Station is able to display the message.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum DisplayMessageStatusEnumType {
     Accepted,
     NotSupportedMessageFormat,
@@ -1299,45 +1446,51 @@ pub enum DisplayMessageStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct SetMonitoringBaseResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: GenericDeviceModelStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct SetMonitoringLevelResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: GenericStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct SetNetworkProfileResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: SetNetworkProfileStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Result of operation.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum SetNetworkProfileStatusEnumType {
     Accepted,
     Failed,
@@ -1346,21 +1499,26 @@ pub enum SetNetworkProfileStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct SetVariableMonitoringResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     #[serde(rename = "setMonitoringResult")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub set_monitoring_result: Vec<SetMonitoringResultType>,
 }
 
 /// Class to hold result of SetVariableMonitoring request.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct SetMonitoringResultType {
+    #[validate]
     pub component: ComponentType,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     /// Id given to the VariableMonitor by the Charging Station. The Id is only returned when
     /// status is accepted. Installed VariableMonitors should have unique id's but the id's of
@@ -1400,14 +1558,16 @@ pub struct SetMonitoringResultType {
     pub status: SetMonitoringStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
     #[serde(rename = "type")]
     pub set_monitoring_result_type_type: MonitorEnumType,
+    #[validate]
     pub variable: VariableType,
 }
 
 /// The type of this monitor, e.g. a threshold, delta or periodic monitor.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum MonitorEnumType {
     Delta,
     LowerThreshold,
@@ -1418,7 +1578,7 @@ pub enum MonitorEnumType {
 
 /// Status is OK if a value could be returned. Otherwise this will indicate the reason why a
 /// value could not be returned.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum SetMonitoringStatusEnumType {
     Accepted,
     Duplicate,
@@ -1430,34 +1590,41 @@ pub enum SetMonitoringStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct SetVariablesResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     #[serde(rename = "setVariableResult")]
+    #[validate(length(min = 1))]
+    #[validate]
     pub set_variable_result: Vec<SetVariableResultType>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct SetVariableResultType {
     #[serde(rename = "attributeStatus")]
     pub attribute_status: SetVariableStatusEnumType,
     #[serde(rename = "attributeStatusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub attribute_status_info: Option<StatusInfoType>,
     #[serde(rename = "attributeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attribute_type: Option<AttributeEnumType>,
+    #[validate]
     pub component: ComponentType,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
+    #[validate]
     pub variable: VariableType,
 }
 
 /// Result status of setting the variable.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum SetVariableStatusEnumType {
     Accepted,
     NotSupportedAttributeType,
@@ -1469,29 +1636,32 @@ pub enum SetVariableStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct SignCertificateResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: GenericStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct StatusNotificationResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct TransactionEventResponse {
     /// Priority from a business point of view. Default priority is 0, The range is from -9 to 9.
     /// Higher values indicate a higher priority. The chargingPriority in
@@ -1505,9 +1675,11 @@ pub struct TransactionEventResponse {
     pub charging_priority: Option<i64>,
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     #[serde(rename = "idTokenInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub id_token_info: Option<IdTokenInfoType>,
     /// SHALL only be sent when charging has ended. Final total cost of this transaction,
     /// including taxes. In the currency configured with the Configuration Variable:
@@ -1518,24 +1690,27 @@ pub struct TransactionEventResponse {
     pub total_cost: Option<f64>,
     #[serde(rename = "updatedPersonalMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub updated_personal_message: Option<MessageContentType>,
 }
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct TriggerMessageResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: TriggerMessageStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// Indicates whether the Charging Station will send the requested notification or not.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum TriggerMessageStatusEnumType {
     Accepted,
     NotImplemented,
@@ -1544,19 +1719,21 @@ pub enum TriggerMessageStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct UnlockConnectorResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: UnlockStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This indicates whether the Charging Station has unlocked the connector.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum UnlockStatusEnumType {
     OngoingAuthorizedTransaction,
     UnknownConnector,
@@ -1566,16 +1743,17 @@ pub enum UnlockStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct UnpublishFirmwareResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: UnpublishFirmwareStatusEnumType,
 }
 
 /// Indicates whether the Local Controller succeeded in unpublishing the firmware.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum UnpublishFirmwareStatusEnumType {
     DownloadOngoing,
     NoFirmware,
@@ -1584,19 +1762,21 @@ pub enum UnpublishFirmwareStatusEnumType {
 
 //=================================================================================================
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Validate, JsonSchema)]
 pub struct UpdateFirmwareResponse {
     #[serde(rename = "customData")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub custom_data: Option<CustomDataType>,
     pub status: UpdateFirmwareStatusEnumType,
     #[serde(rename = "statusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub status_info: Option<StatusInfoType>,
 }
 
 /// This field indicates whether the Charging Station was able to accept the request.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub enum UpdateFirmwareStatusEnumType {
     Accepted,
     AcceptedCanceled,
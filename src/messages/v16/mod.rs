@@ -0,0 +1,287 @@
+use chrono::{DateTime, Utc, SecondsFormat};
+use serde_json::Value;
+
+use crate::messages::{self, wrap_call_result, wrap_call_error_result, ErrorCode};
+use crate::services;
+
+pub mod requests;
+pub mod responses;
+
+// OCPP 1.6 calls are framed exactly like the 2.0.1 ones ([2, "<UniqueId>", "<Action>", {<Payload>}]),
+// so unpacking and wrapping is shared with the parent module. Only the payloads differ.
+
+/// Every OCPP 1.6 action
+pub const ACTIONS: [&str; 28] = [
+    "Authorize", "BootNotification", "CancelReservation", "ChangeAvailability", "ChangeConfiguration",
+    "ClearCache", "ClearChargingProfile", "DataTransfer", "DiagnosticsStatusNotification",
+    "FirmwareStatusNotification", "GetCompositeSchedule", "GetConfiguration", "GetDiagnostics",
+    "GetLocalListVersion", "Heartbeat", "MeterValues", "RemoteStartTransaction", "RemoteStopTransaction",
+    "ReserveNow", "Reset", "SendLocalList", "SetChargingProfile", "StartTransaction", "StatusNotification",
+    "StopTransaction", "TriggerMessage", "UnlockConnector", "UpdateFirmware",
+];
+
+impl From<services::AuthorizationStatus> for responses::AuthorizationStatus {
+    fn from(status: services::AuthorizationStatus) -> Self {
+        match status {
+            services::AuthorizationStatus::Accepted => responses::AuthorizationStatus::Accepted,
+            services::AuthorizationStatus::Blocked => responses::AuthorizationStatus::Blocked,
+            services::AuthorizationStatus::Expired => responses::AuthorizationStatus::Expired,
+            // 1.6 has no "Unknown", an unknown id tag is invalid
+            services::AuthorizationStatus::Invalid |
+            services::AuthorizationStatus::Unknown => responses::AuthorizationStatus::Invalid,
+        }
+    }
+}
+
+fn id_tag_info(id_tag: &str) -> responses::IdTagInfo {
+    responses::IdTagInfo {
+        expiry_date: None,
+        parent_id_tag: None,
+        status: services::authorize(id_tag).into(),
+    }
+}
+
+/// Accepts the station, which is to send a Heartbeat every `interval` seconds
+pub fn boot_notification_response(message_id: &String, payload: &str, interval: i64) -> String {
+    match serde_json::from_str(payload) as Result<requests::BootNotificationRequest, serde_json::Error> {
+        Ok(_) => {
+            let at_now: DateTime<Utc> = Utc::now();
+            let boot_response = responses::BootNotificationResponse {
+                current_time: at_now.to_rfc3339_opts(SecondsFormat::Millis, false),
+                interval,
+                status: responses::RegistrationStatus::Accepted,
+            };
+            wrap_call_result(message_id, serde_json::to_string(&boot_response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn heartbeat_response(message_id: &String) -> String {
+    let at_now: DateTime<Utc> = Utc::now();
+    let heartbeat_response = responses::HeartbeatResponse {
+        current_time: at_now.to_rfc3339_opts(SecondsFormat::Millis, false),
+    };
+    wrap_call_result(message_id, serde_json::to_string(&heartbeat_response).unwrap())
+}
+
+pub fn authorize_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::AuthorizeRequest, serde_json::Error> {
+        Ok(request) => {
+            let authorize_response = responses::AuthorizeResponse {
+                id_tag_info: id_tag_info(&request.id_tag)
+            };
+            wrap_call_result(message_id, serde_json::to_string(&authorize_response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn status_notification_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::StatusNotificationRequest, serde_json::Error> {
+        Ok(_) => {
+            wrap_call_result(message_id,
+                             serde_json::to_string(&responses::StatusNotificationResponse {}).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn meter_values_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::MeterValuesRequest, serde_json::Error> {
+        Ok(_) => {
+            wrap_call_result(message_id,
+                             serde_json::to_string(&responses::MeterValuesResponse {}).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn data_transfer_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::DataTransferRequest, serde_json::Error> {
+        Ok(_) => {
+            // vendor specific extensions are not supported
+            let data_transfer_response = responses::DataTransferResponse {
+                data: None,
+                status: responses::DataTransferStatus::UnknownVendorId,
+            };
+            wrap_call_result(message_id, serde_json::to_string(&data_transfer_response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn diagnostics_status_notification_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::DiagnosticsStatusNotificationRequest, serde_json::Error> {
+        Ok(_) => {
+            wrap_call_result(message_id,
+                             serde_json::to_string(&responses::DiagnosticsStatusNotificationResponse {}).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn firmware_status_notification_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::FirmwareStatusNotificationRequest, serde_json::Error> {
+        Ok(_) => {
+            wrap_call_result(message_id,
+                             serde_json::to_string(&responses::FirmwareStatusNotificationResponse {}).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+/// `transaction_id` is allocated by the transaction service before the response is built
+pub fn start_transaction_response(message_id: &String, request: &requests::StartTransactionRequest,
+                                  transaction_id: &str) -> String {
+    match transaction_id.parse::<i64>() {
+        Ok(transaction_id) => {
+            let start_transaction_response = responses::StartTransactionResponse {
+                id_tag_info: id_tag_info(&request.id_tag),
+                transaction_id,
+            };
+            wrap_call_result(message_id, serde_json::to_string(&start_transaction_response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::InternalError, &format!("\"{}\"", e))
+        }
+    }
+}
+
+pub fn stop_transaction_response(message_id: &String, request: &requests::StopTransactionRequest) -> String {
+    let stop_transaction_response = responses::StopTransactionResponse {
+        id_tag_info: request.id_tag.as_ref().map(|id_tag| id_tag_info(id_tag)),
+    };
+    wrap_call_result(message_id, serde_json::to_string(&stop_transaction_response).unwrap())
+}
+
+/// Checks the payload of an OCPP 1.6 call that the web client wants to send to a charge point
+pub fn message_from_web_browser_is_valid(action: &str, payload: &Value) -> bool {
+    let payload = payload.clone();
+    match action {
+        "CancelReservation" => serde_json::from_value::<requests::CancelReservationRequest>(payload).is_ok(),
+        "ChangeAvailability" => serde_json::from_value::<requests::ChangeAvailabilityRequest>(payload).is_ok(),
+        "ChangeConfiguration" => serde_json::from_value::<requests::ChangeConfigurationRequest>(payload).is_ok(),
+        "ClearCache" => serde_json::from_value::<requests::ClearCacheRequest>(payload).is_ok(),
+        "ClearChargingProfile" => serde_json::from_value::<requests::ClearChargingProfileRequest>(payload).is_ok(),
+        "DataTransfer" => serde_json::from_value::<requests::DataTransferRequest>(payload).is_ok(),
+        "GetCompositeSchedule" => serde_json::from_value::<requests::GetCompositeScheduleRequest>(payload).is_ok(),
+        "GetConfiguration" => serde_json::from_value::<requests::GetConfigurationRequest>(payload).is_ok(),
+        "GetDiagnostics" => serde_json::from_value::<requests::GetDiagnosticsRequest>(payload).is_ok(),
+        "GetLocalListVersion" => serde_json::from_value::<requests::GetLocalListVersionRequest>(payload).is_ok(),
+        "RemoteStartTransaction" => serde_json::from_value::<requests::RemoteStartTransactionRequest>(payload).is_ok(),
+        "RemoteStopTransaction" => serde_json::from_value::<requests::RemoteStopTransactionRequest>(payload).is_ok(),
+        "ReserveNow" => serde_json::from_value::<requests::ReserveNowRequest>(payload).is_ok(),
+        "Reset" => serde_json::from_value::<requests::ResetRequest>(payload).is_ok(),
+        "SendLocalList" => serde_json::from_value::<requests::SendLocalListRequest>(payload).is_ok(),
+        "SetChargingProfile" => serde_json::from_value::<requests::SetChargingProfileRequest>(payload).is_ok(),
+        "TriggerMessage" => serde_json::from_value::<requests::TriggerMessageRequest>(payload).is_ok(),
+        "UnlockConnector" => serde_json::from_value::<requests::UnlockConnectorRequest>(payload).is_ok(),
+        "UpdateFirmware" => serde_json::from_value::<requests::UpdateFirmwareRequest>(payload).is_ok(),
+        _ => false
+    }
+}
+
+/// Turns a request of the web client into the OCPP 1.6 calls (action, payload) to send.
+/// Native 1.6 actions are passed through, the 2.0.1 device model requests `SetVariables` and
+/// `GetVariables` are mapped onto `ChangeConfiguration` and `GetConfiguration` through the
+/// configuration service. `RequestStopTransaction` becomes `RemoteStopTransaction`, and the 2.0.1
+/// payloads of `ChangeAvailability`, `TriggerMessage` and `ReserveNow` are mapped onto their 1.6
+/// counterparts, an EVSE standing for the connector with the same id.
+pub fn calls_from_web_browser(action: &str, payload: &Value) -> Result<Vec<(String, Value)>, String> {
+    match action {
+        "SetVariables" => {
+            let request: messages::requests::SetVariablesRequest =
+                serde_json::from_value(payload.clone()).map_err(|e| e.to_string())?;
+            let mut calls = Vec::new();
+            // ChangeConfiguration carries a single key, so every variable becomes its own call
+            for set_variable_data in request.set_variable_data {
+                let change_configuration = requests::ChangeConfigurationRequest {
+                    key: services::configuration_key(&set_variable_data.component.name,
+                                                     &set_variable_data.variable.name),
+                    value: set_variable_data.attribute_value,
+                };
+                calls.push((String::from("ChangeConfiguration"),
+                            serde_json::to_value(&change_configuration).unwrap()));
+            }
+            Ok(calls)
+        }
+        "GetVariables" => {
+            let request: messages::requests::GetVariablesRequest =
+                serde_json::from_value(payload.clone()).map_err(|e| e.to_string())?;
+            let get_configuration = requests::GetConfigurationRequest {
+                key: Some(request.get_variable_data.iter()
+                    .map(|data| services::configuration_key(&data.component.name, &data.variable.name))
+                    .collect())
+            };
+            Ok(vec![(String::from("GetConfiguration"), serde_json::to_value(&get_configuration).unwrap())])
+        }
+        "RequestStopTransaction" => {
+            let request: messages::requests::RequestStopTransactionRequest =
+                serde_json::from_value(payload.clone()).map_err(|e| e.to_string())?;
+            // 1.6 transaction ids are integers
+            let remote_stop = requests::RemoteStopTransactionRequest {
+                transaction_id: request.transaction_id.parse()
+                    .map_err(|_| format!("{} is not an OCPP 1.6 transaction id", request.transaction_id))?,
+            };
+            Ok(vec![(String::from("RemoteStopTransaction"), serde_json::to_value(&remote_stop).unwrap())])
+        }
+        "ChangeAvailability" if !message_from_web_browser_is_valid(action, payload) => {
+            let request: messages::requests::ChangeAvailabilityRequest =
+                serde_json::from_value(payload.clone()).map_err(|e| e.to_string())?;
+            let change_availability = requests::ChangeAvailabilityRequest {
+                connector_id: request.evse.map_or(0, |evse| evse.id),
+                change_availability_request_type: convert(&request.operational_status)?,
+            };
+            Ok(vec![(action.to_string(), serde_json::to_value(&change_availability).unwrap())])
+        }
+        "TriggerMessage" if !message_from_web_browser_is_valid(action, payload) => {
+            let request: messages::requests::TriggerMessageRequest =
+                serde_json::from_value(payload.clone()).map_err(|e| e.to_string())?;
+            let trigger_message = requests::TriggerMessageRequest {
+                connector_id: request.evse.map(|evse| evse.id),
+                requested_message: convert(&request.requested_message)?,
+            };
+            Ok(vec![(action.to_string(), serde_json::to_value(&trigger_message).unwrap())])
+        }
+        "ReserveNow" if !message_from_web_browser_is_valid(action, payload) => {
+            let request: messages::requests::ReserveNowRequest =
+                serde_json::from_value(payload.clone()).map_err(|e| e.to_string())?;
+            let reserve_now = requests::ReserveNowRequest {
+                connector_id: request.evse_id.unwrap_or(0),
+                expiry_date: request.expiry_date_time,
+                id_tag: request.id_token.id_token,
+                parent_id_tag: request.group_id_token.map(|group_id_token| group_id_token.id_token),
+                reservation_id: request.id,
+            };
+            Ok(vec![(action.to_string(), serde_json::to_value(&reserve_now).unwrap())])
+        }
+        _ => {
+            if message_from_web_browser_is_valid(action, payload) {
+                Ok(vec![(action.to_string(), payload.clone())])
+            } else {
+                Err(format!("{} is not a valid OCPP 1.6 request", action))
+            }
+        }
+    }
+}
+
+/// Converts a 2.0.1 enumeration into the 1.6 one with the same value name
+fn convert<F: serde::Serialize, T: serde::de::DeserializeOwned>(value: &F) -> Result<T, String> {
+    let value = serde_json::to_value(value).unwrap();
+    serde_json::from_value(value.clone()).map_err(|_| format!("{} does not exist in OCPP 1.6", value))
+}
@@ -0,0 +1,796 @@
+use serde::{Serialize, Deserialize};
+
+use super::responses::IdTagInfo;
+
+// structures created with the help of https://app.quicktype.io/ and OCPP 1.6 json schema provided by
+// https://www.openchargealliance.org/
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthorizeRequest {
+    /// This contains the identifier that needs to be authorized.
+    #[serde(rename = "idTag")]
+    pub id_tag: String,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BootNotificationRequest {
+    /// This contains a value that identifies the serial number of the Charge Box inside the
+    /// Charge Point. Deprecated, will be removed in future version
+    #[serde(rename = "chargeBoxSerialNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge_box_serial_number: Option<String>,
+    /// This contains a value that identifies the model of the ChargePoint.
+    #[serde(rename = "chargePointModel")]
+    pub charge_point_model: String,
+    /// This contains a value that identifies the serial number of the Charge Point.
+    #[serde(rename = "chargePointSerialNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge_point_serial_number: Option<String>,
+    /// This contains a value that identifies the vendor of the ChargePoint.
+    #[serde(rename = "chargePointVendor")]
+    pub charge_point_vendor: String,
+    /// This contains the firmware version of the Charge Point.
+    #[serde(rename = "firmwareVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firmware_version: Option<String>,
+    /// This contains the ICCID of the modem’s SIM card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iccid: Option<String>,
+    /// This contains the IMSI of the modem’s SIM card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imsi: Option<String>,
+    /// This contains the serial number of the main electrical meter of the Charge Point.
+    #[serde(rename = "meterSerialNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meter_serial_number: Option<String>,
+    /// This contains the type of the main electrical meter of the Charge Point.
+    #[serde(rename = "meterType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meter_type: Option<String>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CancelReservationRequest {
+    /// Id of the reservation to cancel.
+    #[serde(rename = "reservationId")]
+    pub reservation_id: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChangeAvailabilityRequest {
+    /// The id of the connector for which availability needs to change. Id '0' (zero) is used if
+    /// the availability of the Charge Point and all its connectors needs to change.
+    #[serde(rename = "connectorId")]
+    pub connector_id: i64,
+    #[serde(rename = "type")]
+    pub change_availability_request_type: AvailabilityType,
+}
+
+/// This contains the type of availability change that the Charge Point should perform.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum AvailabilityType {
+    Inoperative,
+    Operative,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChangeConfigurationRequest {
+    /// The name of the configuration setting to change.
+    pub key: String,
+    /// The new value as string for the setting.
+    pub value: String,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClearCacheRequest {}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClearChargingProfileRequest {
+    #[serde(rename = "chargingProfilePurpose")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charging_profile_purpose: Option<ChargingProfilePurposeType>,
+    /// Specifies the ID of the connector for which to clear charging profiles. A connectorId of
+    /// zero (0) specifies the charging profile for the overall Charge Point.
+    #[serde(rename = "connectorId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_id: Option<i64>,
+    /// The ID of the charging profile to clear.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    /// Specifies the stackLevel for which charging profiles will be cleared.
+    #[serde(rename = "stackLevel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stack_level: Option<i64>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DataTransferRequest {
+    /// Data without specified length or format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    /// Additional identification field
+    #[serde(rename = "messageId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+    /// This identifies the Vendor specific implementation
+    #[serde(rename = "vendorId")]
+    pub vendor_id: String,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DiagnosticsStatusNotificationRequest {
+    pub status: DiagnosticsStatus,
+}
+
+/// This contains the status of the diagnostics upload.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum DiagnosticsStatus {
+    Idle,
+    UploadFailed,
+    Uploaded,
+    Uploading,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FirmwareStatusNotificationRequest {
+    pub status: FirmwareStatus,
+}
+
+/// This contains the progress status of the firmware installation.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum FirmwareStatus {
+    DownloadFailed,
+    Downloaded,
+    Downloading,
+    Idle,
+    InstallationFailed,
+    Installed,
+    Installing,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetCompositeScheduleRequest {
+    /// Can be used to force a power or current profile
+    #[serde(rename = "chargingRateUnit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charging_rate_unit: Option<ChargingRateUnitType>,
+    /// The ID of the Connector for which the schedule is requested. When ConnectorId=0, the
+    /// Charge Point will calculate the expected consumption for the grid connection.
+    #[serde(rename = "connectorId")]
+    pub connector_id: i64,
+    /// Time in seconds. length of requested schedule
+    pub duration: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetConfigurationRequest {
+    /// List of keys for which the configuration value is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<Vec<String>>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetDiagnosticsRequest {
+    /// This contains the location (directory) where the diagnostics file shall be uploaded to.
+    pub location: String,
+    /// This specifies how many times Charge Point must try to upload the diagnostics before
+    /// giving up.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i64>,
+    /// The interval in seconds after which a retry may be attempted.
+    #[serde(rename = "retryInterval")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_interval: Option<i64>,
+    /// This contains the date and time of the oldest logging information to include in the
+    /// diagnostics.
+    #[serde(rename = "startTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+    /// This contains the date and time of the latest logging information to include in the
+    /// diagnostics.
+    #[serde(rename = "stopTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_time: Option<String>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetLocalListVersionRequest {}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HeartbeatRequest {}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MeterValuesRequest {
+    /// This contains a number (&gt;0) designating a connector of the Charge Point.‘0’ (zero) is
+    /// used to designate the main power meter.
+    #[serde(rename = "connectorId")]
+    pub connector_id: i64,
+    /// The sampled meter values with timestamps.
+    #[serde(rename = "meterValue")]
+    pub meter_value: Vec<MeterValue>,
+    /// The transaction to which these meter samples are related.
+    #[serde(rename = "transactionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<i64>,
+}
+
+/// Collection of one or more sampled values in MeterValues.req and StopTransaction.req. All
+/// sampled values in a MeterValue are sampled at the same point in time.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MeterValue {
+    /// One or more measured values
+    #[serde(rename = "sampledValue")]
+    pub sampled_value: Vec<SampledValue>,
+    /// Timestamp for measured value(s).
+    pub timestamp: String,
+}
+
+/// Single sampled value in MeterValues. Each value can be accompanied by optional fields.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SampledValue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<ReadingContext>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<ValueFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub measurand: Option<Measurand>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<Phase>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<UnitOfMeasure>,
+    /// Value as a “Raw” (decimal) number or “SignedData”.
+    pub value: String,
+}
+
+/// Values of the context field of a value in SampledValue.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ReadingContext {
+    #[serde(rename = "Interruption.Begin")]
+    InterruptionBegin,
+    #[serde(rename = "Interruption.End")]
+    InterruptionEnd,
+    Other,
+    #[serde(rename = "Sample.Clock")]
+    SampleClock,
+    #[serde(rename = "Sample.Periodic")]
+    SamplePeriodic,
+    #[serde(rename = "Transaction.Begin")]
+    TransactionBegin,
+    #[serde(rename = "Transaction.End")]
+    TransactionEnd,
+    Trigger,
+}
+
+/// Format that specifies how the value element in SampledValue is to be interpreted.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ValueFormat {
+    Raw,
+    SignedData,
+}
+
+/// Allowable values of the optional "location" field of a value element in SampledValue.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Location {
+    Body,
+    Cable,
+    #[serde(rename = "EV")]
+    Ev,
+    Inlet,
+    Outlet,
+}
+
+/// Allowable values of the optional "measurand" field of a Value element, as used in
+/// MeterValues.req and StopTransaction.req messages. Default value of "measurand" is always
+/// "Energy.Active.Import.Register"
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Measurand {
+    #[serde(rename = "Current.Export")]
+    CurrentExport,
+    #[serde(rename = "Current.Import")]
+    CurrentImport,
+    #[serde(rename = "Current.Offered")]
+    CurrentOffered,
+    #[serde(rename = "Energy.Active.Export.Interval")]
+    EnergyActiveExportInterval,
+    #[serde(rename = "Energy.Active.Export.Register")]
+    EnergyActiveExportRegister,
+    #[serde(rename = "Energy.Active.Import.Interval")]
+    EnergyActiveImportInterval,
+    #[serde(rename = "Energy.Active.Import.Register")]
+    EnergyActiveImportRegister,
+    #[serde(rename = "Energy.Reactive.Export.Interval")]
+    EnergyReactiveExportInterval,
+    #[serde(rename = "Energy.Reactive.Export.Register")]
+    EnergyReactiveExportRegister,
+    #[serde(rename = "Energy.Reactive.Import.Interval")]
+    EnergyReactiveImportInterval,
+    #[serde(rename = "Energy.Reactive.Import.Register")]
+    EnergyReactiveImportRegister,
+    Frequency,
+    #[serde(rename = "Power.Active.Export")]
+    PowerActiveExport,
+    #[serde(rename = "Power.Active.Import")]
+    PowerActiveImport,
+    #[serde(rename = "Power.Factor")]
+    PowerFactor,
+    #[serde(rename = "Power.Offered")]
+    PowerOffered,
+    #[serde(rename = "Power.Reactive.Export")]
+    PowerReactiveExport,
+    #[serde(rename = "Power.Reactive.Import")]
+    PowerReactiveImport,
+    #[serde(rename = "RPM")]
+    Rpm,
+    SoC,
+    Temperature,
+    Voltage,
+}
+
+/// Phase as used in SampledValue. Phase specifies how a measured value is to be interpreted.
+/// Please note that not all values of Phase are applicable to all Measurands.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Phase {
+    L1,
+    #[serde(rename = "L1-L2")]
+    L1L2,
+    #[serde(rename = "L1-N")]
+    L1N,
+    L2,
+    #[serde(rename = "L2-L3")]
+    L2L3,
+    #[serde(rename = "L2-N")]
+    L2N,
+    L3,
+    #[serde(rename = "L3-L1")]
+    L3L1,
+    #[serde(rename = "L3-N")]
+    L3N,
+    N,
+}
+
+/// Allowable values of the optional "unit" field of a Value element, as used in SampledValue.
+/// Default value of "unit" is always "Wh".
+#[derive(Serialize, Deserialize, Debug)]
+pub enum UnitOfMeasure {
+    A,
+    Celsius,
+    Fahrenheit,
+    K,
+    #[serde(rename = "kVA")]
+    KVa,
+    #[serde(rename = "kW")]
+    KW,
+    #[serde(rename = "kWh")]
+    KWh,
+    #[serde(rename = "kvar")]
+    Kvar,
+    #[serde(rename = "kvarh")]
+    Kvarh,
+    Percent,
+    V,
+    #[serde(rename = "VA")]
+    Va,
+    W,
+    Wh,
+    #[serde(rename = "var")]
+    Var,
+    #[serde(rename = "varh")]
+    Varh,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RemoteStartTransactionRequest {
+    #[serde(rename = "chargingProfile")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charging_profile: Option<ChargingProfile>,
+    /// Number of the connector on which to start the transaction. connectorId SHALL be &gt; 0
+    #[serde(rename = "connectorId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_id: Option<i64>,
+    /// The identifier that Charge Point must use to start a transaction.
+    #[serde(rename = "idTag")]
+    pub id_tag: String,
+}
+
+/// A ChargingProfile consists of a ChargingSchedule, describing the amount of power or current
+/// that can be delivered per time interval.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChargingProfile {
+    #[serde(rename = "chargingProfileId")]
+    pub charging_profile_id: i64,
+    #[serde(rename = "chargingProfileKind")]
+    pub charging_profile_kind: ChargingProfileKindType,
+    #[serde(rename = "chargingProfilePurpose")]
+    pub charging_profile_purpose: ChargingProfilePurposeType,
+    #[serde(rename = "chargingSchedule")]
+    pub charging_schedule: ChargingSchedule,
+    #[serde(rename = "recurrencyKind")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrency_kind: Option<RecurrencyKindType>,
+    /// Value determining level in hierarchy stack of profiles. Higher values have precedence
+    /// over lower values. Lowest level is 0.
+    #[serde(rename = "stackLevel")]
+    pub stack_level: i64,
+    /// Only valid if ChargingProfilePurpose is set to TxProfile, the transactionId MAY be used to
+    /// match the profile to a specific transaction.
+    #[serde(rename = "transactionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<i64>,
+    /// Point in time at which the profile starts to be valid.
+    #[serde(rename = "validFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_from: Option<String>,
+    /// Point in time at which the profile stops to be valid.
+    #[serde(rename = "validTo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_to: Option<String>,
+}
+
+/// Charging schedule structure defines a list of charging periods.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChargingSchedule {
+    #[serde(rename = "chargingRateUnit")]
+    pub charging_rate_unit: ChargingRateUnitType,
+    #[serde(rename = "chargingSchedulePeriod")]
+    pub charging_schedule_period: Vec<ChargingSchedulePeriod>,
+    /// Duration of the charging schedule in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Minimum charging rate supported by the electric vehicle.
+    #[serde(rename = "minChargingRate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_charging_rate: Option<f64>,
+    /// Starting point of an absolute schedule.
+    #[serde(rename = "startSchedule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_schedule: Option<String>,
+}
+
+/// Charging schedule period structure defines a time period in a charging schedule.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChargingSchedulePeriod {
+    /// Charging rate limit during the schedule period, in the applicable chargingRateUnit.
+    pub limit: f64,
+    /// The number of phases that can be used for charging.
+    #[serde(rename = "numberPhases")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_phases: Option<i64>,
+    /// Start of the period, in seconds from the start of schedule.
+    #[serde(rename = "startPeriod")]
+    pub start_period: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ChargingProfileKindType {
+    Absolute,
+    Recurring,
+    Relative,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ChargingProfilePurposeType {
+    ChargePointMaxProfile,
+    TxDefaultProfile,
+    TxProfile,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ChargingRateUnitType {
+    A,
+    W,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum RecurrencyKindType {
+    Daily,
+    Weekly,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RemoteStopTransactionRequest {
+    /// The identifier of the transaction which Charge Point is requested to stop.
+    #[serde(rename = "transactionId")]
+    pub transaction_id: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReserveNowRequest {
+    /// This contains the id of the connector to be reserved. A value of 0 means that the
+    /// reservation is not for a specific connector.
+    #[serde(rename = "connectorId")]
+    pub connector_id: i64,
+    /// This contains the date and time when the reservation ends.
+    #[serde(rename = "expiryDate")]
+    pub expiry_date: String,
+    /// The identifier for which the Charge Point has to reserve a connector.
+    #[serde(rename = "idTag")]
+    pub id_tag: String,
+    /// The parent idTag.
+    #[serde(rename = "parentIdTag")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id_tag: Option<String>,
+    /// Unique id for this reservation.
+    #[serde(rename = "reservationId")]
+    pub reservation_id: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResetRequest {
+    #[serde(rename = "type")]
+    pub reset_request_type: ResetType,
+}
+
+/// This contains the type of reset that the Charge Point should perform.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ResetType {
+    Hard,
+    Soft,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SendLocalListRequest {
+    /// This contains the version number of the list.
+    #[serde(rename = "listVersion")]
+    pub list_version: i64,
+    /// This contains the Local Authorization List entries.
+    #[serde(rename = "localAuthorizationList")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_authorization_list: Option<Vec<AuthorizationData>>,
+    #[serde(rename = "updateType")]
+    pub update_type: UpdateType,
+}
+
+/// Elements that constitute an entry of a Local Authorization List update.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthorizationData {
+    /// The identifier to which this authorization applies.
+    #[serde(rename = "idTag")]
+    pub id_tag: String,
+    #[serde(rename = "idTagInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_tag_info: Option<IdTagInfo>,
+}
+
+/// This contains the type of update (full or differential) of this request.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum UpdateType {
+    Differential,
+    Full,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SetChargingProfileRequest {
+    /// The connector to which the charging profile applies. If connectorId = 0, the message
+    /// contains an overall limit for the Charge Point.
+    #[serde(rename = "connectorId")]
+    pub connector_id: i64,
+    #[serde(rename = "csChargingProfiles")]
+    pub cs_charging_profiles: ChargingProfile,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StartTransactionRequest {
+    /// This identifies which connector of the Charge Point is used.
+    #[serde(rename = "connectorId")]
+    pub connector_id: i64,
+    /// This contains the identifier for which a transaction has to be started.
+    #[serde(rename = "idTag")]
+    pub id_tag: String,
+    /// This contains the meter value in Wh for the connector at start of the transaction.
+    #[serde(rename = "meterStart")]
+    pub meter_start: i64,
+    /// This contains the id of the reservation that terminates as a result of this transaction.
+    #[serde(rename = "reservationId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reservation_id: Option<i64>,
+    /// This contains the date and time on which the transaction is started.
+    pub timestamp: String,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StatusNotificationRequest {
+    /// The id of the connector for which the status is reported. Id '0' (zero) is used if the
+    /// status is for the Charge Point main controller.
+    #[serde(rename = "connectorId")]
+    pub connector_id: i64,
+    #[serde(rename = "errorCode")]
+    pub error_code: ChargePointErrorCode,
+    /// Additional free format information related to the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info: Option<String>,
+    pub status: ChargePointStatus,
+    /// The time for which the status is reported. If absent time of receipt of the message will
+    /// be assumed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    /// This identifies the vendor-specific implementation.
+    #[serde(rename = "vendorId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_id: Option<String>,
+    /// This contains the vendor-specific error code.
+    #[serde(rename = "vendorErrorCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_error_code: Option<String>,
+}
+
+/// Charge Point status reported in StatusNotification.req.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ChargePointErrorCode {
+    ConnectorLockFailure,
+    #[serde(rename = "EVCommunicationError")]
+    EvCommunicationError,
+    GroundFailure,
+    HighTemperature,
+    InternalError,
+    LocalListConflict,
+    NoError,
+    OtherError,
+    OverCurrentFailure,
+    OverVoltage,
+    PowerMeterFailure,
+    PowerSwitchFailure,
+    ReaderFailure,
+    ResetFailure,
+    UnderVoltage,
+    WeakSignal,
+}
+
+/// Status reported in StatusNotification.req. A status can be reported for the Charge Point
+/// main controller (connectorId = 0) or for a specific connector.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ChargePointStatus {
+    Available,
+    Charging,
+    Faulted,
+    Finishing,
+    Preparing,
+    Reserved,
+    #[serde(rename = "SuspendedEV")]
+    SuspendedEv,
+    #[serde(rename = "SuspendedEVSE")]
+    SuspendedEvse,
+    Unavailable,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StopTransactionRequest {
+    /// This contains the identifier which requested to stop the charging.
+    #[serde(rename = "idTag")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_tag: Option<String>,
+    /// This contains the meter value in Wh for the connector at end of the transaction.
+    #[serde(rename = "meterStop")]
+    pub meter_stop: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<Reason>,
+    /// This contains the date and time on which the transaction is stopped.
+    pub timestamp: String,
+    /// This contains the transaction-id as received by the StartTransaction.conf.
+    #[serde(rename = "transactionId")]
+    pub transaction_id: i64,
+    /// This contains transaction usage details relevant for billing purposes.
+    #[serde(rename = "transactionData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_data: Option<Vec<MeterValue>>,
+}
+
+/// This contains the reason why the transaction was stopped. MAY only be omitted when the
+/// Reason is "Local".
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Reason {
+    DeAuthorized,
+    EmergencyStop,
+    #[serde(rename = "EVDisconnected")]
+    EvDisconnected,
+    HardReset,
+    Local,
+    Other,
+    PowerLoss,
+    Reboot,
+    Remote,
+    SoftReset,
+    UnlockCommand,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TriggerMessageRequest {
+    /// Only filled in when request applies to a specific connector.
+    #[serde(rename = "connectorId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_id: Option<i64>,
+    #[serde(rename = "requestedMessage")]
+    pub requested_message: MessageTrigger,
+}
+
+/// Type of request to be triggered in a TriggerMessage.req.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum MessageTrigger {
+    BootNotification,
+    DiagnosticsStatusNotification,
+    FirmwareStatusNotification,
+    Heartbeat,
+    MeterValues,
+    StatusNotification,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UnlockConnectorRequest {
+    /// This contains the identifier of the connector to be unlocked.
+    #[serde(rename = "connectorId")]
+    pub connector_id: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateFirmwareRequest {
+    /// This contains a string containing a URI pointing to a location from which to retrieve
+    /// the firmware.
+    pub location: String,
+    /// This specifies how many times Charge Point must try to download the firmware before
+    /// giving up.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i64>,
+    /// This contains the date and time after which the Charge Point is allowed to retrieve the
+    /// (new) firmware.
+    #[serde(rename = "retrieveDate")]
+    pub retrieve_date: String,
+    /// The interval in seconds after which a retry may be attempted.
+    #[serde(rename = "retryInterval")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_interval: Option<i64>,
+}
@@ -0,0 +1,377 @@
+use serde::{Serialize, Deserialize};
+
+use super::requests::ChargingSchedule;
+
+// structures created with the help of https://app.quicktype.io/ and OCPP 1.6 json schema provided by
+// https://www.openchargealliance.org/
+
+#[derive(Serialize, Deserialize)]
+pub struct AuthorizeResponse {
+    #[serde(rename = "idTagInfo")]
+    pub id_tag_info: IdTagInfo,
+}
+
+/// Contains status information about an identifier. It is returned in Authorize,
+/// Start Transaction and Stop Transaction responses.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct IdTagInfo {
+    /// This contains the date at which idTag should be removed from the Authorization Cache.
+    #[serde(rename = "expiryDate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_date: Option<String>,
+    /// This contains the parent-identifier.
+    #[serde(rename = "parentIdTag")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id_tag: Option<String>,
+    /// This contains whether the idTag has been accepted or not by the Central System.
+    pub status: AuthorizationStatus,
+}
+
+/// Status in a response to an Authorize.req.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum AuthorizationStatus {
+    Accepted,
+    Blocked,
+    ConcurrentTx,
+    Expired,
+    Invalid,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct BootNotificationResponse {
+    /// This contains the Central System’s current time.
+    #[serde(rename = "currentTime")]
+    pub current_time: String,
+    /// When RegistrationStatus is Accepted, this contains the heartbeat interval in seconds. If
+    /// the Central System returns something other than Accepted, the value of the interval
+    /// field indicates the minimum wait time before sending a next BootNotification request.
+    pub interval: i64,
+    pub status: RegistrationStatus,
+}
+
+/// This contains whether the Charge Point has been registered within the System Central.
+#[derive(Serialize, Deserialize)]
+pub enum RegistrationStatus {
+    Accepted,
+    Pending,
+    Rejected,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct CancelReservationResponse {
+    pub status: CancelReservationStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum CancelReservationStatus {
+    Accepted,
+    Rejected,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct ChangeAvailabilityResponse {
+    pub status: AvailabilityStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum AvailabilityStatus {
+    Accepted,
+    Rejected,
+    Scheduled,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct ChangeConfigurationResponse {
+    pub status: ConfigurationStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum ConfigurationStatus {
+    Accepted,
+    NotSupported,
+    RebootRequired,
+    Rejected,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct ClearCacheResponse {
+    pub status: ClearCacheStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum ClearCacheStatus {
+    Accepted,
+    Rejected,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct ClearChargingProfileResponse {
+    pub status: ClearChargingProfileStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum ClearChargingProfileStatus {
+    Accepted,
+    Unknown,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct DataTransferResponse {
+    /// Data in response to request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    pub status: DataTransferStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum DataTransferStatus {
+    Accepted,
+    Rejected,
+    UnknownMessageId,
+    UnknownVendorId,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct DiagnosticsStatusNotificationResponse {}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct FirmwareStatusNotificationResponse {}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct GetCompositeScheduleResponse {
+    #[serde(rename = "chargingSchedule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charging_schedule: Option<ChargingSchedule>,
+    /// The charging schedule contained in this notification applies to a Connector.
+    #[serde(rename = "connectorId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_id: Option<i64>,
+    /// Time. Periods contained in the charging profile are relative to this point in time.
+    #[serde(rename = "scheduleStart")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_start: Option<String>,
+    pub status: GetCompositeScheduleStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum GetCompositeScheduleStatus {
+    Accepted,
+    Rejected,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct GetConfigurationResponse {
+    /// List of requested or known keys
+    #[serde(rename = "configurationKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration_key: Option<Vec<KeyValue>>,
+    /// Requested keys that are unknown
+    #[serde(rename = "unknownKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unknown_key: Option<Vec<String>>,
+}
+
+/// Contains information about a specific configuration key. It is returned in
+/// GetConfiguration.conf.
+#[derive(Serialize, Deserialize)]
+pub struct KeyValue {
+    pub key: String,
+    /// False if the value can be set with the ChangeConfiguration message.
+    pub readonly: bool,
+    /// If the key is known but not set, this field may be absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct GetDiagnosticsResponse {
+    /// This contains the name of the file with diagnostic information that will be uploaded.
+    /// This field is not present when no diagnostic information is available.
+    #[serde(rename = "fileName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct GetLocalListVersionResponse {
+    /// This contains the current version number of the local authorization list in the Charge
+    /// Point.
+    #[serde(rename = "listVersion")]
+    pub list_version: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct HeartbeatResponse {
+    /// This contains the current time of the Central System.
+    #[serde(rename = "currentTime")]
+    pub current_time: String,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct MeterValuesResponse {}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct RemoteStartTransactionResponse {
+    pub status: RemoteStartStopStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum RemoteStartStopStatus {
+    Accepted,
+    Rejected,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct RemoteStopTransactionResponse {
+    pub status: RemoteStartStopStatus,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct ReserveNowResponse {
+    pub status: ReservationStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum ReservationStatus {
+    Accepted,
+    Faulted,
+    Occupied,
+    Rejected,
+    Unavailable,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct ResetResponse {
+    pub status: ResetStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum ResetStatus {
+    Accepted,
+    Rejected,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct SendLocalListResponse {
+    pub status: UpdateStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum UpdateStatus {
+    Accepted,
+    Failed,
+    NotSupported,
+    VersionMismatch,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct SetChargingProfileResponse {
+    pub status: ChargingProfileStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum ChargingProfileStatus {
+    Accepted,
+    NotSupported,
+    Rejected,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct StartTransactionResponse {
+    #[serde(rename = "idTagInfo")]
+    pub id_tag_info: IdTagInfo,
+    /// This contains the transaction id supplied by the Central System.
+    #[serde(rename = "transactionId")]
+    pub transaction_id: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct StatusNotificationResponse {}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct StopTransactionResponse {
+    #[serde(rename = "idTagInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_tag_info: Option<IdTagInfo>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct TriggerMessageResponse {
+    pub status: TriggerMessageStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum TriggerMessageStatus {
+    Accepted,
+    NotImplemented,
+    Rejected,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct UnlockConnectorResponse {
+    pub status: UnlockStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum UnlockStatus {
+    NotSupported,
+    UnlockFailed,
+    Unlocked,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdateFirmwareResponse {}
@@ -0,0 +1,212 @@
+use serde_json::Value;
+
+use crate::messages::{wrap_call_result, wrap_call_error_result, ErrorCode};
+
+pub mod requests;
+pub mod responses;
+
+// OCPP 2.1 is a superset of 2.0.1: every 2.0.1 message is still valid and keeps its framing, so
+// calls that did not change are handled by the 2.0.1 functions of the parent module. This module
+// only holds the handlers of the messages introduced in 2.1.
+
+/// The actions introduced in OCPP 2.1
+pub const ACTIONS: [&str; 27] = [
+    "AFRRSignal", "AdjustPeriodicEventStream", "BatterySwap", "ChangeTransactionTariff", "ClearDERControl",
+    "ClearTariffs", "ClosePeriodicEventStream", "GetDERControl", "GetPeriodicEventStream", "GetTariffs",
+    "NotifyAllowedEnergyTransfer", "NotifyDERAlarm", "NotifyDERStartStop", "NotifyPeriodicEventStream",
+    "NotifyPriorityCharging", "NotifyQRCodeScanned", "NotifySettlement", "NotifyWebPaymentStarted",
+    "OpenPeriodicEventStream", "PullDynamicScheduleUpdate", "ReportDERControl", "RequestBatterySwap",
+    "SetDERControl", "SetDefaultTariff", "UpdateDynamicSchedule", "UsePriorityCharging", "VatNumberValidation",
+];
+
+pub fn battery_swap_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::BatterySwapRequest, serde_json::Error> {
+        Ok(_) => {
+            let response = responses::BatterySwapResponse { custom_data: None };
+            wrap_call_result(message_id, serde_json::to_string(&response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn close_periodic_event_stream_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::ClosePeriodicEventStreamRequest, serde_json::Error> {
+        Ok(_) => {
+            let response = responses::ClosePeriodicEventStreamResponse { custom_data: None };
+            wrap_call_result(message_id, serde_json::to_string(&response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn notify_der_alarm_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::NotifyDerAlarmRequest, serde_json::Error> {
+        Ok(_) => {
+            let response = responses::NotifyDerAlarmResponse { custom_data: None };
+            wrap_call_result(message_id, serde_json::to_string(&response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn notify_der_start_stop_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::NotifyDerStartStopRequest, serde_json::Error> {
+        Ok(_) => {
+            let response = responses::NotifyDerStartStopResponse { custom_data: None };
+            wrap_call_result(message_id, serde_json::to_string(&response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn notify_priority_charging_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::NotifyPriorityChargingRequest, serde_json::Error> {
+        Ok(_) => {
+            let response = responses::NotifyPriorityChargingResponse { custom_data: None };
+            wrap_call_result(message_id, serde_json::to_string(&response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn notify_qr_code_scanned_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::NotifyQrCodeScannedRequest, serde_json::Error> {
+        Ok(_) => {
+            let response = responses::NotifyQrCodeScannedResponse { custom_data: None };
+            wrap_call_result(message_id, serde_json::to_string(&response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn notify_settlement_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::NotifySettlementRequest, serde_json::Error> {
+        Ok(_) => {
+            // receipts are not generated by the server
+            let response = responses::NotifySettlementResponse {
+                custom_data: None,
+                receipt_id: None,
+                receipt_url: None,
+            };
+            wrap_call_result(message_id, serde_json::to_string(&response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn open_periodic_event_stream_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::OpenPeriodicEventStreamRequest, serde_json::Error> {
+        Ok(_) => {
+            let response = responses::OpenPeriodicEventStreamResponse {
+                custom_data: None,
+                status: responses::GenericStatusEnumType::Accepted,
+                status_info: None,
+            };
+            wrap_call_result(message_id, serde_json::to_string(&response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn pull_dynamic_schedule_update_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::PullDynamicScheduleUpdateRequest, serde_json::Error> {
+        Ok(_) => {
+            // dynamic charging profiles are not managed by the server, so there is nothing to update
+            let response = responses::PullDynamicScheduleUpdateResponse {
+                custom_data: None,
+                schedule_update: None,
+                status: responses::ChargingProfileStatusEnumType::Rejected,
+                status_info: None,
+            };
+            wrap_call_result(message_id, serde_json::to_string(&response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn report_der_control_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::ReportDerControlRequest, serde_json::Error> {
+        Ok(_) => {
+            let response = responses::ReportDerControlResponse { custom_data: None };
+            wrap_call_result(message_id, serde_json::to_string(&response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+pub fn vat_number_validation_response(message_id: &String, payload: &str) -> String {
+    match serde_json::from_str(payload) as Result<requests::VatNumberValidationRequest, serde_json::Error> {
+        Ok(request) => {
+            // there is no VAT registry to check against
+            let response = responses::VatNumberValidationResponse {
+                company: None,
+                custom_data: None,
+                evse_id: request.evse_id,
+                status: responses::GenericStatusEnumType::Rejected,
+                status_info: None,
+                vat_number: request.vat_number,
+            };
+            wrap_call_result(message_id, serde_json::to_string(&response).unwrap())
+        }
+        Err(e) => {
+            wrap_call_error_result(message_id, ErrorCode::FormatViolation, &format!("{:#?}", e))
+        }
+    }
+}
+
+/// Checks the payload of a call that the web client wants to send to an OCPP 2.1 charging
+/// station. Actions that exist in 2.0.1 are checked by the 2.0.1 validation of the server.
+pub fn message_from_web_browser_is_valid(action: &str, payload: &Value) -> Option<bool> {
+    let payload = payload.clone();
+    match action {
+        "AFRRSignal" => Some(serde_json::from_value::<requests::AfrrSignalRequest>(payload).is_ok()),
+        "AdjustPeriodicEventStream" => Some(serde_json::from_value::<requests::AdjustPeriodicEventStreamRequest>(payload).is_ok()),
+        "ChangeTransactionTariff" => Some(serde_json::from_value::<requests::ChangeTransactionTariffRequest>(payload).is_ok()),
+        "ClearDERControl" => Some(serde_json::from_value::<requests::ClearDerControlRequest>(payload).is_ok()),
+        "ClearTariffs" => Some(serde_json::from_value::<requests::ClearTariffsRequest>(payload).is_ok()),
+        "GetDERControl" => Some(serde_json::from_value::<requests::GetDerControlRequest>(payload).is_ok()),
+        "GetPeriodicEventStream" => Some(serde_json::from_value::<requests::GetPeriodicEventStreamRequest>(payload).is_ok()),
+        "GetTariffs" => Some(serde_json::from_value::<requests::GetTariffsRequest>(payload).is_ok()),
+        "NotifyAllowedEnergyTransfer" => Some(serde_json::from_value::<requests::NotifyAllowedEnergyTransferRequest>(payload).is_ok()),
+        "NotifyWebPaymentStarted" => Some(serde_json::from_value::<requests::NotifyWebPaymentStartedRequest>(payload).is_ok()),
+        "RequestBatterySwap" => Some(serde_json::from_value::<requests::RequestBatterySwapRequest>(payload).is_ok()),
+        "SetDefaultTariff" => Some(serde_json::from_value::<requests::SetDefaultTariffRequest>(payload).is_ok()),
+        "SetDERControl" => Some(serde_json::from_value::<requests::SetDerControlRequest>(payload).is_ok()),
+        "UpdateDynamicSchedule" => Some(serde_json::from_value::<requests::UpdateDynamicScheduleRequest>(payload).is_ok()),
+        "UsePriorityCharging" => Some(serde_json::from_value::<requests::UsePriorityChargingRequest>(payload).is_ok()),
+        _ => None
+    }
+}
+
+/// Handles a SEND message (MessageTypeId 6). SEND messages are never answered, not even with a
+/// CallError, so an invalid payload is only reported back to the caller for logging.
+pub fn handle_send(action: &str, payload: &str) -> Result<(), String> {
+    match action {
+        "NotifyPeriodicEventStream" => {
+            serde_json::from_str::<requests::NotifyPeriodicEventStreamRequest>(payload)
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        }
+        _ => Err(format!("{} is not a known SEND message", action))
+    }
+}
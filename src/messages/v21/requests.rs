@@ -0,0 +1,1090 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+
+// OCPP 2.1 keeps the 2.0.1 messages and extends them with optional fields only, so their
+// structures are re-exported from the 2.0.1 module. Structures defined below are new in 2.1, or
+// replace the 2.0.1 ones where an enumeration gained new values.
+pub use crate::messages::requests::*;
+
+// structures created with the help of https://app.quicktype.io/ and OCPP 2.1 json schema provided by
+// https://www.openchargealliance.org/
+
+/// Mode of energy transfer requested by the EV. OCPP 2.1 adds the bidirectional (BPT), DER and
+/// wireless modes.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum EnergyTransferModeEnumType {
+    #[serde(rename = "AC_single_phase")]
+    AcSinglePhase,
+    #[serde(rename = "AC_three_phase")]
+    AcThreePhase,
+    #[serde(rename = "AC_two_phase")]
+    AcTwoPhase,
+    #[serde(rename = "AC_BPT")]
+    AcBpt,
+    #[serde(rename = "AC_BPT_DER")]
+    AcBptDer,
+    #[serde(rename = "AC_DER")]
+    AcDer,
+    #[serde(rename = "DC")]
+    Dc,
+    #[serde(rename = "DC_ACDP")]
+    DcAcdp,
+    #[serde(rename = "DC_ACDP_BPT")]
+    DcAcdpBpt,
+    #[serde(rename = "DC_BPT")]
+    DcBpt,
+    #[serde(rename = "WPT")]
+    Wpt,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AfrrSignalRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Value of signal in _v2xSignalWattCurve_.
+    pub signal: i64,
+    /// Time when signal becomes active.
+    pub timestamp: String,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AdjustPeriodicEventStreamRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    pub id: i64,
+    pub params: PeriodicEventStreamParamsType,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PeriodicEventStreamParamsType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Time in seconds after which stream data is sent.
+    pub interval: Option<i64>,
+    /// Number of items to be sent together in stream.
+    pub values: Option<i64>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BatterySwapRequest {
+    #[serde(rename = "batteryData")]
+    pub battery_data: Vec<BatteryDataType>,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    #[serde(rename = "eventType")]
+    pub event_type: BatterySwapEventEnumType,
+    #[serde(rename = "idToken")]
+    pub id_token: IdTokenType,
+    /// RequestId to correlate BatteryIn/Out events and optional RequestBatterySwapRequest.
+    #[serde(rename = "requestId")]
+    pub request_id: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BatteryDataType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Slot number where battery is inserted or removed.
+    #[serde(rename = "evseId")]
+    pub evse_id: i64,
+    /// Production date of battery.
+    #[serde(rename = "productionDate")]
+    pub production_date: Option<String>,
+    /// Serial number of battery.
+    #[serde(rename = "serialNumber")]
+    pub serial_number: String,
+    /// State of charge
+    #[serde(rename = "soC")]
+    pub so_c: f64,
+    /// State of health
+    #[serde(rename = "soH")]
+    pub so_h: f64,
+    /// Vendor-specific info from battery in undefined format.
+    #[serde(rename = "vendorInfo")]
+    pub vendor_info: Option<String>,
+}
+
+/// Battery in/out
+#[derive(Serialize, Deserialize, Debug)]
+pub enum BatterySwapEventEnumType {
+    BatteryIn,
+    BatteryOut,
+    BatteryOutTimeout,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChangeTransactionTariffRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    pub tariff: TariffType,
+    /// Transaction id for new tariff.
+    #[serde(rename = "transactionId")]
+    pub transaction_id: String,
+}
+
+/// A tariff is described by fields with prices for: energy, charging time, idle time, fixed
+/// fee, reservation time, reservation fixed fee. Each of these fields may have (optional)
+/// conditions that specify when a price is applicable.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TariffType {
+    #[serde(rename = "chargingTime")]
+    pub charging_time: Option<TariffTimeType>,
+    /// Currency code according to ISO 4217
+    pub currency: String,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Description of the tariff in one or more languages
+    pub description: Option<Vec<MessageContentType>>,
+    pub energy: Option<TariffEnergyType>,
+    #[serde(rename = "fixedFee")]
+    pub fixed_fee: Option<TariffFixedType>,
+    #[serde(rename = "idleTime")]
+    pub idle_time: Option<TariffTimeType>,
+    #[serde(rename = "maxCost")]
+    pub max_cost: Option<PriceType>,
+    #[serde(rename = "minCost")]
+    pub min_cost: Option<PriceType>,
+    #[serde(rename = "reservationFixed")]
+    pub reservation_fixed: Option<TariffFixedType>,
+    #[serde(rename = "reservationTime")]
+    pub reservation_time: Option<TariffTimeType>,
+    /// Unique id of tariff
+    #[serde(rename = "tariffId")]
+    pub tariff_id: String,
+    /// Time when this tariff becomes active. When absent, it is immediately active.
+    #[serde(rename = "validFrom")]
+    pub valid_from: Option<String>,
+}
+
+/// Price with and without tax. At least one of _exclTax_, _inclTax_ must be present.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PriceType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Price/cost excluding tax. Can be absent if _inclTax_ is present.
+    #[serde(rename = "exclTax")]
+    pub excl_tax: Option<f64>,
+    /// Price/cost including tax. Can be absent if _exclTax_ is present.
+    #[serde(rename = "inclTax")]
+    pub incl_tax: Option<f64>,
+    #[serde(rename = "taxRates")]
+    pub tax_rates: Option<Vec<TaxRateType>>,
+}
+
+/// Tax percentage
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TaxRateType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Stack level for this type of tax. Default value, when absent, is 0.
+    pub stack: Option<i64>,
+    /// Tax percentage
+    pub tax: f64,
+    /// Type of this tax, e.g. "Federal ", "State", for information on receipt.
+    #[serde(rename = "type")]
+    pub tax_rate_type_type: String,
+}
+
+/// Price elements and tax for energy
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TariffEnergyType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    pub prices: Vec<TariffEnergyPriceType>,
+    #[serde(rename = "taxRates")]
+    pub tax_rates: Option<Vec<TaxRateType>>,
+}
+
+/// Tariff with optional conditions for an energy price.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TariffEnergyPriceType {
+    pub conditions: Option<TariffConditionsType>,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Price per kWh (excl. tax) for this element.
+    #[serde(rename = "priceKwh")]
+    pub price_kwh: f64,
+}
+
+/// Price elements and tax for time
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TariffTimeType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    pub prices: Vec<TariffTimePriceType>,
+    #[serde(rename = "taxRates")]
+    pub tax_rates: Option<Vec<TaxRateType>>,
+}
+
+/// Price element per time
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TariffTimePriceType {
+    pub conditions: Option<TariffConditionsType>,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Price per minute (excl. tax) for this element.
+    #[serde(rename = "priceMinute")]
+    pub price_minute: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TariffFixedType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    pub prices: Vec<TariffFixedPriceType>,
+    #[serde(rename = "taxRates")]
+    pub tax_rates: Option<Vec<TaxRateType>>,
+}
+
+/// Tariff with optional conditions for a fixed price.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TariffFixedPriceType {
+    pub conditions: Option<TariffConditionsFixedType>,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Fixed price for this element e.g. a start fee.
+    #[serde(rename = "priceFixed")]
+    pub price_fixed: f64,
+}
+
+/// These conditions describe if and when a TariffEnergyType or TariffTimeType applies during a
+/// transaction. When more than one restriction is set, they are to be treated as a logical AND.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TariffConditionsType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Day(s) of the week this is tariff applies.
+    #[serde(rename = "dayOfWeek")]
+    pub day_of_week: Option<Vec<DayOfWeekEnumType>>,
+    /// End time of day in local time. Format as per RFC 3339: time-hour ":" time-minute
+    #[serde(rename = "endTimeOfDay")]
+    pub end_time_of_day: Option<String>,
+    #[serde(rename = "evseKind")]
+    pub evse_kind: Option<EvseKindEnumType>,
+    /// Maximum current in Amperes, less than this value
+    #[serde(rename = "maxCurrent")]
+    pub max_current: Option<f64>,
+    /// Maximum consumed energy in Wh, for example 50000 Wh
+    #[serde(rename = "maxEnergy")]
+    pub max_energy: Option<f64>,
+    /// Maximum power in W, less than this value
+    #[serde(rename = "maxPower")]
+    pub max_power: Option<f64>,
+    /// Maximum duration in seconds the transaction (charging &amp; idle) MUST last
+    #[serde(rename = "maxTime")]
+    pub max_time: Option<i64>,
+    /// Minimum current in Amperes
+    #[serde(rename = "minCurrent")]
+    pub min_current: Option<f64>,
+    /// Minimum consumed energy in Wh, for example 20000 Wh
+    #[serde(rename = "minEnergy")]
+    pub min_energy: Option<f64>,
+    /// Minimum power in W
+    #[serde(rename = "minPower")]
+    pub min_power: Option<f64>,
+    /// Minimum duration in seconds the transaction (charging &amp; idle) MUST last
+    #[serde(rename = "minTime")]
+    pub min_time: Option<i64>,
+    /// Start time of day in local time. Format as per RFC 3339: time-hour ":" time-minute
+    #[serde(rename = "startTimeOfDay")]
+    pub start_time_of_day: Option<String>,
+    /// Start date in local time, for example: 2015-12-24.
+    #[serde(rename = "validFromDate")]
+    pub valid_from_date: Option<String>,
+    /// End date in local time, for example: 2015-12-27.
+    #[serde(rename = "validToDate")]
+    pub valid_to_date: Option<String>,
+}
+
+/// These conditions describe if a FixedPrice applies at start of the transaction.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TariffConditionsFixedType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    #[serde(rename = "dayOfWeek")]
+    pub day_of_week: Option<Vec<DayOfWeekEnumType>>,
+    #[serde(rename = "endTimeOfDay")]
+    pub end_time_of_day: Option<String>,
+    #[serde(rename = "evseKind")]
+    pub evse_kind: Option<EvseKindEnumType>,
+    /// For which payment brand this (adhoc) tariff applies.
+    #[serde(rename = "paymentBrand")]
+    pub payment_brand: Option<String>,
+    /// Type of adhoc payment, e.g. CC, Debit.
+    #[serde(rename = "paymentRecognition")]
+    pub payment_recognition: Option<String>,
+    #[serde(rename = "startTimeOfDay")]
+    pub start_time_of_day: Option<String>,
+    #[serde(rename = "validFromDate")]
+    pub valid_from_date: Option<String>,
+    #[serde(rename = "validToDate")]
+    pub valid_to_date: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum DayOfWeekEnumType {
+    Friday,
+    Monday,
+    Saturday,
+    Sunday,
+    Thursday,
+    Tuesday,
+    Wednesday,
+}
+
+/// Type of EVSE (AC, DC) this tariff applies to.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum EvseKindEnumType {
+    #[serde(rename = "AC")]
+    Ac,
+    #[serde(rename = "DC")]
+    Dc,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClearDerControlRequest {
+    #[serde(rename = "controlId")]
+    pub control_id: Option<String>,
+    #[serde(rename = "controlType")]
+    pub control_type: Option<DerControlEnumType>,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// True: clearing default DER controls. False: clearing scheduled controls.
+    #[serde(rename = "isDefault")]
+    pub is_default: bool,
+}
+
+/// Type of DER control, e.g. a volt-var curve or a fixed power factor.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum DerControlEnumType {
+    EnterService,
+    FixedPFAbsorb,
+    FixedPFInject,
+    FixedVar,
+    FreqDroop,
+    FreqWatt,
+    Gradients,
+    HFMayTrip,
+    HFMustTrip,
+    HVMayTrip,
+    HVMomCess,
+    HVMustTrip,
+    LFMustTrip,
+    LVMayTrip,
+    LVMomCess,
+    LVMustTrip,
+    LimitMaxDischarge,
+    PowerMonitoringMustTrip,
+    VoltVar,
+    VoltWatt,
+    WattPF,
+    WattVar,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClearTariffsRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// When present only clear tariffs matching _tariffIds_ at EVSE _evseId_.
+    #[serde(rename = "evseId")]
+    pub evse_id: Option<i64>,
+    /// List of tariff Ids to clear. When absent clears all tariffs at _evseId_.
+    #[serde(rename = "tariffIds")]
+    pub tariff_ids: Option<Vec<String>>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClosePeriodicEventStreamRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Id of stream to close.
+    pub id: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetDerControlRequest {
+    #[serde(rename = "controlId")]
+    pub control_id: Option<String>,
+    #[serde(rename = "controlType")]
+    pub control_type: Option<DerControlEnumType>,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// True: get a default DER control. False: get a scheduled control.
+    #[serde(rename = "isDefault")]
+    pub is_default: Option<bool>,
+    /// RequestId to be used in ReportDERControlRequest.
+    #[serde(rename = "requestId")]
+    pub request_id: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetPeriodicEventStreamRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetTariffsRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// EVSE id to get tariff from. When _evseId_ = 0, this gets tariffs from all EVSEs.
+    #[serde(rename = "evseId")]
+    pub evse_id: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NotifyAllowedEnergyTransferRequest {
+    /// Modes of energy transfer that are accepted by CSMS.
+    #[serde(rename = "allowedEnergyTransfer")]
+    pub allowed_energy_transfer: Vec<EnergyTransferModeEnumType>,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// The transaction for which the allowed energy transfer is allowed.
+    #[serde(rename = "transactionId")]
+    pub transaction_id: String,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NotifyDerAlarmRequest {
+    /// True when error condition has ended. Absent or false when alarm has started.
+    #[serde(rename = "alarmEnded")]
+    pub alarm_ended: Option<bool>,
+    #[serde(rename = "controlType")]
+    pub control_type: DerControlEnumType,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Optional info provided by EV.
+    #[serde(rename = "extraInfo")]
+    pub extra_info: Option<String>,
+    #[serde(rename = "gridEventFault")]
+    pub grid_event_fault: Option<GridEventFaultEnumType>,
+    /// Time of start or end of alarm.
+    pub timestamp: String,
+}
+
+/// Type of grid event that caused this
+#[derive(Serialize, Deserialize, Debug)]
+pub enum GridEventFaultEnumType {
+    CurrentImbalance,
+    LocalEmergency,
+    LowInputPower,
+    OverCurrent,
+    OverFrequency,
+    OverVoltage,
+    PhaseRotation,
+    RemoteEmergency,
+    UnderFrequency,
+    UnderVoltage,
+    VoltageImbalance,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NotifyDerStartStopRequest {
+    /// Id of the started or stopped DER control. Corresponds to the _controlId_ of the
+    /// SetDERControlRequest.
+    #[serde(rename = "controlId")]
+    pub control_id: String,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// True if DER control has started. False if it has ended.
+    pub started: bool,
+    /// List of controlIds that are superseded as a result of this control starting.
+    #[serde(rename = "supersededIds")]
+    pub superseded_ids: Option<Vec<String>>,
+    /// Time of start or end of event.
+    pub timestamp: String,
+}
+
+//=================================================================================================
+
+/// Sent as a SEND message (MessageTypeId 6), the CSMS does not respond to it.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NotifyPeriodicEventStreamRequest {
+    /// Base timestamp to add to time offset of values.
+    pub basetime: String,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    pub data: Vec<StreamDataElementType>,
+    pub id: i64,
+    /// Number of data elements still pending to be sent.
+    pub pending: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StreamDataElementType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Offset relative to _basetime_ of this message. _basetime_ + _t_ is timestamp of recorded
+    /// value.
+    pub t: f64,
+    pub v: String,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NotifyPriorityChargingRequest {
+    /// True if priority charging was activated. False if it has stopped using the priority
+    /// charging profile.
+    pub activated: bool,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// The transaction for which priority charging is requested.
+    #[serde(rename = "transactionId")]
+    pub transaction_id: String,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NotifyQrCodeScannedRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// EVSE id for which transaction is requested.
+    #[serde(rename = "evseId")]
+    pub evse_id: i64,
+    /// Timeout value in seconds after which no result of web payment process (e.g. QR code
+    /// scanning) is to be expected anymore.
+    pub timeout: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NotifySettlementRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// The payment reference received from the payment terminal and is used as the value for
+    /// _idToken_.
+    #[serde(rename = "pspRef")]
+    pub psp_ref: String,
+    /// The receipt id, to be used if the receipt is generated by the payment terminal or the
+    /// Charging Station.
+    #[serde(rename = "receiptId")]
+    pub receipt_id: Option<String>,
+    /// The receipt URL, to be used if the receipt is generated by the payment terminal or the
+    /// Charging Station.
+    #[serde(rename = "receiptUrl")]
+    pub receipt_url: Option<String>,
+    /// The amount that was settled, or attempted to be settled (in case of failure).
+    #[serde(rename = "settlementAmount")]
+    pub settlement_amount: f64,
+    /// The time when the settlement was done.
+    #[serde(rename = "settlementTime")]
+    pub settlement_time: String,
+    pub status: PaymentStatusEnumType,
+    /// Additional information from payment terminal/payment process.
+    #[serde(rename = "statusInfo")]
+    pub status_info: Option<String>,
+    /// The _transactionId_ that the settlement belongs to. Can be empty if the payment
+    /// transaction is canceled prior to the start of the OCPP transaction.
+    #[serde(rename = "transactionId")]
+    pub transaction_id: Option<String>,
+    /// VAT number for a company receipt.
+    #[serde(rename = "vatNumber")]
+    pub vat_number: Option<String>,
+}
+
+/// The status of the settlement attempt.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum PaymentStatusEnumType {
+    Canceled,
+    Failed,
+    Rejected,
+    Settled,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NotifyWebPaymentStartedRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// EVSE id for which transaction is requested.
+    #[serde(rename = "evseId")]
+    pub evse_id: i64,
+    /// Timeout value in seconds after which no result of web payment process is to be expected
+    /// anymore.
+    pub timeout: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OpenPeriodicEventStreamRequest {
+    #[serde(rename = "constantStreamData")]
+    pub constant_stream_data: ConstantStreamDataType,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConstantStreamDataType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Uniquely identifies the stream
+    pub id: i64,
+    pub params: PeriodicEventStreamParamsType,
+    /// Id of monitor used to report his event. It can be a preconfigured or hardwired monitor.
+    #[serde(rename = "variableMonitoringId")]
+    pub variable_monitoring_id: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PullDynamicScheduleUpdateRequest {
+    /// Id of charging profile to update.
+    #[serde(rename = "chargingProfileId")]
+    pub charging_profile_id: i64,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReportDerControlRequest {
+    pub curve: Option<Vec<DerCurveGetType>>,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    #[serde(rename = "enterService")]
+    pub enter_service: Option<Vec<EnterServiceGetType>>,
+    #[serde(rename = "fixedPFAbsorb")]
+    pub fixed_pf_absorb: Option<Vec<FixedPfGetType>>,
+    #[serde(rename = "fixedPFInject")]
+    pub fixed_pf_inject: Option<Vec<FixedPfGetType>>,
+    #[serde(rename = "fixedVar")]
+    pub fixed_var: Option<Vec<FixedVarGetType>>,
+    #[serde(rename = "freqDroop")]
+    pub freq_droop: Option<Vec<FreqDroopGetType>>,
+    pub gradient: Option<Vec<GradientGetType>>,
+    #[serde(rename = "limitMaxDischarge")]
+    pub limit_max_discharge: Option<Vec<LimitMaxDischargeGetType>>,
+    /// RequestId from GetDERControlRequest.
+    #[serde(rename = "requestId")]
+    pub request_id: i64,
+    /// To Be Continued. Default value when omitted: false. False indicates that there are no
+    /// further messages as part of this report.
+    pub tbc: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DerCurveGetType {
+    pub curve: DerCurveType,
+    #[serde(rename = "curveType")]
+    pub curve_type: DerControlEnumType,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    pub id: String,
+    #[serde(rename = "isDefault")]
+    pub is_default: bool,
+    #[serde(rename = "isSuperseded")]
+    pub is_superseded: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EnterServiceGetType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    #[serde(rename = "enterService")]
+    pub enter_service: EnterServiceType,
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FixedPfGetType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    #[serde(rename = "fixedPF")]
+    pub fixed_pf: FixedPfType,
+    pub id: String,
+    #[serde(rename = "isDefault")]
+    pub is_default: bool,
+    #[serde(rename = "isSuperseded")]
+    pub is_superseded: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FixedVarGetType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    #[serde(rename = "fixedVar")]
+    pub fixed_var: FixedVarType,
+    pub id: String,
+    #[serde(rename = "isDefault")]
+    pub is_default: bool,
+    #[serde(rename = "isSuperseded")]
+    pub is_superseded: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FreqDroopGetType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    #[serde(rename = "freqDroop")]
+    pub freq_droop: FreqDroopType,
+    pub id: String,
+    #[serde(rename = "isDefault")]
+    pub is_default: bool,
+    #[serde(rename = "isSuperseded")]
+    pub is_superseded: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GradientGetType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    pub gradient: GradientType,
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LimitMaxDischargeGetType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    pub id: String,
+    #[serde(rename = "isDefault")]
+    pub is_default: bool,
+    #[serde(rename = "isSuperseded")]
+    pub is_superseded: bool,
+    #[serde(rename = "limitMaxDischarge")]
+    pub limit_max_discharge: LimitMaxDischargeType,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RequestBatterySwapRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    #[serde(rename = "idToken")]
+    pub id_token: IdTokenType,
+    /// Request id to match with BatterySwapRequest.
+    #[serde(rename = "requestId")]
+    pub request_id: i64,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SetDefaultTariffRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// EVSE that tariff applies to. When _evseId_ = 0, then tarriff applies to all EVSEs.
+    #[serde(rename = "evseId")]
+    pub evse_id: i64,
+    pub tariff: TariffType,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SetDerControlRequest {
+    /// Unique id of this control, e.g. UUID
+    #[serde(rename = "controlId")]
+    pub control_id: String,
+    #[serde(rename = "controlType")]
+    pub control_type: DerControlEnumType,
+    pub curve: Option<DerCurveType>,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    #[serde(rename = "enterService")]
+    pub enter_service: Option<EnterServiceType>,
+    #[serde(rename = "fixedPFAbsorb")]
+    pub fixed_pf_absorb: Option<FixedPfType>,
+    #[serde(rename = "fixedPFInject")]
+    pub fixed_pf_inject: Option<FixedPfType>,
+    #[serde(rename = "fixedVar")]
+    pub fixed_var: Option<FixedVarType>,
+    #[serde(rename = "freqDroop")]
+    pub freq_droop: Option<FreqDroopType>,
+    pub gradient: Option<GradientType>,
+    /// True if this is a default DER control
+    #[serde(rename = "isDefault")]
+    pub is_default: bool,
+    #[serde(rename = "limitMaxDischarge")]
+    pub limit_max_discharge: Option<LimitMaxDischargeType>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DerCurveType {
+    /// Coordinates of the DER curve. X-axis is determined by _curveType_. Y-axis is determined by
+    /// _yUnit_.
+    #[serde(rename = "curveData")]
+    pub curve_data: Vec<DerCurvePointsType>,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Duration in seconds that this curve will be active. Only absent when _default_ is true.
+    pub duration: Option<f64>,
+    /// Hysteresis parameters for the curve.
+    pub hysteresis: Option<HysteresisType>,
+    /// Priority of curve (0=highest)
+    pub priority: i64,
+    /// Open loop response time, the time to ramp up to 90% of the new target in response to the
+    /// change in voltage, in seconds.
+    #[serde(rename = "responseTime")]
+    pub response_time: Option<f64>,
+    /// Point in time when this curve will become activated. Only absent when _default_ is true.
+    #[serde(rename = "startTime")]
+    pub start_time: Option<String>,
+    #[serde(rename = "yUnit")]
+    pub y_unit: DerUnitEnumType,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DerCurvePointsType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// The data value of the X-axis (independent) variable, depending on the curve type.
+    pub x: f64,
+    /// The data value of the Y-axis (dependent) variable, depending on the _DERUnitEnumType_ of
+    /// the curve.
+    pub y: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HysteresisType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Delay in seconds, once grid parameter within HysteresisLow and HysteresisHigh, for the EV
+    /// to return to normal operation after a grid event.
+    #[serde(rename = "hysteresisDelay")]
+    pub hysteresis_delay: Option<f64>,
+    /// High value for return to normal operation after a grid event, in absolute value.
+    #[serde(rename = "hysteresisHigh")]
+    pub hysteresis_high: Option<f64>,
+    /// Low value for return to normal operation after a grid event, in absolute value.
+    #[serde(rename = "hysteresisLow")]
+    pub hysteresis_low: Option<f64>,
+    /// Set default rate of change (ramp rate %/s) for the EV to return to normal operation after
+    /// a grid event
+    #[serde(rename = "hysteresisGradient")]
+    pub hysteresis_gradient: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EnterServiceType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Enter service delay
+    pub delay: Option<f64>,
+    /// Enter service frequency high
+    #[serde(rename = "highFreq")]
+    pub high_freq: f64,
+    /// Enter service voltage high
+    #[serde(rename = "highVoltage")]
+    pub high_voltage: f64,
+    /// Enter service frequency low
+    #[serde(rename = "lowFreq")]
+    pub low_freq: f64,
+    /// Enter service voltage low
+    #[serde(rename = "lowVoltage")]
+    pub low_voltage: f64,
+    /// Priority of setting (0=highest)
+    pub priority: i64,
+    /// Enter service randomized delay
+    #[serde(rename = "randomDelay")]
+    pub random_delay: Option<f64>,
+    /// Enter service ramp rate in seconds
+    #[serde(rename = "rampRate")]
+    pub ramp_rate: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FixedPfType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Power factor, cos(phi), as value between 0..1.
+    pub displacement: f64,
+    /// Duration in seconds that this setting is active.
+    pub duration: Option<f64>,
+    /// True when absorbing reactive power (under-excited), false when injecting reactive power
+    /// (over-excited).
+    pub excitation: bool,
+    /// Priority of setting (0=highest)
+    pub priority: i64,
+    /// Time when this setting becomes active
+    #[serde(rename = "startTime")]
+    pub start_time: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FixedVarType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Duration in seconds that this setting is active.
+    pub duration: Option<f64>,
+    /// Priority of setting (0=highest)
+    pub priority: i64,
+    /// The value specifies a target var output interpreted as a signed percentage (-100 to 100).
+    /// A negative value refers to charging, whereas a positive one refers to discharging.
+    pub setpoint: f64,
+    /// Time when this setting becomes active.
+    #[serde(rename = "startTime")]
+    pub start_time: Option<String>,
+    pub unit: DerUnitEnumType,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FreqDroopType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Duration in seconds that this setting is active
+    pub duration: Option<f64>,
+    /// Over-frequency slope
+    #[serde(rename = "overDroop")]
+    pub over_droop: f64,
+    /// Over-frequency start of droop
+    #[serde(rename = "overFreq")]
+    pub over_freq: f64,
+    /// Priority of setting (0=highest)
+    pub priority: i64,
+    /// Open loop response time in seconds
+    #[serde(rename = "responseTime")]
+    pub response_time: f64,
+    /// Time when this setting becomes active
+    #[serde(rename = "startTime")]
+    pub start_time: Option<String>,
+    /// Under-frequency slope
+    #[serde(rename = "underDroop")]
+    pub under_droop: f64,
+    /// Under-frequency start of droop
+    #[serde(rename = "underFreq")]
+    pub under_freq: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GradientType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Default ramp rate in seconds (0 if not applicable)
+    pub gradient: f64,
+    /// Id of setting
+    pub priority: i64,
+    /// Soft-start ramp rate in seconds (0 if not applicable)
+    #[serde(rename = "softGradient")]
+    pub soft_gradient: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LimitMaxDischargeType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Duration in seconds that this setting is active
+    pub duration: Option<f64>,
+    /// Only for PowerMonitoring. The value specifies a percentage (0 to 100) of the rated maximum
+    /// discharge power of EV. The PowerMonitoring curve becomes active when power exceeds this
+    /// percentage.
+    #[serde(rename = "pctMaxDischargePower")]
+    pub pct_max_discharge_power: Option<f64>,
+    #[serde(rename = "powerMonitoringMustTrip")]
+    pub power_monitoring_must_trip: Option<DerCurveType>,
+    /// Priority of setting (0=highest)
+    pub priority: i64,
+    /// Time when this setting becomes active
+    #[serde(rename = "startTime")]
+    pub start_time: Option<String>,
+}
+
+/// Unit of the Y-axis of DER curve
+#[derive(Serialize, Deserialize, Debug)]
+pub enum DerUnitEnumType {
+    #[serde(rename = "Not_Applicable")]
+    NotApplicable,
+    PctEffectiveV,
+    PctMaxVar,
+    PctMaxW,
+    PctVarAvail,
+    PctWAvail,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateDynamicScheduleRequest {
+    /// Id of charging profile to update.
+    #[serde(rename = "chargingProfileId")]
+    pub charging_profile_id: i64,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    #[serde(rename = "scheduleUpdate")]
+    pub schedule_update: ChargingScheduleUpdateType,
+}
+
+/// Updates to a ChargingSchedulePeriodType for dynamic charging profiles.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChargingScheduleUpdateType {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// Optional only when not required by the _operationMode_, as in CentralSetpoint,
+    /// ExternalSetpoint, ExternalLimits, LocalFrequency, LocalLoadBalancing. Charging rate
+    /// limit during the schedule period, in the applicable _chargingRateUnit_.
+    pub limit: Option<f64>,
+    /// Limit in _chargingRateUnit_ that the EV is allowed to discharge with. Note, these are
+    /// negative values in order to be consistent with _setpoint_, which can be positive and
+    /// negative.
+    #[serde(rename = "dischargeLimit")]
+    pub discharge_limit: Option<f64>,
+    /// Setpoint in _chargingRateUnit_ that the EV should follow as close as possible. Use
+    /// negative values for discharging.
+    pub setpoint: Option<f64>,
+    /// Setpoint for reactive power (or current) in _chargingRateUnit_ that the EV should follow
+    /// as closely as possible. Positive values for inductive, negative for capacitive reactive
+    /// power or current.
+    #[serde(rename = "setpointReactive")]
+    pub setpoint_reactive: Option<f64>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UsePriorityChargingRequest {
+    /// True to request priority charging. False to request stopping priority charging.
+    pub activate: bool,
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// The transaction for which priority charging is requested.
+    #[serde(rename = "transactionId")]
+    pub transaction_id: String,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VatNumberValidationRequest {
+    #[serde(rename = "customData")]
+    pub custom_data: Option<Value>,
+    /// EVSE id for which check is done
+    #[serde(rename = "evseId")]
+    pub evse_id: Option<i64>,
+    /// VAT number to check.
+    #[serde(rename = "vatNumber")]
+    pub vat_number: String,
+}
@@ -0,0 +1,159 @@
+use serde::{Serialize, Deserialize};
+
+// Responses to the 2.0.1 messages are unchanged in OCPP 2.1 apart from new optional fields.
+pub use crate::messages::responses::*;
+use super::requests::ChargingScheduleUpdateType;
+
+// structures created with the help of https://app.quicktype.io/ and OCPP 2.1 json schema provided by
+// https://www.openchargealliance.org/
+
+#[derive(Serialize, Deserialize)]
+pub struct BatterySwapResponse {
+    #[serde(rename = "customData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct ClosePeriodicEventStreamResponse {
+    #[serde(rename = "customData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct NotifyDerAlarmResponse {
+    #[serde(rename = "customData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct NotifyDerStartStopResponse {
+    #[serde(rename = "customData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct NotifyPriorityChargingResponse {
+    #[serde(rename = "customData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct NotifyQrCodeScannedResponse {
+    #[serde(rename = "customData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct NotifySettlementResponse {
+    #[serde(rename = "customData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+    /// The receipt id if the receipt is generated by CSMS.
+    #[serde(rename = "receiptId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_id: Option<String>,
+    /// The receipt URL if receipt generated by CSMS. The Charging Station can QR encode it and
+    /// show it to the EV Driver.
+    #[serde(rename = "receiptUrl")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_url: Option<String>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct OpenPeriodicEventStreamResponse {
+    #[serde(rename = "customData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+    pub status: GenericStatusEnumType,
+    #[serde(rename = "statusInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct PullDynamicScheduleUpdateResponse {
+    #[serde(rename = "customData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+    #[serde(rename = "scheduleUpdate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_update: Option<ChargingScheduleUpdateType>,
+    pub status: ChargingProfileStatusEnumType,
+    #[serde(rename = "statusInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct ReportDerControlResponse {
+    #[serde(rename = "customData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+
+//=================================================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct VatNumberValidationResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<AddressType>,
+    #[serde(rename = "customData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+    /// EVSE id for which check was requested.
+    #[serde(rename = "evseId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evse_id: Option<i64>,
+    /// Result of operation.
+    pub status: GenericStatusEnumType,
+    #[serde(rename = "statusInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// VAT number that was requested.
+    #[serde(rename = "vatNumber")]
+    pub vat_number: String,
+}
+
+/// A generic address format.
+#[derive(Serialize, Deserialize)]
+pub struct AddressType {
+    #[serde(rename = "address1")]
+    pub address1: String,
+    #[serde(rename = "address2")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address2: Option<String>,
+    pub city: String,
+    pub country: String,
+    #[serde(rename = "customData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+    /// Name of person/company
+    pub name: String,
+    #[serde(rename = "postalCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+}
@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use crate::messages::actions::Action;
+use crate::messages::{v16, v21, ErrorCode};
+use crate::traffic::{TrafficDirection, TrafficFrame};
+
+// Prometheus metrics of GET /metrics, in the text exposition format. The counters are fed with
+// the frames of the traffic tap, which the session actors report to the ocpp server, and with
+// the meter readings of the stations; the gauges are read from the state of the ocpp server when
+// the metrics are scraped. Actions and error codes come from the stations, so only the ones of
+// the OCPP versions are labels, anything else is "unknown" and cannot grow the series.
+
+/// Upper bounds in seconds of the buckets of the call latency histograms
+const LATENCY_BUCKETS: [f64; 11] = [0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+/// Values read from the ocpp server at scrape time
+pub struct Gauges {
+    pub connected_stations: usize,
+    pub web_clients: usize,
+    pub outstanding_calls: usize,
+    pub active_transactions: usize,
+}
+
+#[derive(Default)]
+struct Histogram {
+    /// observations per bucket of LATENCY_BUCKETS, not cumulative
+    buckets: [u64; LATENCY_BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, seconds: f64) {
+        if let Some(bucket) = LATENCY_BUCKETS.iter().position(|bound| seconds <= *bound) {
+            self.buckets[bucket] += 1;
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+}
+
+fn direction_label(direction: TrafficDirection) -> &'static str {
+    match direction {
+        TrafficDirection::Inbound => "inbound",
+        TrafficDirection::Outbound => "outbound",
+    }
+}
+
+fn message_type_label(message_type_id: Option<u64>) -> &'static str {
+    match message_type_id {
+        Some(2) => "Call",
+        Some(3) => "CallResult",
+        Some(4) => "CallError",
+        Some(6) => "Send",
+        _ => "Invalid",
+    }
+}
+
+/// Label of an action of OCPP 1.6, 2.0.1 or 2.1
+fn action_label(action: Option<&str>) -> &'static str {
+    match action {
+        Some(action) => Action::from_name(action).map(|action| action.name())
+            .or_else(|| v16::ACTIONS.iter().chain(v21::ACTIONS.iter()).find(|known| **known == action).copied())
+            .unwrap_or("unknown"),
+        None => "unknown",
+    }
+}
+
+/// Counters and histograms since the server started
+#[derive(Default)]
+pub struct Metrics {
+    messages: BTreeMap<(&'static str, &'static str, &'static str), u64>, // key: (action, direction, message type)
+    call_errors: BTreeMap<(&'static str, &'static str), u64>, // key: (ErrorCode, direction)
+    call_latency: BTreeMap<&'static str, Histogram>, // key: action
+    energy_delivered_wh: f64,
+}
+
+impl Metrics {
+    pub fn new() -> Metrics {
+        Metrics::default()
+    }
+
+    /// Counts a frame to or from a station. The latency of the calls the server sends is the time
+    /// until the station's CallResult or CallError.
+    pub fn frame(&mut self, frame: &TrafficFrame) {
+        let action = action_label(frame.action.as_deref());
+        let direction = direction_label(frame.direction);
+        *self.messages.entry((action, direction, message_type_label(frame.message_type_id))).or_insert(0) += 1;
+        if frame.message_type_id == Some(4) {
+            let error_code = serde_json::from_str::<Vec<Value>>(&frame.frame).ok()
+                .and_then(|fields| fields.get(2).and_then(|code| code.as_str()).and_then(ErrorCode::from_name))
+                .map_or("unknown", |error_code| error_code.name());
+            *self.call_errors.entry((error_code, direction)).or_insert(0) += 1;
+        }
+        if let (TrafficDirection::Inbound, Some(latency_ms)) = (frame.direction, frame.latency_ms) {
+            self.call_latency.entry(action).or_default().observe(latency_ms as f64 / 1000.0);
+        }
+    }
+
+    /// Adds energy a station delivered, in Wh
+    pub fn energy_delivered(&mut self, wh: f64) {
+        self.energy_delivered_wh += wh;
+    }
+
+    /// The metrics in the Prometheus text format
+    pub fn render(&self, gauges: &Gauges) -> String {
+        let mut text = String::new();
+        let mut gauge = |name: &str, help: &str, value: usize| {
+            let _ = writeln!(text, "# HELP {} {}\n# TYPE {} gauge\n{} {}", name, help, name, name, value);
+        };
+        gauge("ocpp_connected_stations", "Charging stations with an open websocket", gauges.connected_stations);
+        gauge("ocpp_web_clients", "Web clients with an open websocket", gauges.web_clients);
+        gauge("ocpp_outstanding_calls", "Calls sent to stations that wait for their CallResult or CallError",
+              gauges.outstanding_calls);
+        gauge("ocpp_active_transactions", "Transactions started and not stopped", gauges.active_transactions);
+
+        text.push_str("# HELP ocpp_messages_total OCPP-J frames by action, direction and message type\n\
+                       # TYPE ocpp_messages_total counter\n");
+        for ((action, direction, message_type), count) in &self.messages {
+            let _ = writeln!(text, "ocpp_messages_total{{action=\"{}\",direction=\"{}\",type=\"{}\"}} {}",
+                             action, direction, message_type, count);
+        }
+        text.push_str("# HELP ocpp_call_errors_total CallErrors by ErrorCode and direction\n\
+                       # TYPE ocpp_call_errors_total counter\n");
+        for ((error_code, direction), count) in &self.call_errors {
+            let _ = writeln!(text, "ocpp_call_errors_total{{error_code=\"{}\",direction=\"{}\"}} {}",
+                             error_code, direction, count);
+        }
+        text.push_str("# HELP ocpp_call_duration_seconds Time until a station answers a call of the server\n\
+                       # TYPE ocpp_call_duration_seconds histogram\n");
+        for (action, histogram) in &self.call_latency {
+            let mut cumulative = 0;
+            for (bound, count) in LATENCY_BUCKETS.iter().zip(histogram.buckets.iter()) {
+                cumulative += count;
+                let _ = writeln!(text, "ocpp_call_duration_seconds_bucket{{action=\"{}\",le=\"{}\"}} {}",
+                                 action, bound, cumulative);
+            }
+            let _ = writeln!(text, "ocpp_call_duration_seconds_bucket{{action=\"{}\",le=\"+Inf\"}} {}\n\
+                                    ocpp_call_duration_seconds_sum{{action=\"{}\"}} {}\n\
+                                    ocpp_call_duration_seconds_count{{action=\"{}\"}} {}",
+                             action, histogram.count, action, histogram.sum, action, histogram.count);
+        }
+        let _ = writeln!(text, "# HELP ocpp_energy_delivered_wh_total Energy delivered in transactions, from \
+                                the meter readings of the stations\n\
+                                # TYPE ocpp_energy_delivered_wh_total counter\n\
+                                ocpp_energy_delivered_wh_total {}", self.energy_delivered_wh);
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traffic::TrafficTap;
+
+    use super::*;
+
+    fn gauges() -> Gauges {
+        Gauges { connected_stations: 2, web_clients: 1, outstanding_calls: 0, active_transactions: 1 }
+    }
+
+    #[test]
+    fn frames_are_counted_by_action_and_direction() {
+        let mut tap = TrafficTap::new();
+        let mut metrics = Metrics::new();
+        for (direction, frame) in [
+            (TrafficDirection::Outbound, r#"[2,"1","Reset",{"type":"Immediate"}]"#),
+            (TrafficDirection::Inbound, r#"[3,"1",{"status":"Accepted"}]"#),
+            (TrafficDirection::Outbound, r#"[2,"2","Reset",{"type":"Immediate"}]"#),
+            (TrafficDirection::Inbound, r#"[4,"2","NotSupported","",{}]"#),
+            (TrafficDirection::Inbound, r#"[2,"3","Heartbeat",{}]"#),
+            (TrafficDirection::Inbound, r#"[2,"4","Heartbeat2",{}]"#),
+            (TrafficDirection::Outbound, r#"[4,"4","NoSuchCode","",{}]"#),
+            (TrafficDirection::Inbound, r#"[2,"5","StartTransaction",{}]"#),
+        ] {
+            metrics.frame(&tap.record("CS001", direction, frame.to_string()));
+        }
+        let text = metrics.render(&gauges());
+        assert!(text.contains("ocpp_connected_stations 2\n"));
+        assert!(text.contains("ocpp_messages_total{action=\"Reset\",direction=\"outbound\",type=\"Call\"} 2\n"));
+        assert!(text.contains("ocpp_messages_total{action=\"Reset\",direction=\"inbound\",type=\"CallResult\"} 1\n"));
+        assert!(text.contains("ocpp_messages_total{action=\"Heartbeat\",direction=\"inbound\",type=\"Call\"} 1\n"));
+        assert!(text.contains("ocpp_call_errors_total{error_code=\"NotSupported\",direction=\"inbound\"} 1\n"));
+        assert!(text.contains("ocpp_messages_total{action=\"unknown\",direction=\"inbound\",type=\"Call\"} 1\n"));
+        assert!(text.contains("ocpp_call_errors_total{error_code=\"unknown\",direction=\"outbound\"} 1\n"));
+        assert!(text.contains("ocpp_messages_total{action=\"StartTransaction\",direction=\"inbound\",type=\"Call\"} 1\n"));
+        assert!(text.contains("ocpp_call_duration_seconds_bucket{action=\"Reset\",le=\"+Inf\"} 2\n"));
+        assert!(text.contains("ocpp_call_duration_seconds_count{action=\"Reset\"} 2\n"));
+    }
+
+    #[test]
+    fn histogram_buckets_are_cumulative() {
+        let mut histogram = Histogram::default();
+        histogram.observe(0.02);
+        histogram.observe(0.3);
+        histogram.observe(60.0);
+        let mut metrics = Metrics::new();
+        metrics.call_latency.insert("Reset", histogram);
+        metrics.energy_delivered(1500.0);
+        let text = metrics.render(&gauges());
+        assert!(text.contains("ocpp_call_duration_seconds_bucket{action=\"Reset\",le=\"0.01\"} 0\n"));
+        assert!(text.contains("ocpp_call_duration_seconds_bucket{action=\"Reset\",le=\"0.025\"} 1\n"));
+        assert!(text.contains("ocpp_call_duration_seconds_bucket{action=\"Reset\",le=\"0.5\"} 2\n"));
+        assert!(text.contains("ocpp_call_duration_seconds_bucket{action=\"Reset\",le=\"30\"} 2\n"));
+        assert!(text.contains("ocpp_call_duration_seconds_bucket{action=\"Reset\",le=\"+Inf\"} 3\n"));
+        assert!(text.contains("ocpp_energy_delivered_wh_total 1500\n"));
+    }
+}
@@ -0,0 +1,482 @@
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::JsonSchema;
+use serde_json::{json, Map, Value};
+
+use crate::api;
+use crate::events;
+use crate::journal;
+use crate::messages::{actions, requests, responses};
+use crate::server;
+use crate::services;
+use crate::traffic;
+
+// The OpenAPI document served at /api/openapi.json. Schemas are generated from the serde types of
+// the handlers and of `messages::requests` / `messages::responses`, the routes are listed below.
+// A test checks that every handler declared with a route attribute is part of the list.
+
+/// An endpoint of the HTTP API as it appears in the OpenAPI document
+struct Route {
+    method: &'static str,
+    path: &'static str,
+    summary: &'static str,
+    query: Vec<(&'static str, &'static str, Value)>, // (name, description, schema)
+    body: Option<Value>,
+    responses: Vec<(&'static str, &'static str, Option<Value>)>, // (status code, description, schema)
+    /// media type of the responses, "text/event-stream" for Server-Sent Events
+    media_type: &'static str,
+}
+
+fn schema<T: JsonSchema>(generator: &mut SchemaGenerator) -> Option<Value> {
+    Some(serde_json::to_value(generator.subschema_for::<T>()).unwrap())
+}
+
+fn error_schema() -> Option<Value> {
+    Some(json!({
+        "type": "object",
+        "properties": { "error": { "type": "string" } },
+        "required": ["error"]
+    }))
+}
+
+/// Responses shared by the endpoints that send a call to a station and wait for its reply
+fn call_responses(generator: &mut SchemaGenerator, result: Option<Value>)
+                  -> Vec<(&'static str, &'static str, Option<Value>)> {
+    vec![
+        ("200", "The station accepted the request", result.clone()),
+        ("400", "The body is not a valid request for the OCPP version of the station", error_schema()),
+        ("409", "The station rejected the request", result),
+        ("502", "The station answered with a CallError or an invalid CallResult",
+         schema::<api::CallErrorBody>(generator)),
+        ("503", "The station is not connected", error_schema()),
+        ("504", "The station did not answer in time", error_schema()),
+    ]
+}
+
+fn routes(generator: &mut SchemaGenerator) -> Vec<Route> {
+    let websocket = vec![("101", "Switching to the websocket protocol", None)];
+    let reset_result = schema::<responses::ResetResponse>(generator);
+    let mut reset_responses = call_responses(generator, reset_result.clone());
+    reset_responses.insert(1, ("202", "The station scheduled the reset", reset_result));
+    let unlock_result = schema::<responses::UnlockConnectorResponse>(generator);
+    let unlock_responses = call_responses(generator, unlock_result);
+    let remote_start_result = schema::<responses::RequestStartTransactionResponse>(generator);
+    let remote_start_responses = call_responses(generator, remote_start_result);
+    let remote_stop_result = schema::<responses::RequestStopTransactionResponse>(generator);
+    let remote_stop_responses = call_responses(generator, remote_stop_result);
+    let availability_result = schema::<responses::ChangeAvailabilityResponse>(generator);
+    let mut availability_responses = call_responses(generator, availability_result.clone());
+    availability_responses.insert(1, ("202", "The station scheduled the change, e.g. after a running transaction",
+                                      availability_result));
+    let trigger_result = schema::<responses::TriggerMessageResponse>(generator);
+    let trigger_responses = call_responses(generator, trigger_result);
+    let reserve_result = schema::<responses::ReserveNowResponse>(generator);
+    let reserve_responses = call_responses(generator, reserve_result);
+    let cancel_result = schema::<responses::CancelReservationResponse>(generator);
+    let cancel_responses = call_responses(generator, cancel_result);
+    let get_variables_result = schema::<responses::GetVariablesResponse>(generator);
+    let mut get_variables_responses = call_responses(generator, get_variables_result);
+    // every variable has its own attributeStatus, the request as a whole is never rejected
+    get_variables_responses.retain(|(code, _, _)| *code != "409");
+    get_variables_responses[0].1 = "The station answered, with a status per variable";
+    let variables_result = schema::<responses::SetVariablesResponse>(generator);
+    let variables_responses = call_responses(generator, variables_result);
+    let journal_query = vec![
+        ("station", "Only entries of this station", json!({ "type": "string" })),
+        ("action", "Only entries of this action, CallResults and CallErrors included", json!({ "type": "string" })),
+        ("direction", "Inbound (sent by the station) or Outbound", json!({ "type": "string", "enum": ["Inbound", "Outbound"] })),
+        ("messageTypeId", "2 Call, 3 CallResult, 4 CallError or 6 Send", json!({ "type": "integer" })),
+        ("messageId", "Only entries of this message id", json!({ "type": "string" })),
+        ("from", "Only entries at or after this RFC 3339 timestamp", json!({ "type": "string", "format": "date-time" })),
+        ("to", "Only entries before this RFC 3339 timestamp", json!({ "type": "string", "format": "date-time" })),
+        ("limit", "At most this many entries, the newest ones, 1000 by default", json!({ "type": "integer" })),
+    ];
+    vec![
+        Route {
+            method: "get",
+            path: "/ocpp/{serial_id}",
+            summary: "OCPP-J websocket of a charging station (ocpp2.1, ocpp2.0.1 or ocpp1.6 sub-protocol)",
+            query: Vec::new(),
+            body: None,
+            responses: vec![
+                websocket[0].clone(),
+                ("401", "Security profile 1 or 2 and the basic authentication of the station failed", None),
+            ],
+            media_type: "application/json",
+        },
+        Route {
+            method: "get",
+            path: "/api/webclient-socket/{serial_id}",
+            summary: "Websocket of the web client, every frame sent by the server is a WebEvent (see components)",
+            query: Vec::new(),
+            body: None,
+            responses: websocket,
+            media_type: "application/json",
+        },
+        Route {
+            method: "get",
+            path: "/api/get-chargers",
+            summary: "Serial ids of the connected charging stations",
+            query: Vec::new(),
+            body: None,
+            responses: vec![
+                ("200", "Connected charging stations", schema::<Vec<String>>(generator)),
+                ("500", "The OCPP server did not answer", error_schema()),
+            ],
+            media_type: "application/json",
+        },
+        Route {
+            method: "post",
+            path: "/api/post-request",
+            summary: "Queues a call of the web client, the reply is sent over the web client websocket",
+            query: Vec::new(),
+            body: schema::<server::MessageFromWebBrowser>(generator),
+            responses: vec![("200", "The call was queued", Some(json!({
+                "type": "object",
+                "properties": { "status": { "type": "string" } }
+            })))],
+            media_type: "application/json",
+        },
+        Route {
+            method: "post",
+            path: "/api/stations/{id}/reset",
+            summary: "Sends a Reset to the station",
+            query: Vec::new(),
+            body: schema::<requests::ResetRequest>(generator),
+            responses: reset_responses,
+            media_type: "application/json",
+        },
+        Route {
+            method: "post",
+            path: "/api/stations/{id}/unlock",
+            summary: "Sends an UnlockConnector to the station",
+            query: Vec::new(),
+            body: schema::<requests::UnlockConnectorRequest>(generator),
+            responses: unlock_responses,
+            media_type: "application/json",
+        },
+        Route {
+            method: "post",
+            path: "/api/stations/{id}/remote-start",
+            summary: "Sends a RequestStartTransaction to the station, the remoteStartId is generated by the server",
+            query: Vec::new(),
+            body: schema::<api::RemoteStartBody>(generator),
+            responses: remote_start_responses,
+            media_type: "application/json",
+        },
+        Route {
+            method: "post",
+            path: "/api/stations/{id}/remote-stop",
+            summary: "Sends a RequestStopTransaction to the station, a RemoteStopTransaction to an OCPP 1.6 charge point",
+            query: Vec::new(),
+            body: schema::<requests::RequestStopTransactionRequest>(generator),
+            responses: remote_stop_responses,
+            media_type: "application/json",
+        },
+        Route {
+            method: "post",
+            path: "/api/stations/{id}/change-availability",
+            summary: "Sends a ChangeAvailability to the station",
+            query: Vec::new(),
+            body: schema::<requests::ChangeAvailabilityRequest>(generator),
+            responses: availability_responses,
+            media_type: "application/json",
+        },
+        Route {
+            method: "post",
+            path: "/api/stations/{id}/trigger-message",
+            summary: "Sends a TriggerMessage to the station",
+            query: Vec::new(),
+            body: schema::<requests::TriggerMessageRequest>(generator),
+            responses: trigger_responses,
+            media_type: "application/json",
+        },
+        Route {
+            method: "post",
+            path: "/api/stations/{id}/reserve",
+            summary: "Sends a ReserveNow to the station",
+            query: Vec::new(),
+            body: schema::<requests::ReserveNowRequest>(generator),
+            responses: reserve_responses,
+            media_type: "application/json",
+        },
+        Route {
+            method: "post",
+            path: "/api/stations/{id}/cancel-reservation",
+            summary: "Sends a CancelReservation to the station",
+            query: Vec::new(),
+            body: schema::<requests::CancelReservationRequest>(generator),
+            responses: cancel_responses,
+            media_type: "application/json",
+        },
+        Route {
+            method: "post",
+            path: "/api/stations/{id}/get-variables",
+            summary: "Sends a GetVariables to the station, a GetConfiguration to an OCPP 1.6 charge point",
+            query: Vec::new(),
+            body: schema::<requests::GetVariablesRequest>(generator),
+            responses: get_variables_responses,
+            media_type: "application/json",
+        },
+        Route {
+            method: "post",
+            path: "/api/stations/{id}/variables",
+            summary: "Sends a SetVariables to the station",
+            query: Vec::new(),
+            body: schema::<requests::SetVariablesRequest>(generator),
+            responses: variables_responses,
+            media_type: "application/json",
+        },
+        Route {
+            method: "get",
+            path: "/api/stations/{id}/heartbeat",
+            summary: "Websocket ping interval, pong timeout and Heartbeat interval of the station",
+            query: Vec::new(),
+            body: None,
+            responses: vec![("200", "The heartbeat policy", schema::<services::HeartbeatPolicy>(generator))],
+            media_type: "application/json",
+        },
+        Route {
+            method: "put",
+            path: "/api/stations/{id}/heartbeat",
+            summary: "Changes the heartbeat policy of the station, new intervals are pushed with SetVariables",
+            query: Vec::new(),
+            body: schema::<services::HeartbeatPolicyChange>(generator),
+            responses: vec![
+                ("200", "The station accepted the new intervals", schema::<api::HeartbeatPolicyBody>(generator)),
+                ("202", "The station is offline, it gets the Heartbeat interval when it boots",
+                 schema::<api::HeartbeatPolicyBody>(generator)),
+                ("400", "The policy is not consistent", error_schema()),
+                ("409", "The station rejected a new interval, the policy is unchanged and the intervals it \
+                         accepted are set back", schema::<api::HeartbeatPolicyBody>(generator)),
+                ("502", "The station answered with a CallError or an invalid CallResult",
+                 schema::<api::CallErrorBody>(generator)),
+                ("504", "The station did not answer in time", error_schema()),
+            ],
+            media_type: "application/json",
+        },
+        Route {
+            method: "get",
+            path: "/api/stations",
+            summary: "Charging stations seen since the server started",
+            query: vec![
+                ("status", "Only stations with a connector in this status", json!({ "type": "string" })),
+                ("online", "Only stations that are (or are not) connected", json!({ "type": "boolean" })),
+            ],
+            body: None,
+            responses: vec![("200", "Charging stations ordered by id", schema::<Vec<services::Station>>(generator))],
+            media_type: "application/json",
+        },
+        Route {
+            method: "get",
+            path: "/api/stations/{id}",
+            summary: "A charging station with its EVSEs, connectors and active transactions, and the \
+                      variables, reservations, charging profiles and network profiles the station accepted",
+            query: Vec::new(),
+            body: None,
+            responses: vec![
+                ("200", "The charging station", schema::<services::StationDetail>(generator)),
+                ("404", "The station has not connected since the server started", error_schema()),
+            ],
+            media_type: "application/json",
+        },
+        Route {
+            method: "get",
+            path: "/api/stations/{id}/availability",
+            summary: "Connections, disconnections and missed Heartbeats of the station with its uptime per window",
+            query: Vec::new(),
+            body: None,
+            responses: vec![
+                ("200", "The connectivity history, oldest first", schema::<services::Availability>(generator)),
+                ("404", "The station has not connected since the server started", error_schema()),
+            ],
+            media_type: "application/json",
+        },
+        Route {
+            method: "get",
+            path: "/api/offline-stations",
+            summary: "Stations offline for longer than a threshold",
+            query: vec![
+                ("threshold", "Seconds, the configured offline threshold by default", json!({ "type": "integer" })),
+            ],
+            body: None,
+            responses: vec![("200", "Offline stations, longest offline first",
+                             schema::<Vec<services::OfflineStation>>(generator))],
+            media_type: "application/json",
+        },
+        Route {
+            method: "get",
+            path: "/api/openapi.json",
+            summary: "This document",
+            query: Vec::new(),
+            body: None,
+            responses: vec![("200", "OpenAPI 3 document", Some(json!({ "type": "object" })))],
+            media_type: "application/json",
+        },
+        Route {
+            method: "get",
+            path: "/api/stations/{id}/traffic",
+            summary: "Server-Sent Events stream of the OCPP frames to and from the station, \
+                      the data of every `frame` event is a TrafficFrame",
+            query: Vec::new(),
+            body: None,
+            responses: vec![("200", "Stream of frames", schema::<traffic::TrafficFrame>(generator))],
+            media_type: "text/event-stream",
+        },
+        Route {
+            method: "get",
+            path: "/api/journal",
+            summary: "Entries of the OCPP traffic journal, oldest first",
+            query: journal_query.clone(),
+            body: None,
+            responses: vec![
+                ("200", "Journal entries", schema::<Vec<journal::JournalEntry>>(generator)),
+                ("400", "from or to is not an RFC 3339 timestamp", error_schema()),
+            ],
+            media_type: "application/json",
+        },
+        Route {
+            method: "get",
+            path: "/api/journal/export",
+            summary: "Entries of the OCPP traffic journal as JSON Lines, one JournalEntry per line",
+            query: journal_query.into_iter().filter(|(name, _, _)| *name != "limit").chain(vec![
+                ("limit", "At most this many entries, the newest ones, no limit by default", json!({ "type": "integer" })),
+            ]).collect(),
+            body: None,
+            responses: vec![
+                ("200", "Journal entries", schema::<journal::JournalEntry>(generator)),
+                ("400", "from or to is not an RFC 3339 timestamp", error_schema()),
+            ],
+            media_type: "application/x-ndjson",
+        },
+        Route {
+            method: "get",
+            path: "/metrics",
+            summary: "Prometheus metrics of the stations, the OCPP messages and calls, the transactions and \
+                      the energy delivered, in the text exposition format",
+            query: Vec::new(),
+            body: None,
+            responses: vec![("200", "Metrics", Some(json!({ "type": "string" })))],
+            media_type: "text/plain",
+        },
+    ]
+}
+
+fn parameters(route: &Route) -> Vec<Value> {
+    let path = route.path.split('/')
+        .filter(|segment| segment.starts_with('{') && segment.ends_with('}'))
+        .map(|segment| json!({
+            "name": segment.trim_start_matches('{').trim_end_matches('}'),
+            "in": "path",
+            "required": true,
+            "schema": { "type": "string" }
+        }));
+    let query = route.query.iter()
+        .map(|(name, description, schema)| json!({
+            "name": name,
+            "in": "query",
+            "description": description,
+            "required": false,
+            "schema": schema
+        }));
+    path.chain(query).collect()
+}
+
+fn operation(route: Route) -> Value {
+    let parameters = parameters(&route);
+    let mut responses = Map::new();
+    for (status, description, schema) in route.responses {
+        let mut response = json!({ "description": description });
+        if let Some(schema) = schema {
+            response["content"] = json!({ route.media_type: { "schema": schema } });
+        }
+        responses.insert(status.to_string(), response);
+    }
+    let mut operation = json!({
+        "summary": route.summary,
+        "parameters": parameters,
+        "responses": responses,
+    });
+    if let Some(body) = route.body {
+        operation["requestBody"] = json!({
+            "required": true,
+            "content": { "application/json": { "schema": body } }
+        });
+    }
+    operation
+}
+
+/// Builds the OpenAPI 3 document of the HTTP API
+pub fn document() -> Value {
+    let mut generator = SchemaSettings::openapi3().into_generator();
+    let mut paths = Map::new();
+    for route in routes(&mut generator) {
+        let method = route.method;
+        let path = paths.entry(route.path).or_insert_with(|| json!({}));
+        path[method] = operation(route);
+    }
+    // every OCPP message is listed, not only the ones used by the endpoints
+    actions::message_schemas(&mut generator);
+    // events of the web client websocket
+    generator.subschema_for::<events::WebEvent>();
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "rusted_ocpp_server",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "paths": paths,
+        "components": { "schemas": generator.take_definitions() },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::document;
+
+    /// (method, path) of every handler declared with an actix route attribute
+    fn declared_routes(dir: &Path, routes: &mut Vec<(String, String)>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                declared_routes(&path, routes);
+                continue;
+            }
+            if path.extension().is_none_or(|extension| extension != "rs") {
+                continue;
+            }
+            for line in fs::read_to_string(&path).unwrap().lines() {
+                for method in &["get", "post", "put", "patch", "delete"] {
+                    let attribute = format!("#[{}(\"", method);
+                    if let Some(rest) = line.trim().strip_prefix(attribute.as_str()) {
+                        let route = rest.split('"').next().unwrap();
+                        routes.push((method.to_string(), route.to_string()));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_route_is_in_the_document() {
+        let document = document();
+        let mut routes = Vec::new();
+        declared_routes(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), &mut routes);
+        assert!(!routes.is_empty());
+        for (method, path) in routes {
+            assert!(document["paths"][&path][&method].is_object(),
+                    "{} {} is missing from the OpenAPI document", method.to_uppercase(), path);
+        }
+    }
+
+    #[test]
+    fn message_types_are_in_the_document() {
+        let schemas = &document()["components"]["schemas"];
+        assert!(schemas["BootNotificationRequest"].is_object());
+        assert!(schemas["GetVariablesResponse"].is_object());
+        assert_eq!(schemas["ResetEnumType"]["enum"], serde_json::json!(["Immediate", "OnIdle"]));
+        assert!(schemas["WebEvent"].is_object());
+    }
+}
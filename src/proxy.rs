@@ -0,0 +1,165 @@
+use std::net::IpAddr;
+
+use actix_web::HttpRequest;
+
+use crate::services::StationPeer;
+
+// Identity of a station behind a reverse proxy (nginx, an ingress). The address of the websocket
+// connection is then the one of the proxy, and the proxy terminates TLS, so the client
+// certificate of the station can only reach the server in a header. Both are taken from the
+// request only when it comes from a trusted proxy, any client could send the headers otherwise:
+//   X-Forwarded-For  read from right to left, the station is the first address that is not a
+//                    trusted proxy
+//   client_cert_header, e.g. X-SSL-Client-S-DN set by nginx to $ssl_client_s_dn, the subject of
+//                    the certificate the proxy verified
+
+/// Proxies whose X-Forwarded-For and client certificate headers are honoured
+#[derive(Clone, Default)]
+pub struct TrustedProxies {
+    /// (network address, prefix length)
+    networks: Vec<(IpAddr, u8)>,
+    client_cert_header: Option<String>,
+}
+
+/// The address without its IPv4-mapped IPv6 form, which dual-stack sockets report for IPv4 peers
+fn canonical(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(ip, IpAddr::V4),
+        IpAddr::V4(_) => ip,
+    }
+}
+
+fn parse_network(network: &str) -> Result<(IpAddr, u8), String> {
+    let invalid = || format!("{} is not an IP address or a network such as 10.0.0.0/8", network);
+    let (address, prefix) = match network.split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix)),
+        None => (network, None),
+    };
+    let address = canonical(address.parse::<IpAddr>().map_err(|_| invalid())?);
+    let max_prefix = if address.is_ipv4() { 32 } else { 128 };
+    let prefix = match prefix {
+        Some(prefix) => prefix.parse::<u8>().map_err(|_| invalid())?,
+        None => max_prefix,
+    };
+    if prefix > max_prefix {
+        return Err(invalid());
+    }
+    Ok((address, prefix))
+}
+
+/// Whether the address is in the network
+fn contains(network: &(IpAddr, u8), ip: IpAddr) -> bool {
+    match (network, ip) {
+        ((IpAddr::V4(network), prefix), IpAddr::V4(ip)) => {
+            let mask = u32::MAX.checked_shl(32 - *prefix as u32).unwrap_or(0);
+            u32::from(*network) & mask == u32::from(ip) & mask
+        }
+        ((IpAddr::V6(network), prefix), IpAddr::V6(ip)) => {
+            let mask = u128::MAX.checked_shl(128 - *prefix as u32).unwrap_or(0);
+            u128::from(*network) & mask == u128::from(ip) & mask
+        }
+        _ => false,
+    }
+}
+
+impl TrustedProxies {
+    /// Proxies from a comma separated list of addresses and networks, e.g. "127.0.0.1,10.0.0.0/8"
+    pub fn new(trusted: &str, client_cert_header: Option<String>) -> Result<TrustedProxies, String> {
+        let networks = trusted.split(',')
+            .map(|network| network.trim())
+            .filter(|network| !network.is_empty())
+            .map(parse_network)
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(TrustedProxies { networks, client_cert_header })
+    }
+
+    fn is_trusted(&self, ip: IpAddr) -> bool {
+        self.networks.iter().any(|network| contains(network, ip))
+    }
+
+    /// Address of the client that reached `peer`, a proxy when it is trusted, through the hops of
+    /// X-Forwarded-For
+    fn client_ip(&self, peer: IpAddr, forwarded_for: &[&str]) -> IpAddr {
+        let mut client = canonical(peer);
+        if !self.is_trusted(client) {
+            return client;
+        }
+        for hop in forwarded_for.iter().rev().flat_map(|header| header.rsplit(',')) {
+            match hop.trim().parse::<IpAddr>() {
+                Ok(ip) => {
+                    client = canonical(ip);
+                    if !self.is_trusted(client) {
+                        break;
+                    }
+                }
+                // e.g. "unknown", the hops before it cannot be trusted
+                Err(_) => break,
+            }
+        }
+        client
+    }
+
+    /// Address and client certificate subject of the station that sent the request
+    pub fn peer(&self, r: &HttpRequest) -> StationPeer {
+        let peer = match r.peer_addr() {
+            Some(peer) => peer.ip(),
+            None => return StationPeer::default(),
+        };
+        let forwarded_for: Vec<&str> = r.headers().get_all("x-forwarded-for")
+            .filter_map(|header| header.to_str().ok())
+            .collect();
+        let certificate_subject = match &self.client_cert_header {
+            Some(header) if self.is_trusted(canonical(peer)) => r.headers().get(header.as_str())
+                .and_then(|subject| subject.to_str().ok())
+                .map(|subject| subject.trim().to_string())
+                .filter(|subject| !subject.is_empty()),
+            _ => None,
+        };
+        StationPeer {
+            ip_address: Some(self.client_ip(peer, &forwarded_for).to_string()),
+            certificate_subject,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(ip: &str) -> IpAddr {
+        ip.parse().unwrap()
+    }
+
+    #[test]
+    fn networks_are_parsed() {
+        let proxies = TrustedProxies::new("127.0.0.1, 10.0.0.0/8,fd00::/8", None).unwrap();
+        assert!(proxies.is_trusted(ip("127.0.0.1")));
+        assert!(!proxies.is_trusted(ip("127.0.0.2")));
+        assert!(proxies.is_trusted(ip("10.1.2.3")));
+        assert!(proxies.is_trusted(ip("fd12::1")));
+        assert!(!proxies.is_trusted(ip("fe80::1")));
+        assert!(TrustedProxies::new("10.0.0.0/33", None).is_err());
+        assert!(TrustedProxies::new("proxy.local", None).is_err());
+        assert!(TrustedProxies::new("0.0.0.0/0", None).unwrap().is_trusted(ip("203.0.113.7")));
+    }
+
+    #[test]
+    fn forwarded_for_is_only_read_from_trusted_proxies() {
+        let proxies = TrustedProxies::new("10.0.0.0/8", None).unwrap();
+        assert_eq!(proxies.client_ip(ip("203.0.113.7"), &["198.51.100.1"]), ip("203.0.113.7"));
+        assert_eq!(proxies.client_ip(ip("10.0.0.1"), &["198.51.100.1"]), ip("198.51.100.1"));
+        assert_eq!(proxies.client_ip(ip("10.0.0.1"), &[]), ip("10.0.0.1"));
+        assert_eq!(proxies.client_ip(ip("::ffff:10.0.0.1"), &["198.51.100.1"]), ip("198.51.100.1"));
+    }
+
+    #[test]
+    fn the_station_is_the_first_untrusted_hop() {
+        let proxies = TrustedProxies::new("10.0.0.0/8", None).unwrap();
+        // the station can put anything in front of the list, only the hops added by trusted
+        // proxies count
+        assert_eq!(proxies.client_ip(ip("10.0.0.1"), &["1.2.3.4, 198.51.100.1, 10.0.0.2"]), ip("198.51.100.1"));
+        assert_eq!(proxies.client_ip(ip("10.0.0.1"), &["1.2.3.4", "198.51.100.1, 10.0.0.2"]), ip("198.51.100.1"));
+        assert_eq!(proxies.client_ip(ip("10.0.0.1"), &["10.0.0.3, 10.0.0.2"]), ip("10.0.0.3"));
+        assert_eq!(proxies.client_ip(ip("10.0.0.1"), &["198.51.100.1, unknown"]), ip("10.0.0.1"));
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use jsonschema::{Draft, JSONSchema};
+use jsonschema::error::ValidationErrorKind;
+use serde_json::{json, Value};
+
+use crate::messages::ErrorCode;
+
+// Payloads of OCPP 2.0.1 messages are checked against JSON schemas compiled once at startup.
+// Serde only checks that a payload fits the message structs, the schemas also enforce maxLength,
+// enumerations, ranges and `additionalProperties: false`. With schemas.directory set, the schemas
+// are read from the official OCA distribution (OCPP-2.0.1_part3_JSON_schemas) in that directory,
+// otherwise the ones of schemas/ocpp2.0.1 embedded in the binary are used. Those were derived
+// from the message structs and are not the official ones, see schemas/README.md.
+
+macro_rules! embedded_schemas {
+    ($($name:literal),* $(,)?) => {
+        [$(($name, include_str!(concat!("../schemas/ocpp2.0.1/", $name, ".json")))),*]
+    };
+}
+
+const SCHEMAS: &[(&str, &str)] = &embedded_schemas![
+    "AuthorizeRequest", "AuthorizeResponse", "BootNotificationRequest", "BootNotificationResponse",
+    "CancelReservationRequest", "CancelReservationResponse", "CertificateSignedRequest",
+    "CertificateSignedResponse", "ChangeAvailabilityRequest", "ChangeAvailabilityResponse",
+    "ClearCacheRequest", "ClearCacheResponse", "ClearChargingProfileRequest",
+    "ClearChargingProfileResponse", "ClearDisplayMessageRequest", "ClearDisplayMessageResponse",
+    "ClearVariableMonitoringRequest", "ClearVariableMonitoringResponse",
+    "ClearedChargingLimitRequest", "ClearedChargingLimitResponse", "CostUpdatedRequest",
+    "CostUpdatedResponse", "CustomerInformationRequest", "CustomerInformationResponse",
+    "DataTransferRequest", "DataTransferResponse", "DeleteCertificateRequest",
+    "DeleteCertificateResponse", "FirmwareStatusNotificationRequest",
+    "FirmwareStatusNotificationResponse", "Get15118EVCertificateRequest",
+    "Get15118EVCertificateResponse", "GetBaseReportRequest", "GetBaseReportResponse",
+    "GetCertificateStatusRequest", "GetCertificateStatusResponse", "GetChargingProfilesRequest",
+    "GetChargingProfilesResponse", "GetCompositeScheduleRequest", "GetCompositeScheduleResponse",
+    "GetDisplayMessagesRequest", "GetDisplayMessagesResponse", "GetInstalledCertificateIdsRequest",
+    "GetInstalledCertificateIdsResponse", "GetLocalListVersionRequest",
+    "GetLocalListVersionResponse", "GetLogRequest", "GetLogResponse", "GetMonitoringReportRequest",
+    "GetMonitoringReportResponse", "GetReportRequest", "GetReportResponse",
+    "GetTransactionStatusRequest", "GetTransactionStatusResponse", "GetVariablesRequest",
+    "GetVariablesResponse", "HeartbeatRequest", "HeartbeatResponse", "InstallCertificateRequest",
+    "InstallCertificateResponse", "LogStatusNotificationRequest", "LogStatusNotificationResponse",
+    "MeterValuesRequest", "MeterValuesResponse", "NotifyChargingLimitRequest",
+    "NotifyChargingLimitResponse", "NotifyCustomerInformationRequest",
+    "NotifyCustomerInformationResponse", "NotifyDisplayMessagesRequest",
+    "NotifyDisplayMessagesResponse", "NotifyEVChargingNeedsRequest",
+    "NotifyEVChargingNeedsResponse", "NotifyEVChargingScheduleRequest",
+    "NotifyEVChargingScheduleResponse", "NotifyEventRequest", "NotifyEventResponse",
+    "NotifyMonitoringReportRequest", "NotifyMonitoringReportResponse", "NotifyReportRequest",
+    "NotifyReportResponse", "PublishFirmwareRequest", "PublishFirmwareResponse",
+    "PublishFirmwareStatusNotificationRequest", "PublishFirmwareStatusNotificationResponse",
+    "ReportChargingProfilesRequest", "ReportChargingProfilesResponse",
+    "RequestStartTransactionRequest", "RequestStartTransactionResponse",
+    "RequestStopTransactionRequest", "RequestStopTransactionResponse",
+    "ReservationStatusUpdateRequest", "ReservationStatusUpdateResponse", "ReserveNowRequest",
+    "ReserveNowResponse", "ResetRequest", "ResetResponse", "SecurityEventNotificationRequest",
+    "SecurityEventNotificationResponse", "SendLocalListRequest", "SendLocalListResponse",
+    "SetChargingProfileRequest", "SetChargingProfileResponse", "SetDisplayMessageRequest",
+    "SetDisplayMessageResponse", "SetMonitoringBaseRequest", "SetMonitoringBaseResponse",
+    "SetMonitoringLevelRequest", "SetMonitoringLevelResponse", "SetNetworkProfileRequest",
+    "SetNetworkProfileResponse", "SetVariableMonitoringRequest", "SetVariableMonitoringResponse",
+    "SetVariablesRequest", "SetVariablesResponse", "SignCertificateRequest",
+    "SignCertificateResponse", "StatusNotificationRequest", "StatusNotificationResponse",
+    "TransactionEventRequest", "TransactionEventResponse", "TriggerMessageRequest",
+    "TriggerMessageResponse", "UnlockConnectorRequest", "UnlockConnectorResponse",
+    "UnpublishFirmwareRequest", "UnpublishFirmwareResponse", "UpdateFirmwareRequest",
+    "UpdateFirmwareResponse"
+];
+
+/// A payload that does not conform to its schema, with the error code of the CallError to send
+/// back and the CallError details naming the offending field
+pub struct SchemaViolation {
+    pub error_code: ErrorCode,
+    pub details: Value,
+}
+
+pub struct SchemaValidator {
+    schemas: HashMap<&'static str, JSONSchema>,
+}
+
+impl SchemaValidator {
+    /// Compiles the schema of every message, from `directory` when given or else the embedded
+    /// ones. A missing or broken schema file stops the server at startup.
+    pub fn compile(directory: Option<&Path>) -> Result<SchemaValidator, String> {
+        let mut schemas = HashMap::new();
+        for (name, embedded) in SCHEMAS {
+            let source = match directory {
+                Some(directory) => {
+                    let file = directory.join(format!("{}.json", name));
+                    fs::read_to_string(&file).map_err(|e| format!("schema {}: {}", file.display(), e))?
+                }
+                None => embedded.to_string(),
+            };
+            // some editors save the files with a byte order mark
+            let mut schema: Value = serde_json::from_str(source.trim_start_matches('\u{feff}'))
+                .map_err(|e| format!("schema {} is not valid JSON: {}", name, e))?;
+            // the urn:OCPP:... ids of the official schemas are not resolvable base URIs, all
+            // references are local anyway
+            if let Some(schema) = schema.as_object_mut() {
+                schema.remove("$id");
+            }
+            let compiled = JSONSchema::options()
+                .with_draft(Draft::Draft6)
+                .compile(&schema)
+                .map_err(|e| format!("schema {} does not compile: {}", name, e))?;
+            schemas.insert(*name, compiled);
+        }
+        Ok(SchemaValidator { schemas })
+    }
+
+    /// Checks the payload of a Call. Actions without a schema are left to the message handlers,
+    /// which answer them with NotImplemented.
+    pub fn validate_request(&self, action: &str, payload: &Value) -> Result<(), SchemaViolation> {
+        self.validate(&format!("{}Request", action), payload)
+    }
+
+    /// Checks the payload of a CallResult to a Call with the given action
+    pub fn validate_response(&self, action: &str, payload: &Value) -> Result<(), SchemaViolation> {
+        self.validate(&format!("{}Response", action), payload)
+    }
+
+    fn validate(&self, name: &str, payload: &Value) -> Result<(), SchemaViolation> {
+        let schema = match self.schemas.get(name) {
+            Some(schema) => schema,
+            None => return Ok(()),
+        };
+        let result = schema.validate(payload);
+        if let Err(mut errors) = result {
+            // the first violation is enough to reject the message
+            if let Some(error) = errors.next() {
+                return Err(SchemaViolation {
+                    error_code: error_code(&error.kind),
+                    details: json!({
+                        "pointer": error.instance_path.to_string(),
+                        "description": error.to_string(),
+                    }),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Maps a schema violation onto the OCPP-J error codes
+fn error_code(kind: &ValidationErrorKind) -> ErrorCode {
+    match kind {
+        ValidationErrorKind::Type { .. } => ErrorCode::TypeConstraintViolation,
+        ValidationErrorKind::Required { .. } |
+        ValidationErrorKind::AdditionalProperties { .. } |
+        ValidationErrorKind::MinItems { .. } |
+        ValidationErrorKind::MaxItems { .. } => ErrorCode::OccurrenceConstraintViolation,
+        ValidationErrorKind::Enum { .. } |
+        ValidationErrorKind::MaxLength { .. } |
+        ValidationErrorKind::MinLength { .. } |
+        ValidationErrorKind::Maximum { .. } |
+        ValidationErrorKind::Minimum { .. } |
+        ValidationErrorKind::Pattern { .. } |
+        ValidationErrorKind::Format { .. } => ErrorCode::PropertyConstraintViolation,
+        _ => ErrorCode::FormatViolation,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schemas_are_read_from_the_directory() {
+        let directory = std::env::temp_dir().join(format!("schemas-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        for (name, source) in SCHEMAS.iter().skip(1) {
+            fs::write(directory.join(format!("{}.json", name)), source).unwrap();
+        }
+        let missing = SchemaValidator::compile(Some(&directory)).err().unwrap();
+        fs::write(directory.join("AuthorizeRequest.json"), r#"{"type": "object", "required": ["idToken"]}"#).unwrap();
+        let validator = SchemaValidator::compile(Some(&directory));
+        fs::remove_dir_all(&directory).unwrap();
+        assert!(missing.contains("AuthorizeRequest.json"));
+        let validator = validator.unwrap();
+        assert!(validator.validate_request("Authorize", &json!({})).is_err());
+        assert!(validator.validate_request("Authorize", &json!({"idToken": 1})).is_ok());
+        assert!(SchemaValidator::compile(None).unwrap().validate_request("Authorize", &json!({"idToken": 1})).is_err());
+    }
+}
@@ -1,10 +1,26 @@
 use actix::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use serde::{ Serialize, Deserialize};
-use serde_json::{Value};
+use schemars::JsonSchema;
+use serde_json::{json, Value};
 use uuid::Uuid;
-use crate::messages::{wrap_call, Call, CallResult, CallError, wrap_call_result};
+use chrono::Utc;
+use tracing::{debug, info, warn};
+use futures::channel::{mpsc, oneshot};
+use crate::messages::{wrap_call, Call, CallResult, CallError, OcppVersion};
+use crate::events::{self, WebEvent};
 use crate::messages;
+use crate::services;
+use crate::services::{Availability, AvailabilitySettings, ConnectivityChange, HeartbeatPolicy,
+                      HeartbeatPolicyChange, OfflineStation};
+use crate::schemas::SchemaValidator;
+use crate::traffic::{TrafficDirection, TrafficFrame, TrafficTap};
+use crate::metrics::{Gauges, Metrics};
+use crate::journal::{Journal, JournalEntry, JournalQuery};
+use crate::messages::actions::{Action, CallResultPayload};
+use crate::messages::{requests, responses};
 // Code below is for handling multiple websocket sessions between Ocpp server and charge points
 //                ,_____________
 //                | web client  |
@@ -25,6 +41,16 @@ use crate::messages;
 //|charge_point | |charge_point | |charge_point |
 //`-------------' `-------------' `-------------'
 
+/// How often journal entries past their retention are dropped
+const JOURNAL_PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// How often the server looks for stations whose Heartbeats lapsed and for calls that were not
+/// answered in time
+const HEARTBEAT_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How long the server waits for the CallResult or CallError of a call it sent
+pub const CALL_RESULT_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Ocpp server sends this message through websocket session to the charger
 #[derive(Message)]
 #[rtype(result = "()")]
@@ -39,15 +65,13 @@ pub struct MessageFromChargeStation{
     pub call_error: Option<CallError>
 }
 
-/// Ocpp server sends this messages through websocket session to the web browser
-#[derive(Message, Serialize, Deserialize)]
+/// Ocpp server sends this event through websocket session to the web browser
+#[derive(Message)]
 #[rtype(result = "()")]
-pub struct MessageToWebBrowser{
-    pub message: String
-}
+pub struct MessageToWebBrowser(pub WebEvent);
 
 /// a OCPP message to OCPP server from web client
-#[derive(Message, Clone, Deserialize)]
+#[derive(Message, Clone, Deserialize, JsonSchema)]
 #[rtype(result = "()")]
 pub struct MessageFromWebBrowser {
     #[serde(rename = "clientId")]
@@ -55,21 +79,50 @@ pub struct MessageFromWebBrowser {
     pub charger: String, // target Charge point
     pub selected: String,
     pub payload: Value, // OCPP message
+    /// correlation id of the HTTP request, logged with the calls and their results
+    #[serde(skip)]
+    pub correlation_id: String,
 }
 
-/// New Chargepoint websocket session is created
+/// Ocpp server closes a websocket session of a charger, e.g. when the charger connected again
 #[derive(Message)]
-#[rtype(String)]
+#[rtype(result = "()")]
+pub struct CloseChargerSession {
+    pub reason: String,
+}
+
+/// Ocpp server tells a websocket session of a charger its new heartbeat policy
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct HeartbeatPolicyChanged(pub HeartbeatPolicy);
+
+/// What a websocket session of a charger gets from the ocpp server once it is registered
+pub struct ChargerRegistration {
+    pub session_id: u64,
+    pub heartbeat: HeartbeatPolicy,
+}
+
+/// New Chargepoint websocket session is created. Returns the id of the session, a session of the
+/// same charger that is still open is closed.
+#[derive(Message)]
+#[rtype(result = "ChargerRegistration")]
 pub struct ConnectCharger {
     pub addr: Recipient<MessageToChargeStation>,
+    pub close: Recipient<CloseChargerSession>,
+    pub heartbeat: Recipient<HeartbeatPolicyChanged>,
     pub serial_id: String,
+    pub version: OcppVersion,
+    pub peer: services::StationPeer,
 }
 
-/// Chargepoint websocket session is disconnected
+/// Chargepoint websocket session is disconnected, ignored when the session was already replaced
 #[derive(Message)]
 #[rtype(result = "()")]
 pub struct DisconnectCharger {
     pub serial_id: String,
+    pub session_id: u64,
+    /// e.g. "closed by the station" or "no pong within 600 seconds"
+    pub reason: String,
 }
 
 #[derive(Serialize)]
@@ -92,240 +145,767 @@ pub struct DisconnectWebClient {
 
 impl actix::Message for GetChargers { type Result = Vec<String>; }
 
+/// Charge station reports the start of a transaction. Returns the transaction id, which is
+/// allocated by the server when the station did not provide one (OCPP 1.6)
+#[derive(Message)]
+#[rtype(String)]
+pub struct StartTransaction {
+    pub charger_id: String,
+    pub transaction_id: Option<String>,
+    pub evse_id: Option<i64>,
+    pub id_token: Option<String>,
+    pub timestamp: String,
+}
+
+/// Charge station reports the energy register of the meter of a transaction, in Wh
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct MeterReading {
+    pub charger_id: String,
+    pub transaction_id: String,
+    pub energy_wh: f64,
+}
+
+/// Charge station reports the end of a transaction
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct StopTransaction {
+    pub charger_id: String,
+    pub transaction_id: String,
+    pub timestamp: String,
+    pub reason: Option<String>,
+}
+
+/// A web client subscribes to the events of some stations
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct Subscribe {
+    pub web_client: String,
+    pub all: bool,
+    pub stations: Vec<String>,
+    pub groups: Vec<String>,
+}
+
+/// A web client unsubscribes from the events of some stations, `all` drops the whole subscription
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct Unsubscribe {
+    pub web_client: String,
+    pub all: bool,
+    pub stations: Vec<String>,
+    pub groups: Vec<String>,
+}
+
+/// A frame went over the websocket of a charge station
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct FrameTapped {
+    pub charger_id: String,
+    pub direction: TrafficDirection,
+    pub frame: String,
+}
+
+/// Opens a stream of the frames to and from a station
+#[derive(Message)]
+#[rtype(result = "mpsc::UnboundedReceiver<TrafficFrame>")]
+pub struct ListenTraffic {
+    pub charger_id: String,
+}
+
+/// Entries of the journal matching a query
+#[derive(Message)]
+#[rtype(result = "std::io::Result<Vec<JournalEntry>>")]
+pub struct QueryJournal(pub JournalQuery);
+
+/// Charge station sent a BootNotification
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct StationBooted {
+    pub charger_id: String,
+    pub vendor: String,
+    pub model: String,
+    pub serial_number: Option<String>,
+    pub firmware_version: Option<String>,
+    pub reason: Option<String>,
+}
+
+/// Charge station sent a Heartbeat
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct HeartbeatReceived {
+    pub charger_id: String,
+}
+
+/// Charge station reports the status of a connector with StatusNotification
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct ConnectorStatusChanged {
+    pub charger_id: String,
+    pub evse_id: i64,
+    pub connector_id: i64,
+    pub status: String,
+    pub timestamp: String,
+}
+
+/// The REST API asks for the known stations, optionally only the ones with a connector in
+/// `status` or the ones that are `online` (or not)
+#[derive(Message)]
+#[rtype(result = "Vec<services::Station>")]
+pub struct GetStations {
+    pub status: Option<String>,
+    pub online: Option<bool>,
+}
+
+/// The REST API asks for a single station with its variables, reservations and profiles
+#[derive(Message)]
+#[rtype(result = "Option<services::StationDetail>")]
+pub struct GetStation {
+    pub charger_id: String,
+}
+
+/// Charge station reports the remoteStartId of a transaction started by RequestStartTransaction
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct LinkRemoteStart {
+    pub charger_id: String,
+    pub transaction_id: String,
+    pub remote_start_id: i64,
+}
+
+/// The REST API sends a call to a charge station. The returned receiver yields the reply of
+/// the station.
+#[derive(Message)]
+#[rtype(result = "Result<oneshot::Receiver<CallReply>, SendCallError>")]
+pub struct SendCall {
+    pub charger_id: String,
+    pub action: String,
+    pub payload: Value,
+    /// correlation id of the HTTP request, logged with the call and its result
+    pub correlation_id: String,
+}
+
+/// Why a call of the REST API could not be sent
+pub enum SendCallError {
+    Offline,
+    InvalidPayload(String),
+}
+
+/// The REST API asks for the heartbeat policy of a station
+#[derive(Message)]
+#[rtype(result = "HeartbeatPolicy")]
+pub struct GetHeartbeatPolicy {
+    pub charger_id: String,
+}
+
+/// The REST API changes the heartbeat policy of a station. Returns the new policy, which the
+/// session of the station applies at once.
+#[derive(Message)]
+#[rtype(result = "Result<HeartbeatPolicy, String>")]
+pub struct SetHeartbeatPolicy {
+    pub charger_id: String,
+    pub change: HeartbeatPolicyChange,
+}
+
+/// The REST API asks for the connectivity history and uptime of a station
+#[derive(Message)]
+#[rtype(result = "Option<Availability>")]
+pub struct GetAvailability {
+    pub charger_id: String,
+}
+
+/// The REST API asks for the stations offline for longer than a threshold, the configured one
+/// when it has none
+#[derive(Message)]
+#[rtype(result = "Vec<OfflineStation>")]
+pub struct GetOfflineStations {
+    pub threshold: Option<Duration>,
+}
+
+/// The REST API asks for the Prometheus metrics of the server
+#[derive(Message)]
+#[rtype(String)]
+pub struct GetMetrics;
+
+/// Reply of a charge station to a call of the REST API
+pub enum CallReply {
+    Result(Value),
+    Error {
+        error_code: String,
+        error_description: String,
+        error_details: Value,
+    },
+    /// the CallResult does not match the response of the call's action
+    ProtocolViolation {
+        payload: Value,
+        violation: String,
+    },
+}
+
+/// Who is waiting for the reply of a call
+enum ReplyTo {
+    WebClient(String),
+    Api(oneshot::Sender<CallReply>),
+    /// calls of the server itself, e.g. the heartbeat policy pushed after a BootNotification
+    Server,
+}
+
+/// A call sent to a charge station that waits for its CallResult or CallError
+struct PendingCall {
+    reply_to: ReplyTo,
+    charger_id: String,
+    /// the call is dropped when no reply came by then
+    deadline: Instant,
+    action: String,
+    request: Value,
+    correlation_id: String,
+}
+
+/// Stations a web client receives events about
+#[derive(Default)]
+struct Subscription {
+    all: bool,
+    stations: HashSet<String>,
+    groups: HashSet<String>,
+}
+
+/// The open websocket session of a charger
+struct ChargerSession {
+    id: u64,
+    addr: Recipient<MessageToChargeStation>,
+    close: Recipient<CloseChargerSession>,
+    heartbeat: Recipient<HeartbeatPolicyChanged>,
+    /// last frame sent by the station, or when the session opened
+    last_heartbeat: Instant,
+    /// no frame came within the lapse of the heartbeat policy
+    lapsed: bool,
+}
+
 /// `OcppServer` manages websocket sessions with charge stations
 pub struct OcppServer {
-    awaiting_call_result: HashMap<String, String>, // key: MessageId, value: websocket_worker_id
-    websocket_workers: HashMap<String, Recipient<MessageToChargeStation>>,
+    awaiting_call_result: HashMap<String, PendingCall>, // key: MessageId
+    websocket_workers: HashMap<String, ChargerSession>, // key: charger_id
+    last_session_id: u64,
     webclient_workers: HashMap<String, Recipient<MessageToWebBrowser>>,
-    chargers_webclients_pair: HashMap<String, String> // key: charger_id, value: browser_id
+    subscriptions: HashMap<String, Subscription>, // key: browser_id
+    station_groups: HashMap<String, HashSet<String>>, // key: group name, value: charger ids
+    charger_versions: HashMap<String, OcppVersion>, // key: charger_id, value: negotiated OCPP version
+    transactions: services::TransactionService,
+    device_model: services::DeviceModelService,
+    reservations: services::ReservationService,
+    charging_profiles: services::ChargingProfileService,
+    network_profiles: services::NetworkProfileService,
+    stations: services::StationService,
+    heartbeats: services::HeartbeatService,
+    connectivity: services::ConnectivityService,
+    availability: AvailabilitySettings,
+    offline_reported: HashSet<String>, // charger ids of the stations reported by OfflineThresholdExceeded
+    traffic: TrafficTap,
+    traffic_listeners: HashMap<String, Vec<mpsc::UnboundedSender<TrafficFrame>>>, // key: charger_id
+    journal: Journal,
+    metrics: Metrics,
+    schemas: Arc<SchemaValidator>,
 }
 
 impl OcppServer {
-    pub fn new() -> OcppServer {
+    pub fn new(schemas: Arc<SchemaValidator>, station_groups: HashMap<String, HashSet<String>>,
+               heartbeats: services::HeartbeatService, availability: AvailabilitySettings,
+               journal: Journal) -> OcppServer {
         OcppServer {
             awaiting_call_result: HashMap::new(),
             websocket_workers: HashMap::new(),
+            last_session_id: 0,
             webclient_workers: HashMap::new(),
-            chargers_webclients_pair: HashMap::new()
+            subscriptions: HashMap::new(),
+            station_groups,
+            charger_versions: HashMap::new(),
+            transactions: services::TransactionService::new(),
+            device_model: services::DeviceModelService::new(),
+            reservations: services::ReservationService::new(),
+            charging_profiles: services::ChargingProfileService::new(),
+            network_profiles: services::NetworkProfileService::new(),
+            stations: services::StationService::new(),
+            heartbeats,
+            connectivity: services::ConnectivityService::new(availability.max_events),
+            availability,
+            offline_reported: HashSet::new(),
+            traffic: TrafficTap::new(),
+            traffic_listeners: HashMap::new(),
+            journal,
+            metrics: Metrics::new(),
+            schemas,
         }
     }
 
     fn send_message_to_charger(&self, charger: &String, message: &String) {
         if let Some(session) = self.websocket_workers.get(charger) {
-            match session.do_send(MessageToChargeStation(message.to_owned())) {
-                Err(e) => {println!("{}", e.to_string())}
-                Ok(_) => {}
+            if let Err(e) = session.addr.do_send(MessageToChargeStation(message.to_owned())) {
+                warn!(station_id = %charger, "unable to pass the frame to the session: {}", e);
             }
         }
     }
 
-    fn send_message_to_web_client(&self, web_client: &String, message: &String) {
+    fn send_event_to_web_client(&self, web_client: &String, event: WebEvent) {
         if let Some(session) = self.webclient_workers.get(web_client) {
-            match session.do_send(MessageToWebBrowser{message: message.to_owned()}) {
-                Err(e) => {println!("{}", e.to_string())}
-                Ok(_) => {}
+            if let Err(e) = session.do_send(MessageToWebBrowser(event)) {
+                warn!(web_client = %web_client, "unable to pass the event to the session: {}", e);
             }
         }
     }
 
-    fn message_from_web_browser_is_valid(msg: MessageFromWebBrowser) -> bool {
-        match msg.selected.as_str() {
-            "CancelReservation" => {
-                let res: Result<messages::requests::CancelReservationRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "CertificateSigned" => {
-                let res: Result<messages::requests::CertificateSignedRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "ChangeAvailability" => {
-                let res: Result<messages::requests::ChangeAvailabilityRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "ClearCache" => {
-                let res: Result<messages::requests::ClearCacheRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "ClearChargingProfile" => {
-                let res: Result<messages::requests::ClearChargingProfileRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "ClearDisplayMessage" => {
-                let res: Result<messages::requests::ClearDisplayMessageRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "ClearVariableMonitoring" => {
-                let res: Result<messages::requests::ClearVariableMonitoringRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "CostUpdated" => {
-                let res: Result<messages::requests::CostUpdatedRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "CustomerInformation" => {
-                let res: Result<messages::requests::CustomerInformationRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "DataTransfer" => {
-                let res: Result<messages::requests::DataTransferRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "DeleteCertificate" => {
-                let res: Result<messages::requests::DeleteCertificateRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "GetBaseReport" => {
-                let res: Result<messages::requests::GetBaseReportRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "GetChargingProfiles" => {
-                let res: Result<messages::requests::GetChargingProfilesRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "GetCompositeSchedule" => {
-                let res: Result<messages::requests::GetCompositeScheduleRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "GetDisplayMessages" => {
-                let res: Result<messages::requests::GetDisplayMessagesRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "GetInstalledCertificateIds" => {
-                let res: Result<messages::requests::GetInstalledCertificateIdsRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "GetLocalListVersion" => {
-                let res: Result<messages::requests::GetLocalListVersionRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "GetLog" => {
-                let res: Result<messages::requests::GetLogRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "GetMonitoringReport" => {
-                let res: Result<messages::requests::GetMonitoringReportRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "GetReport" => {
-                let res: Result<messages::requests::GetReportRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "GetTransactionStatus" => {
-                let res: Result<messages::requests::GetTransactionStatusRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "GetVariables" => {
-                let res: Result<messages::requests::GetVariablesRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "InstallCertificate" => {
-                let res: Result<messages::requests::InstallCertificateRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "PublishFirmware" => {
-                let res: Result<messages::requests::PublishFirmwareRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "ReserveNow" => {
-                let res: Result<messages::requests::ReserveNowRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "Reset" => {
-                let res: Result<messages::requests::ResetRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "SendLocalList" => {
-                let res: Result<messages::requests::SendLocalListRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "SetChargingProfile" => {
-                let res: Result<messages::requests::SetChargingProfileRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "SetDisplayMessage" => {
-                let res: Result<messages::requests::SetDisplayMessageRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "SetMonitoringBase" => {
-                let res: Result<messages::requests::SetMonitoringBaseRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "SetMonitoringLevel" => {
-                let res: Result<messages::requests::SetMonitoringLevelRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "SetNetworkProfile" => {
-                let res: Result<messages::requests::SetNetworkProfileRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "SetVariableMonitoring" => {
-                let res: Result<messages::requests::SetVariableMonitoringRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "SetVariables" => {
-                let res: Result<messages::requests::SetVariablesRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "SignCertificate" => {
-                let res: Result<messages::requests::SignCertificateRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "TriggerMessage" => {
-                let res: Result<messages::requests::TriggerMessageRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "UnlockConnector" => {
-                let res: Result<messages::requests::UnlockConnectorRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
-            },
-            "UnpublishFirmware" => {
-                let res: Result<messages::requests::UnpublishFirmwareRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
+    /// Web clients subscribed to a station, directly, through one of its groups or to all stations
+    fn subscribers(&self, charger_id: &str) -> Vec<&String> {
+        self.subscriptions.iter()
+            .filter(|(_, subscription)| {
+                subscription.all || subscription.stations.contains(charger_id) ||
+                    subscription.groups.iter().any(|group| {
+                        self.station_groups.get(group).is_some_and(|stations| stations.contains(charger_id))
+                    })
+            })
+            .map(|(web_client, _)| web_client)
+            .collect()
+    }
+
+    /// Sends an event about a station to every web client subscribed to it
+    fn send_event_about_station(&self, charger_id: &str, event: WebEvent) {
+        for web_client in self.subscribers(charger_id) {
+            self.send_event_to_web_client(web_client, event.clone());
+        }
+    }
+
+    fn subscription_changed(&self, web_client: &String) {
+        if let Some(subscription) = self.subscriptions.get(web_client) {
+            let mut stations: Vec<String> = subscription.stations.iter().cloned().collect();
+            let mut groups: Vec<String> = subscription.groups.iter().cloned().collect();
+            stations.sort();
+            groups.sort();
+            self.send_event_to_web_client(web_client, WebEvent::SubscriptionChanged {
+                all: subscription.all,
+                stations,
+                groups,
+                timestamp: events::now(),
+            });
+        }
+    }
+
+    /// Sends an event to every web client
+    fn broadcast_event(&self, event: WebEvent) {
+        for web_client in self.webclient_workers.keys() {
+            self.send_event_to_web_client(web_client, event.clone());
+        }
+    }
+
+    fn transaction_updated(&self, charger_id: &str, transaction_id: &str) {
+        if let Some(transaction) = self.transactions.get(charger_id, transaction_id) {
+            self.send_event_about_station(charger_id, WebEvent::TransactionUpdated {
+                station_id: charger_id.to_string(),
+                transaction,
+                timestamp: events::now(),
+            });
+        }
+    }
+
+    /// Adds the running transactions to the EVSEs of a station
+    fn with_active_transactions(&self, mut station: services::Station) -> services::Station {
+        for evse in station.evses.iter_mut() {
+            evse.active_transaction = self.transactions.active(&station.id, evse.id);
+        }
+        station
+    }
+
+    /// Passes the reply of a charge station to whoever sent the call. Web clients get the reply as
+    /// `WebEvent`, the REST API gets it as `CallReply`.
+    fn reply(&self, pending: PendingCall, event: WebEvent, reply: CallReply) {
+        self.send_call_event(&pending.charger_id, &pending.reply_to, event);
+        // the REST request may have timed out in the meantime
+        if let ReplyTo::Api(sender) = pending.reply_to {
+            let _ = sender.send(reply);
+        }
+    }
+
+    /// Sends an event about a call to the web clients subscribed to the station, and to the web
+    /// client that requested the call if it is not one of them
+    fn send_call_event(&self, charger_id: &str, reply_to: &ReplyTo, event: WebEvent) {
+        let subscribers = self.subscribers(charger_id);
+        if let ReplyTo::WebClient(web_client) = reply_to {
+            if !subscribers.contains(&web_client) {
+                self.send_event_to_web_client(web_client, event.clone());
+            }
+        }
+        for web_client in subscribers {
+            self.send_event_to_web_client(web_client, event.clone());
+        }
+    }
+
+    /// Tells the web clients about a call that will not get a reply
+    fn call_failed(&self, message_id: &str, pending: &PendingCall, error: String) {
+        self.send_call_event(&pending.charger_id, &pending.reply_to, WebEvent::CallFailed {
+            station_id: pending.charger_id.clone(),
+            message_id: message_id.to_string(),
+            action: pending.action.clone(),
+            error,
+            timestamp: events::now(),
+        });
+    }
+
+    /// Validates a call requested by a web client or the REST API and turns it into the calls to
+    /// send. Some requests to OCPP 1.6 charge points need several calls.
+    fn prepare_calls(&mut self, charger_id: &str, action: &str, payload: &Value) -> Result<Vec<(String, Value)>, String> {
+        let version = self.charger_versions.get(charger_id).cloned().unwrap_or(OcppVersion::V201);
+        if version == OcppVersion::V16 {
+            return messages::v16::calls_from_web_browser(action, payload);
+        }
+        match version {
+            // actions that did not change since 2.0.1 are validated like 2.0.1 ones
+            OcppVersion::V21 => match messages::v21::message_from_web_browser_is_valid(action, payload) {
+                Some(true) => Ok(()),
+                Some(false) => Err(format!("{} is not a valid OCPP 2.1 request", action)),
+                None => OcppServer::call_is_valid(action, payload),
             },
-            "UpdateFirmware" => {
-                let res: Result<messages::requests::UpdateFirmwareRequest, serde_json::Error> =
-                    serde_json::from_value(msg.payload);
-                res.is_ok()
+            _ => OcppServer::call_is_valid(action, payload),
+        }?;
+        // 2.1 payloads may carry fields unknown to the 2.0.1 schemas
+        if version == OcppVersion::V201 {
+            if let Err(violation) = self.schemas.validate_request(action, payload) {
+                return Err(violation.details.to_string());
+            }
+        }
+        let mut payload = payload.clone();
+        if action == Action::RequestStartTransaction.name() {
+            self.assign_remote_start_id(charger_id, &mut payload);
+        }
+        Ok(vec![(action.to_string(), payload)])
+    }
+
+    /// Sends a call to a charge station and remembers who waits for the reply. Returns the
+    /// MessageId of the call.
+    fn send_call(&mut self, charger_id: &String, action: String, payload: Value, reply_to: ReplyTo,
+                 correlation_id: &str) -> String {
+        let message_id = Uuid::new_v4().to_string();
+        let call = wrap_call(&message_id, &action, &payload.to_string());
+        info!(station_id = %charger_id, message_id = %message_id, action = %action, correlation_id, "call sent");
+        self.send_message_to_charger(charger_id, &call);
+        self.send_call_event(charger_id, &reply_to, WebEvent::CallSent {
+            station_id: charger_id.clone(),
+            message_id: message_id.clone(),
+            action: action.clone(),
+            payload: payload.clone(),
+            timestamp: events::now(),
+        });
+        self.awaiting_call_result.insert(message_id.clone(), PendingCall {
+            reply_to,
+            charger_id: charger_id.clone(),
+            deadline: Instant::now() + CALL_RESULT_TIMEOUT,
+            action,
+            request: payload,
+            correlation_id: correlation_id.to_string(),
+        });
+        message_id
+    }
+
+    /// Sets the Heartbeat and ping intervals of the station's heartbeat policy on a station that
+    /// booted, with SetVariables or, for OCPP 1.6 charge points, ChangeConfiguration. Variables
+    /// the station is known to hold already are left out.
+    fn push_heartbeat_policy(&mut self, charger_id: &String) {
+        let policy = self.heartbeats.policy(charger_id);
+        let set_variable_data: Vec<Value> = [("HeartbeatInterval", policy.heartbeat_interval),
+                                             ("WebSocketPingInterval", policy.ping_interval)].iter()
+            .map(|(variable, value)| (variable, value.to_string()))
+            .filter(|(variable, value)| self.device_model.get(charger_id, "OCPPCommCtrlr", variable) != Some(value))
+            .map(|(variable, value)| json!({
+                "attributeValue": value,
+                "component": { "name": "OCPPCommCtrlr" },
+                "variable": { "name": variable },
+            }))
+            .collect();
+        if set_variable_data.is_empty() {
+            return;
+        }
+        let payload = json!({ "setVariableData": set_variable_data });
+        match self.prepare_calls(charger_id, Action::SetVariables.name(), &payload) {
+            Ok(calls) => {
+                for (action, payload) in calls {
+                    self.send_call(charger_id, action, payload, ReplyTo::Server, "");
+                }
+            }
+            Err(e) => warn!(station_id = %charger_id, "unable to push the heartbeat policy: {}", e),
+        }
+    }
+
+    /// Drops the calls of a station whose session is gone, they cannot be answered anymore. A REST
+    /// request waiting for one of them sees its reply channel closed, the web clients get a
+    /// CallFailed event.
+    fn drop_pending_calls(&mut self, charger_id: &str, reason: &str) {
+        let dropped: Vec<String> = self.awaiting_call_result.iter()
+            .filter(|(_, pending)| pending.charger_id == charger_id)
+            .map(|(message_id, _)| message_id.clone())
+            .collect();
+        for message_id in dropped {
+            if let Some(pending) = self.awaiting_call_result.remove(&message_id) {
+                info!(station_id = charger_id, message_id = %message_id, action = %pending.action,
+                      correlation_id = %pending.correlation_id, reason, "call dropped");
+                self.call_failed(&message_id, &pending, format!("No reply, {}", reason));
+            }
+        }
+    }
+
+    /// Drops the calls that were not answered within CALL_RESULT_TIMEOUT, a late reply is ignored
+    fn expire_pending_calls(&mut self) {
+        let now = Instant::now();
+        let expired: Vec<String> = self.awaiting_call_result.iter()
+            .filter(|(_, pending)| pending.deadline <= now)
+            .map(|(message_id, _)| message_id.clone())
+            .collect();
+        for message_id in expired {
+            if let Some(pending) = self.awaiting_call_result.remove(&message_id) {
+                warn!(station_id = %pending.charger_id, message_id = %message_id, action = %pending.action,
+                      correlation_id = %pending.correlation_id, "no reply to the call in time, dropping it");
+                self.call_failed(&message_id, &pending,
+                                 format!("No reply within {} seconds", CALL_RESULT_TIMEOUT.as_secs()));
             }
-            &_ => false
+        }
+    }
+
+    /// Replaces the remoteStartId chosen by the web client with one generated by the server, so
+    /// the transaction started by the station can be linked back to the request
+    fn assign_remote_start_id(&mut self, charger_id: &str, payload: &mut Value) {
+        if let Ok(request) = serde_json::from_value::<requests::RequestStartTransactionRequest>(payload.clone()) {
+            let remote_start_id = self.transactions.request_remote_start(charger_id, request.evse_id,
+                                                                         &request.id_token.id_token);
+            payload["remoteStartId"] = json!(remote_start_id);
+        }
+    }
+
+    /// Checks the CallResult of a 2.0.1 station against the schema and the response type of the
+    /// call's action, then hands the decoded response to the services
+    fn process_call_result(&mut self, charger_id: &str, pending: &PendingCall, payload: &Value) -> Result<(), String> {
+        if let Err(violation) = self.schemas.validate_response(&pending.action, payload) {
+            return Err(violation.details.to_string());
+        }
+        let action = Action::from_name(&pending.action)
+            .ok_or_else(|| format!("{} is not an OCPP 2.0.1 action", pending.action))?;
+        let response = action.decode_response(payload)?;
+        self.apply_call_result(charger_id, &pending.request, response);
+        Ok(())
+    }
+
+    /// Updates the state kept about a station with the outcome of a call
+    fn apply_call_result(&mut self, charger_id: &str, request: &Value, response: CallResultPayload) {
+        match response {
+            CallResultPayload::GetVariables(response) => {
+                for result in response.get_variable_result {
+                    if let (responses::GetVariableStatusEnumType::Accepted, Some(value)) =
+                        (result.attribute_status, result.attribute_value) {
+                        self.device_model.set(charger_id, &result.component.name, &result.variable.name, &value);
+                    }
+                }
+            }
+            CallResultPayload::SetVariables(response) => {
+                let request: requests::SetVariablesRequest = match serde_json::from_value(request.clone()) {
+                    Ok(request) => request,
+                    Err(_) => return,
+                };
+                for result in response.set_variable_result {
+                    if !matches!(result.attribute_status, responses::SetVariableStatusEnumType::Accepted |
+                                                          responses::SetVariableStatusEnumType::RebootRequired) {
+                        continue;
+                    }
+                    let data = request.set_variable_data.iter().find(|data| {
+                        data.component.name == result.component.name && data.variable.name == result.variable.name
+                    });
+                    if let Some(data) = data {
+                        self.device_model.set(charger_id, &data.component.name, &data.variable.name,
+                                              &data.attribute_value);
+                    }
+                }
+            }
+            CallResultPayload::ReserveNow(response) => {
+                if let (responses::ReserveNowStatusEnumType::Accepted, Ok(request)) =
+                    (response.status, serde_json::from_value::<requests::ReserveNowRequest>(request.clone())) {
+                    self.reservations.reserve(charger_id, services::Reservation {
+                        id: request.id,
+                        evse_id: request.evse_id,
+                        id_token: request.id_token.id_token,
+                        expiry_date_time: request.expiry_date_time,
+                    });
+                }
+            }
+            CallResultPayload::CancelReservation(response) => {
+                if let (responses::CancelReservationStatusEnumType::Accepted, Ok(request)) =
+                    (response.status, serde_json::from_value::<requests::CancelReservationRequest>(request.clone())) {
+                    self.reservations.cancel(charger_id, request.reservation_id);
+                }
+            }
+            CallResultPayload::SetChargingProfile(response) => {
+                if let (responses::ChargingProfileStatusEnumType::Accepted, Ok(request)) =
+                    (response.status, serde_json::from_value::<requests::SetChargingProfileRequest>(request.clone())) {
+                    self.charging_profiles.set(charger_id, services::ChargingProfile {
+                        id: request.charging_profile.id,
+                        evse_id: request.evse_id,
+                        stack_level: request.charging_profile.stack_level,
+                        purpose: format!("{:?}", request.charging_profile.charging_profile_purpose),
+                    });
+                }
+            }
+            CallResultPayload::ClearChargingProfile(response) => {
+                if let (responses::ClearChargingProfileStatusEnumType::Accepted, Ok(request)) =
+                    (response.status, serde_json::from_value::<requests::ClearChargingProfileRequest>(request.clone())) {
+                    let criteria = request.charging_profile_criteria;
+                    self.charging_profiles.clear(
+                        charger_id,
+                        request.charging_profile_id,
+                        criteria.as_ref().and_then(|criteria| criteria.evse_id),
+                        criteria.as_ref().and_then(|criteria| criteria.stack_level),
+                        criteria.as_ref().and_then(|criteria| criteria.charging_profile_purpose.as_ref())
+                            .map(|purpose| format!("{:?}", purpose)));
+                }
+            }
+            CallResultPayload::RequestStartTransaction(response) => {
+                let request: requests::RequestStartTransactionRequest = match serde_json::from_value(request.clone()) {
+                    Ok(request) => request,
+                    Err(_) => return,
+                };
+                match (response.status, response.transaction_id) {
+                    // the transaction was already running, e.g. the cable was plugged in before
+                    (responses::RequestStartStopStatusEnumType::Accepted, Some(transaction_id)) => {
+                        self.transactions.link_remote_start(charger_id, request.remote_start_id, &transaction_id);
+                        self.transaction_updated(charger_id, &transaction_id);
+                    }
+                    // the station reports the remoteStartId in the TransactionEvent of the new transaction
+                    (responses::RequestStartStopStatusEnumType::Accepted, None) => {}
+                    (responses::RequestStartStopStatusEnumType::Rejected, _) => {
+                        self.transactions.cancel_remote_start(charger_id, request.remote_start_id);
+                    }
+                }
+            }
+            CallResultPayload::CostUpdated(_) => {
+                if let Ok(request) = serde_json::from_value::<requests::CostUpdatedRequest>(request.clone()) {
+                    self.transactions.update_cost(charger_id, &request.transaction_id, request.total_cost);
+                    self.transaction_updated(charger_id, &request.transaction_id);
+                }
+            }
+            CallResultPayload::SetNetworkProfile(response) => {
+                let request: requests::SetNetworkProfileRequest = match serde_json::from_value(request.clone()) {
+                    Ok(request) => request,
+                    Err(_) => return,
+                };
+                match response.status {
+                    responses::SetNetworkProfileStatusEnumType::Accepted => {
+                        self.network_profiles.set(charger_id, request.configuration_slot,
+                                                  serde_json::to_value(&request.connection_data).unwrap());
+                    }
+                    status => {
+                        warn!(station_id = %charger_id, "network profile for slot {} not set: {} {}",
+                              request.configuration_slot, serde_json::to_value(&status).unwrap(),
+                                 serde_json::to_value(&response.status_info).unwrap());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Marks offline the stations that sent nothing for longer than the lapse of their heartbeat
+    /// policy
+    fn check_heartbeats(&mut self) {
+        let mut lapsed = Vec::new();
+        for (charger_id, session) in self.websocket_workers.iter_mut() {
+            let policy = self.heartbeats.policy(charger_id);
+            if !session.lapsed && session.last_heartbeat.elapsed() > policy.lapse() {
+                session.lapsed = true;
+                lapsed.push(charger_id.clone());
+            }
+        }
+        for charger_id in lapsed {
+            let reason = format!("no message within {} seconds", self.heartbeats.policy(&charger_id).lapse().as_secs());
+            warn!(station_id = %charger_id, "{}, marking the station offline", reason);
+            self.stations.heartbeat_lapsed(&charger_id);
+            self.record_connectivity(&charger_id, ConnectivityChange::HeartbeatLapsed, Some(reason));
+            let last_heartbeat = self.stations.get(&charger_id).and_then(|station| station.last_heartbeat);
+            self.broadcast_event(WebEvent::HeartbeatLapsed {
+                station_id: charger_id,
+                last_heartbeat,
+                timestamp: events::now(),
+            });
+        }
+    }
+
+    /// Records a Heartbeat or BootNotification of a station
+    fn heartbeat_received(&mut self, charger_id: &str) {
+        self.stations.heartbeat(charger_id, &events::now());
+        self.sign_of_life(charger_id);
+    }
+
+    /// Records a frame sent by a station, bringing it back online when its heartbeats had lapsed.
+    /// OCPP 2.0.1 lets a station skip Heartbeats while it sends other messages, so every frame
+    /// counts.
+    fn sign_of_life(&mut self, charger_id: &str) {
+        let resumed = match self.websocket_workers.get_mut(charger_id) {
+            Some(session) => {
+                session.last_heartbeat = Instant::now();
+                std::mem::take(&mut session.lapsed)
+            }
+            None => false,
+        };
+        if resumed {
+            info!(station_id = %charger_id, "the station sends heartbeats again");
+            self.record_connectivity(charger_id, ConnectivityChange::HeartbeatResumed, None);
+            self.broadcast_event(WebEvent::HeartbeatResumed {
+                station_id: charger_id.to_string(),
+                timestamp: events::now(),
+            });
+        }
+    }
+
+    /// Adds a change to the connectivity history of a station
+    fn record_connectivity(&mut self, charger_id: &str, change: ConnectivityChange, reason: Option<String>) {
+        self.connectivity.record(charger_id, change, reason, Utc::now());
+        if matches!(change, ConnectivityChange::Connected | ConnectivityChange::HeartbeatResumed) {
+            self.offline_reported.remove(charger_id);
+        }
+    }
+
+    /// Tells the web clients about the stations that went past the offline threshold
+    fn check_offline_stations(&mut self) {
+        for offline in self.connectivity.offline(self.availability.offline_threshold, Utc::now()) {
+            if !self.offline_reported.insert(offline.station_id.clone()) {
+                continue;
+            }
+            warn!(station_id = %offline.station_id, "the station is offline since {}", offline.offline_since);
+            self.broadcast_event(WebEvent::OfflineThresholdExceeded {
+                station_id: offline.station_id,
+                offline_since: offline.offline_since,
+                offline_seconds: offline.offline_seconds,
+                reason: offline.reason,
+                timestamp: events::now(),
+            });
+        }
+    }
+
+    fn prune_journal(&mut self, ctx: &mut Context<Self>) {
+        let prune = match self.journal.start_prune() {
+            Some(prune) => prune,
+            None => return,
+        };
+        // the whole file is read and written again, which must not hold up the stations
+        ctx.spawn(actix_web::web::block(prune).into_actor(self).map(|pruned, act, _| {
+            if let Err(e) = act.journal.finish_prune() {
+                warn!("unable to reopen the journal: {}", e);
+            }
+            match pruned {
+                Ok(0) => {}
+                Ok(dropped) => info!("pruned {} journal entries", dropped),
+                Err(e) => warn!("unable to prune the journal: {}", e),
+            }
+        }));
+    }
+
+    /// checks that the action may be sent by the CSMS and that the payload is a valid request
+    /// of that action
+    fn call_is_valid(action: &str, payload: &Value) -> Result<(), String> {
+        match Action::from_name(action) {
+            Some(known) if known.direction().is_sent_by_csms() => known.validate_request(payload),
+            Some(_) => Err(format!("{} is not sent by the CSMS", action)),
+            None => Err(format!("{} is not an OCPP 2.0.1 action", action)),
         }
     }
 }
@@ -334,15 +914,57 @@ impl Actor for OcppServer {
     /// We are going to use simple Context, we just need ability to communicate
     /// with other actors.
     type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        self.prune_journal(ctx);
+        ctx.run_interval(JOURNAL_PRUNE_INTERVAL, |act, ctx| act.prune_journal(ctx));
+        ctx.run_interval(HEARTBEAT_CHECK_INTERVAL, |act, _| {
+            act.check_heartbeats();
+            act.check_offline_stations();
+            act.expire_pending_calls();
+        });
+    }
 }
 
 impl Handler<ConnectCharger> for OcppServer {
-    type Result = String;
+    type Result = MessageResult<ConnectCharger>;
 
     fn handle(&mut self, msg: ConnectCharger, _: &mut Context<Self>) -> Self::Result {
-        self.websocket_workers.insert(msg.serial_id.clone(), msg.addr);
-        println!("OcppServer: Inserting charger: {}", msg.serial_id);
-        msg.serial_id
+        self.last_session_id += 1;
+        let session = ChargerSession {
+            id: self.last_session_id,
+            addr: msg.addr,
+            close: msg.close,
+            heartbeat: msg.heartbeat,
+            last_heartbeat: Instant::now(),
+            lapsed: false,
+        };
+        if let Some(replaced) = self.websocket_workers.insert(msg.serial_id.clone(), session) {
+            // the calls of the replaced session cannot be answered on the new one
+            self.drop_pending_calls(&msg.serial_id, "the station connected again");
+            info!(station_id = %msg.serial_id, session_id = replaced.id, "the station connected again, closing its session");
+            self.traffic.forget(&msg.serial_id);
+            let reason = String::from("Replaced by a new connection of the charging station");
+            self.record_connectivity(&msg.serial_id, ConnectivityChange::Disconnected, Some(reason.clone()));
+            let _ = replaced.close.do_send(CloseChargerSession { reason });
+        }
+        self.record_connectivity(&msg.serial_id, ConnectivityChange::Connected, None);
+        self.charger_versions.insert(msg.serial_id.clone(), msg.version);
+        self.stations.connected(&msg.serial_id, msg.version, &msg.peer);
+        self.broadcast_event(WebEvent::StationConnected {
+            station_id: msg.serial_id.clone(),
+            version: msg.version,
+            ip_address: msg.peer.ip_address.clone(),
+            timestamp: events::now(),
+        });
+        info!(station_id = %msg.serial_id, session_id = self.last_session_id, version = msg.version.sub_protocol(),
+              ip_address = msg.peer.ip_address.as_deref().unwrap_or("unknown"),
+              certificate_subject = msg.peer.certificate_subject.as_deref().unwrap_or("none"),
+              "station connected");
+        MessageResult(ChargerRegistration {
+            session_id: self.last_session_id,
+            heartbeat: self.heartbeats.policy(&msg.serial_id),
+        })
     }
 }
 
@@ -351,7 +973,8 @@ impl Handler<ConnectWebClient> for OcppServer {
 
     fn handle(&mut self, msg: ConnectWebClient, _: &mut Context<Self>) -> Self::Result {
         self.webclient_workers.insert(msg.serial_id.clone(), msg.addr);
-        println!("OcppServer: Inserting web client: {}", msg.serial_id);
+        self.subscriptions.insert(msg.serial_id.clone(), Subscription::default());
+        info!(web_client = %msg.serial_id, "web client connected");
         msg.serial_id
     }
 }
@@ -359,9 +982,23 @@ impl Handler<ConnectWebClient> for OcppServer {
 impl Handler<DisconnectCharger> for OcppServer {
     type Result = ();
     fn handle(&mut self, msg: DisconnectCharger, _: &mut Context<Self>) -> Self::Result {
-        println!("OcppServer: Removing charger: {}", msg.serial_id);
+        let current = self.websocket_workers.get(&msg.serial_id).map(|session| session.id);
+        if current != Some(msg.session_id) {
+            debug!(station_id = %msg.serial_id, session_id = msg.session_id,
+                   "ignoring the disconnect of a session that was replaced or already removed");
+            return;
+        }
+        info!(station_id = %msg.serial_id, session_id = msg.session_id, reason = %msg.reason, "station disconnected");
+        self.record_connectivity(&msg.serial_id, ConnectivityChange::Disconnected, Some(msg.reason));
         self.websocket_workers.remove(msg.serial_id.as_str());
-        self.chargers_webclients_pair.remove(msg.serial_id.as_str());
+        self.drop_pending_calls(&msg.serial_id, "the station disconnected");
+        self.charger_versions.remove(msg.serial_id.as_str());
+        self.stations.disconnected(&msg.serial_id);
+        self.traffic.forget(&msg.serial_id);
+        self.broadcast_event(WebEvent::StationDisconnected {
+            station_id: msg.serial_id.clone(),
+            timestamp: events::now(),
+        });
     }
 }
 
@@ -369,13 +1006,9 @@ impl Handler<DisconnectWebClient> for OcppServer {
     type Result = ();
 
     fn handle(&mut self, msg: DisconnectWebClient, _: &mut Context<Self>) -> Self::Result {
-        println!("OcppServer: Removing web client: {}", msg.serial_id);
+        info!(web_client = %msg.serial_id, "web client disconnected");
         self.webclient_workers.remove(&msg.serial_id);
-        for (item, value) in self.chargers_webclients_pair.clone() {
-            if value == msg.serial_id {
-                self.chargers_webclients_pair.remove(&item);
-            }
-        }
+        self.subscriptions.remove(&msg.serial_id);
     }
 }
 
@@ -395,17 +1028,49 @@ impl Handler<MessageFromWebBrowser> for OcppServer {
     type Result = ();
 
     fn handle(&mut self, msg: MessageFromWebBrowser, _: &mut Context<Self>) -> Self::Result {
-        println!("sending message to: {}", msg.charger);
-        let message_id = Uuid::new_v4().to_string();
-        if OcppServer::message_from_web_browser_is_valid(msg.clone()) {
-            let call = wrap_call(&message_id, &msg.selected, &serde_json::to_string(&msg.payload).unwrap());
-            self.send_message_to_charger(&msg.charger, &call);
-            self.awaiting_call_result.insert(message_id, msg.client_id.clone());
-            self.chargers_webclients_pair.insert(msg.charger.clone(), msg.client_id.clone());
-            self.send_message_to_web_client(&msg.client_id, &format!("call sent to charger {}:\r\n{}", &msg.charger, call))
+        let calls = if self.websocket_workers.contains_key(&msg.charger) {
+            self.prepare_calls(&msg.charger, &msg.selected, &msg.payload)
         } else {
-            self.send_message_to_web_client(&msg.client_id, &format!("improper payload:\r\n{}", &msg.payload))
+            Err(format!("Charging station {} is offline", msg.charger))
+        };
+        match calls {
+            Ok(calls) => {
+                for (action, payload) in calls {
+                    self.send_call(&msg.charger, action, payload, ReplyTo::WebClient(msg.client_id.clone()),
+                                   &msg.correlation_id);
+                }
+            }
+            Err(e) => {
+                self.send_event_to_web_client(&msg.client_id, WebEvent::CallRejected {
+                    station_id: msg.charger.clone(),
+                    action: msg.selected.clone(),
+                    payload: msg.payload.clone(),
+                    error: e,
+                    timestamp: events::now(),
+                });
+            }
+        }
+    }
+}
+
+impl Handler<SendCall> for OcppServer {
+    type Result = Result<oneshot::Receiver<CallReply>, SendCallError>;
+
+    fn handle(&mut self, msg: SendCall, _: &mut Context<Self>) -> Self::Result {
+        if !self.websocket_workers.contains_key(&msg.charger_id) {
+            return Err(SendCallError::Offline);
+        }
+        let mut calls = self.prepare_calls(&msg.charger_id, &msg.action, &msg.payload)
+            .map_err(SendCallError::InvalidPayload)?;
+        // a single reply is awaited, requests that become several OCPP 1.6 calls are sent one by one
+        if calls.len() != 1 {
+            return Err(SendCallError::InvalidPayload(
+                format!("{} has to be sent as {} separate calls to this charge point", msg.action, calls.len())));
         }
+        let (action, payload) = calls.remove(0);
+        let (sender, receiver) = oneshot::channel();
+        self.send_call(&msg.charger_id, action, payload, ReplyTo::Api(sender), &msg.correlation_id);
+        Ok(receiver)
     }
 }
 
@@ -413,37 +1078,474 @@ impl Handler<MessageFromChargeStation> for OcppServer {
     type Result = ();
 
     fn handle(&mut self, msg: MessageFromChargeStation, _: &mut Context<Self>) -> Self::Result {
-        if msg.call.is_some() {
-            let call = msg.call.unwrap();
-            if let Some(webclient_id) = self.chargers_webclients_pair.get(msg.charger_id.as_str()){
-                let call_as_string = format!("Call: [2, \"{}\", \"{}\", {}]", call.unique_id,
-                                             call.action, call.payload.as_str().unwrap());
-                self.send_message_to_web_client(webclient_id, &call_as_string);
+        if let Some(call) = msg.call {
+            self.send_event_about_station(&msg.charger_id, WebEvent::CallReceived {
+                station_id: msg.charger_id.clone(),
+                message_id: call.unique_id,
+                action: call.action,
+                payload: call.payload,
+                timestamp: events::now(),
+            });
+        }
+        if let Some(call_error) = msg.call_error {
+            let key = call_error.unique_id.trim_matches('"');
+            if let Some(pending) = self.awaiting_call_result.remove(key) {
+                let error_code = call_error.error_code.trim_matches('"').to_string();
+                let error_description = call_error.error_description.trim_matches('"').to_string();
+                let error_details: Value = serde_json::from_str(&call_error.error_details)
+                    .unwrap_or_else(|_| Value::String(call_error.error_details.clone()));
+                info!(station_id = %msg.charger_id, message_id = key, action = %pending.action,
+                      correlation_id = %pending.correlation_id, error_code = %error_code, "call error received");
+                let event = WebEvent::CallErrorReceived {
+                    station_id: msg.charger_id.clone(),
+                    message_id: key.to_string(),
+                    action: pending.action.clone(),
+                    error_code: error_code.clone(),
+                    error_description: error_description.clone(),
+                    error_details: error_details.clone(),
+                    timestamp: events::now(),
+                };
+                self.reply(pending, event, CallReply::Error { error_code, error_description, error_details });
             }
         }
-        if msg.call_error.is_some() {
-            let call_error = msg.call_error.unwrap();
-            if let Some(webclient_id) = self.awaiting_call_result.get(call_error.unique_id.as_str()) {
-                let call_error_as_a_string = format!("Call error: [4, \"{}\", \"{}\", \"{}\", {}]",
-                                                     call_error.unique_id,
-                                                     call_error.error_code, call_error.error_description,
-                                                     call_error.error_details);
-                self.send_message_to_web_client(webclient_id, &call_error_as_a_string);
-                self.awaiting_call_result.remove(call_error.unique_id.as_str());
+        if let Some(call_result) = msg.call_result {
+            let key = call_result.unique_id.trim_matches('"');
+            if let Some(pending) = self.awaiting_call_result.remove(key) {
+                let version = self.charger_versions.get(&msg.charger_id).cloned().unwrap_or(OcppVersion::V201);
+                let processed = if version == OcppVersion::V201 {
+                    self.process_call_result(&msg.charger_id, &pending, &call_result.payload)
+                } else {
+                    Ok(())
+                };
+                match processed {
+                    Ok(()) => {
+                        info!(station_id = %msg.charger_id, message_id = key, action = %pending.action,
+                              correlation_id = %pending.correlation_id, "call result received");
+                        let event = WebEvent::CallResultReceived {
+                            station_id: msg.charger_id.clone(),
+                            message_id: key.to_string(),
+                            action: pending.action.clone(),
+                            payload: call_result.payload.clone(),
+                            timestamp: events::now(),
+                        };
+                        self.reply(pending, event, CallReply::Result(call_result.payload));
+                    }
+                    // a CallResult cannot be answered with a CallError, the sender of the call is told instead
+                    Err(violation) => {
+                        warn!(station_id = %msg.charger_id, message_id = key, action = %pending.action,
+                              correlation_id = %pending.correlation_id, violation = %violation,
+                              "protocol violation in the call result");
+                        let event = WebEvent::ProtocolViolation {
+                            station_id: msg.charger_id.clone(),
+                            message_id: key.to_string(),
+                            action: pending.action.clone(),
+                            payload: call_result.payload.clone(),
+                            violation: violation.clone(),
+                            timestamp: events::now(),
+                        };
+                        self.reply(pending, event, CallReply::ProtocolViolation {
+                            payload: call_result.payload,
+                            violation,
+                        });
+                    }
+                }
             }
         }
-        if msg.call_result.is_some() {
-            let call_result = msg.call_result.unwrap();
-            let key = call_result.unique_id.strip_prefix("\"").unwrap().strip_suffix("\"").unwrap();
-
-            if let Some(webclient_id) = self.awaiting_call_result.get(key) {
-                let call_result_as_a_string =
-                    format!("Call result: \r\n{}",
-                            wrap_call_result(&call_result.unique_id,
-                                             (&call_result.payload).to_string()));
-                self.send_message_to_web_client(webclient_id, &call_result_as_a_string);
-                self.awaiting_call_result.remove(call_result.unique_id.as_str());
+    }
+}
+
+impl Handler<StartTransaction> for OcppServer {
+    type Result = String;
+
+    fn handle(&mut self, msg: StartTransaction, _: &mut Context<Self>) -> Self::Result {
+        let transaction_id = self.transactions.start(&msg.charger_id, msg.transaction_id, msg.evse_id,
+                                                     msg.id_token, &msg.timestamp);
+        info!(station_id = %msg.charger_id, transaction_id = %transaction_id, "transaction started");
+        self.transaction_updated(&msg.charger_id, &transaction_id);
+        transaction_id
+    }
+}
+
+impl Handler<MeterReading> for OcppServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: MeterReading, _: &mut Context<Self>) -> Self::Result {
+        let delivered_wh = self.transactions.meter_reading(&msg.charger_id, &msg.transaction_id, msg.energy_wh);
+        self.metrics.energy_delivered(delivered_wh);
+    }
+}
+
+impl Handler<StopTransaction> for OcppServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: StopTransaction, _: &mut Context<Self>) -> Self::Result {
+        info!(station_id = %msg.charger_id, transaction_id = %msg.transaction_id, "transaction stopped");
+        self.transactions.stop(&msg.charger_id, &msg.transaction_id, &msg.timestamp, msg.reason);
+        self.transaction_updated(&msg.charger_id, &msg.transaction_id);
+    }
+}
+
+impl Handler<LinkRemoteStart> for OcppServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: LinkRemoteStart, _: &mut Context<Self>) -> Self::Result {
+        info!(station_id = %msg.charger_id, transaction_id = %msg.transaction_id, remote_start_id = msg.remote_start_id,
+              "transaction started by a remote start");
+        self.transactions.link_remote_start(&msg.charger_id, msg.remote_start_id, &msg.transaction_id);
+        self.transaction_updated(&msg.charger_id, &msg.transaction_id);
+    }
+}
+
+impl Handler<StationBooted> for OcppServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: StationBooted, _: &mut Context<Self>) -> Self::Result {
+        self.heartbeat_received(&msg.charger_id);
+        self.stations.booted(&msg.charger_id, services::BootInfo {
+            vendor: msg.vendor,
+            model: msg.model,
+            serial_number: msg.serial_number,
+            firmware_version: msg.firmware_version,
+            reason: msg.reason,
+            timestamp: events::now(),
+        });
+        self.push_heartbeat_policy(&msg.charger_id);
+    }
+}
+
+impl Handler<HeartbeatReceived> for OcppServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: HeartbeatReceived, _: &mut Context<Self>) -> Self::Result {
+        self.heartbeat_received(&msg.charger_id);
+    }
+}
+
+impl Handler<GetAvailability> for OcppServer {
+    type Result = Option<Availability>;
+
+    fn handle(&mut self, msg: GetAvailability, _: &mut Context<Self>) -> Self::Result {
+        let history = self.connectivity.history(&msg.charger_id)?.clone();
+        let now = Utc::now();
+        let uptime = self.availability.uptime_windows.iter()
+            .map(|(window, length)| services::Uptime {
+                window: window.clone(),
+                window_seconds: length.as_secs(),
+                uptime_percent: self.connectivity.uptime(&msg.charger_id, *length, now),
+            })
+            .collect();
+        Some(Availability {
+            online: self.stations.get(&msg.charger_id).is_some_and(|station| station.online),
+            station_id: msg.charger_id,
+            uptime,
+            history,
+        })
+    }
+}
+
+impl Handler<GetOfflineStations> for OcppServer {
+    type Result = MessageResult<GetOfflineStations>;
+
+    fn handle(&mut self, msg: GetOfflineStations, _: &mut Context<Self>) -> Self::Result {
+        let threshold = msg.threshold.unwrap_or(self.availability.offline_threshold);
+        MessageResult(self.connectivity.offline(threshold, Utc::now()))
+    }
+}
+
+impl Handler<GetMetrics> for OcppServer {
+    type Result = String;
+
+    fn handle(&mut self, _: GetMetrics, _: &mut Context<Self>) -> Self::Result {
+        self.metrics.render(&Gauges {
+            connected_stations: self.websocket_workers.len(),
+            web_clients: self.webclient_workers.len(),
+            outstanding_calls: self.awaiting_call_result.len(),
+            active_transactions: self.transactions.active_count(),
+        })
+    }
+}
+
+impl Handler<GetHeartbeatPolicy> for OcppServer {
+    type Result = MessageResult<GetHeartbeatPolicy>;
+
+    fn handle(&mut self, msg: GetHeartbeatPolicy, _: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.heartbeats.policy(&msg.charger_id))
+    }
+}
+
+impl Handler<SetHeartbeatPolicy> for OcppServer {
+    type Result = Result<HeartbeatPolicy, String>;
+
+    fn handle(&mut self, msg: SetHeartbeatPolicy, _: &mut Context<Self>) -> Self::Result {
+        let policy = self.heartbeats.policy(&msg.charger_id).with(&msg.change);
+        policy.validate()?;
+        self.heartbeats.set(&msg.charger_id, policy);
+        if let Some(session) = self.websocket_workers.get(&msg.charger_id) {
+            let _ = session.heartbeat.do_send(HeartbeatPolicyChanged(policy));
+        }
+        info!(station_id = %msg.charger_id, "heartbeat policy is now {:?}", policy);
+        Ok(policy)
+    }
+}
+
+impl Handler<ConnectorStatusChanged> for OcppServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: ConnectorStatusChanged, _: &mut Context<Self>) -> Self::Result {
+        debug!(station_id = %msg.charger_id, evse_id = msg.evse_id, connector_id = msg.connector_id,
+               "connector is {}", msg.status);
+        self.stations.connector_status(&msg.charger_id, msg.evse_id, msg.connector_id, &msg.status, &msg.timestamp);
+        self.send_event_about_station(&msg.charger_id, WebEvent::StatusChanged {
+            station_id: msg.charger_id.clone(),
+            evse_id: msg.evse_id,
+            connector_id: msg.connector_id,
+            status: msg.status,
+            timestamp: msg.timestamp,
+        });
+    }
+}
+
+impl Handler<GetStations> for OcppServer {
+    type Result = MessageResult<GetStations>;
+
+    fn handle(&mut self, msg: GetStations, _: &mut Context<Self>) -> Self::Result {
+        let stations = self.stations.all().into_iter()
+            .filter(|station| msg.online.is_none_or(|online| station.online == online))
+            .filter(|station| msg.status.as_ref().is_none_or(|status| {
+                station.evses.iter().any(|evse| evse.connectors.iter().any(|connector| connector.status == *status))
+            }))
+            .map(|station| self.with_active_transactions(station))
+            .collect();
+        MessageResult(stations)
+    }
+}
+
+impl Handler<GetStation> for OcppServer {
+    type Result = MessageResult<GetStation>;
+
+    fn handle(&mut self, msg: GetStation, _: &mut Context<Self>) -> Self::Result {
+        let detail = self.stations.get(&msg.charger_id).map(|station| services::StationDetail {
+            station: self.with_active_transactions(station),
+            variables: self.device_model.variables(&msg.charger_id),
+            reservations: self.reservations.reservations(&msg.charger_id),
+            charging_profiles: self.charging_profiles.profiles(&msg.charger_id),
+            network_profiles: self.network_profiles.profiles(&msg.charger_id),
+        });
+        MessageResult(detail)
+    }
+}
+
+impl Handler<Subscribe> for OcppServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: Subscribe, _: &mut Context<Self>) -> Self::Result {
+        if let Some(subscription) = self.subscriptions.get_mut(&msg.web_client) {
+            subscription.all |= msg.all;
+            subscription.stations.extend(msg.stations);
+            subscription.groups.extend(msg.groups);
+        }
+        self.subscription_changed(&msg.web_client);
+    }
+}
+
+impl Handler<Unsubscribe> for OcppServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: Unsubscribe, _: &mut Context<Self>) -> Self::Result {
+        if let Some(subscription) = self.subscriptions.get_mut(&msg.web_client) {
+            if msg.all {
+                *subscription = Subscription::default();
+            }
+            for station in &msg.stations {
+                subscription.stations.remove(station);
+            }
+            for group in &msg.groups {
+                subscription.groups.remove(group);
             }
         }
+        self.subscription_changed(&msg.web_client);
     }
-}
\ No newline at end of file
+}
+
+impl Handler<FrameTapped> for OcppServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: FrameTapped, _: &mut Context<Self>) -> Self::Result {
+        if msg.direction == TrafficDirection::Inbound {
+            self.sign_of_life(&msg.charger_id);
+        }
+        let frame = self.traffic.record(&msg.charger_id, msg.direction, msg.frame);
+        self.metrics.frame(&frame);
+        if let Err(e) = self.journal.append(&JournalEntry::from_frame(&frame)) {
+            warn!("unable to write the journal: {}", e);
+        }
+        if let Some(listeners) = self.traffic_listeners.get_mut(&msg.charger_id) {
+            // the receiver of a listener is dropped when its HTTP client goes away
+            listeners.retain(|listener| listener.unbounded_send(frame.clone()).is_ok());
+            if listeners.is_empty() {
+                self.traffic_listeners.remove(&msg.charger_id);
+            }
+        }
+        self.send_event_about_station(&msg.charger_id, WebEvent::Traffic(frame));
+    }
+}
+
+impl Handler<ListenTraffic> for OcppServer {
+    type Result = MessageResult<ListenTraffic>;
+
+    fn handle(&mut self, msg: ListenTraffic, _: &mut Context<Self>) -> Self::Result {
+        // listeners of quiet stations are not pruned by FrameTapped, so the ones that went away
+        // are dropped whenever a listener is added
+        self.traffic_listeners.retain(|_, listeners| {
+            listeners.retain(|listener| !listener.is_closed());
+            !listeners.is_empty()
+        });
+        let (sender, receiver) = mpsc::unbounded();
+        self.traffic_listeners.entry(msg.charger_id).or_default().push(sender);
+        MessageResult(receiver)
+    }
+}
+
+impl Handler<QueryJournal> for OcppServer {
+    type Result = ResponseFuture<std::io::Result<Vec<JournalEntry>>>;
+
+    /// The file is read on a blocking thread, the server keeps serving the stations meanwhile
+    fn handle(&mut self, msg: QueryJournal, _: &mut Context<Self>) -> Self::Result {
+        let path = self.journal.path().to_path_buf();
+        Box::pin(async move {
+            actix_web::web::block(move || Journal::read(&path, &msg.0)).await.map_err(|e| match e {
+                actix_web::error::BlockingError::Error(e) => e,
+                actix_web::error::BlockingError::Canceled =>
+                    std::io::Error::other("the journal query was canceled"),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use crate::journal::JournalSettings;
+
+    /// Websocket session of a charger that passes what the server sends it to the test
+    struct StubSession {
+        received: mpsc::UnboundedSender<String>,
+    }
+
+    impl Actor for StubSession {
+        type Context = Context<Self>;
+    }
+
+    impl Handler<MessageToChargeStation> for StubSession {
+        type Result = ();
+
+        fn handle(&mut self, msg: MessageToChargeStation, _: &mut Context<Self>) -> Self::Result {
+            let _ = self.received.unbounded_send(msg.0);
+        }
+    }
+
+    impl Handler<HeartbeatPolicyChanged> for StubSession {
+        type Result = ();
+
+        fn handle(&mut self, msg: HeartbeatPolicyChanged, _: &mut Context<Self>) -> Self::Result {
+            let _ = self.received.unbounded_send(format!("heartbeat: {}", msg.0.heartbeat_interval));
+        }
+    }
+
+    impl Handler<CloseChargerSession> for StubSession {
+        type Result = ();
+
+        fn handle(&mut self, msg: CloseChargerSession, ctx: &mut Context<Self>) -> Self::Result {
+            let _ = self.received.unbounded_send(format!("closed: {}", msg.reason));
+            ctx.stop();
+        }
+    }
+
+    fn start_server() -> Addr<OcppServer> {
+        let journal = Journal::open(JournalSettings {
+            path: std::env::temp_dir().join(format!("journal-{}.jsonl", Uuid::new_v4())),
+            retention_days: None,
+            max_entries: None,
+        }).unwrap();
+        let heartbeats = services::HeartbeatService::new(HeartbeatPolicy::default(), HashMap::new());
+        OcppServer::new(Arc::new(SchemaValidator::compile(None).unwrap()), HashMap::new(), heartbeats,
+                        AvailabilitySettings::default(), journal).start()
+    }
+
+    /// Connects a session of CS001, returns its id and what the server sends it
+    async fn connect(server: &Addr<OcppServer>) -> (u64, mpsc::UnboundedReceiver<String>) {
+        let (received, frames) = mpsc::unbounded();
+        let session = StubSession { received }.start();
+        let registration = server.send(ConnectCharger {
+            addr: session.clone().recipient(),
+            close: session.clone().recipient(),
+            heartbeat: session.recipient(),
+            serial_id: String::from("CS001"),
+            version: OcppVersion::V201,
+            peer: services::StationPeer::default(),
+        }).await.unwrap();
+        (registration.session_id, frames)
+    }
+
+    fn disconnect(session_id: u64) -> DisconnectCharger {
+        DisconnectCharger { serial_id: String::from("CS001"), session_id, reason: String::from("closed by the station") }
+    }
+
+    #[actix_rt::test]
+    async fn reconnect_closes_the_replaced_session() {
+        let server = start_server();
+        let (first, mut first_frames) = connect(&server).await;
+        let (second, _second_frames) = connect(&server).await;
+        assert_ne!(first, second);
+        assert_eq!(first_frames.next().await,
+                   Some(String::from("closed: Replaced by a new connection of the charging station")));
+    }
+
+    #[actix_rt::test]
+    async fn stale_disconnect_keeps_the_new_session() {
+        let server = start_server();
+        let (first, _first_frames) = connect(&server).await;
+        let (second, mut second_frames) = connect(&server).await;
+        // the replaced session stops after the new one registered
+        server.send(disconnect(first)).await.unwrap();
+        assert_eq!(server.send(GetChargers).await.unwrap(), vec![String::from("CS001")]);
+        let sent = server.send(SendCall {
+            charger_id: String::from("CS001"),
+            action: String::from("Reset"),
+            payload: json!({"type": "Immediate"}),
+            correlation_id: String::from("test"),
+        }).await.unwrap();
+        assert!(sent.is_ok());
+        let frame = second_frames.next().await.unwrap();
+        assert!(frame.contains("\"Reset\""), "unexpected frame {}", frame);
+
+        server.send(disconnect(second)).await.unwrap();
+        assert!(server.send(GetChargers).await.unwrap().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn repeated_disconnect_is_ignored() {
+        let server = start_server();
+        let (session_id, _frames) = connect(&server).await;
+        server.send(disconnect(session_id)).await.unwrap();
+        // a disconnect reported twice must not remove a later session
+        server.send(disconnect(session_id)).await.unwrap();
+        connect(&server).await;
+        server.send(disconnect(session_id)).await.unwrap();
+        assert_eq!(server.send(GetChargers).await.unwrap(), vec![String::from("CS001")]);
+    }
+
+    #[actix_rt::test]
+    async fn heartbeat_policy_change_reaches_the_session() {
+        let server = start_server();
+        let (_, mut frames) = connect(&server).await;
+        let change = HeartbeatPolicyChange { heartbeat_interval: Some(300), ..HeartbeatPolicyChange::default() };
+        let policy = server.send(SetHeartbeatPolicy { charger_id: String::from("CS001"), change }).await.unwrap();
+        assert_eq!(policy.unwrap(), HeartbeatPolicy { heartbeat_interval: 300, ..HeartbeatPolicy::default() });
+        assert_eq!(frames.next().await, Some(String::from("heartbeat: 300")));
+
+        let change = HeartbeatPolicyChange { pong_timeout: Some(1), ..HeartbeatPolicyChange::default() };
+        assert!(server.send(SetHeartbeatPolicy { charger_id: String::from("CS001"), change }).await.unwrap().is_err());
+    }
+}
@@ -0,0 +1,1033 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::messages::OcppVersion;
+
+// Services below are shared by the message handlers of every OCPP version. Version specific
+// modules (`messages` for 2.0.1, `messages::v16` for 1.6) translate their own message types
+// into these calls, so a transaction started by a 1.6 charge point looks the same as one
+// started by a 2.0.1 charging station.
+
+/// Outcome of authorizing an id token (2.0.1) or id tag (1.6)
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum AuthorizationStatus {
+    Accepted,
+    Blocked,
+    Expired,
+    Invalid,
+    Unknown,
+}
+
+/// There is no id token store yet, so every token is accepted.
+pub fn authorize(_id_token: &str) -> AuthorizationStatus {
+    AuthorizationStatus::Accepted
+}
+
+/// A charging session as seen by the CSMS, regardless of the protocol version of the station
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct Transaction {
+    #[serde(rename = "transactionId")]
+    pub transaction_id: String,
+    #[serde(rename = "chargerId")]
+    pub charger_id: String,
+    /// evseId for OCPP 2.0.1, connectorId for OCPP 1.6
+    #[serde(rename = "evseId")]
+    pub evse_id: Option<i64>,
+    #[serde(rename = "idToken")]
+    pub id_token: Option<String>,
+    #[serde(rename = "startedAt")]
+    pub started_at: String,
+    #[serde(rename = "stoppedAt")]
+    pub stopped_at: Option<String>,
+    #[serde(rename = "stoppedReason")]
+    pub stopped_reason: Option<String>,
+    /// Id of the RequestStartTransaction that started the transaction, if it was started remotely
+    #[serde(rename = "remoteStartId")]
+    pub remote_start_id: Option<i64>,
+    /// Last total cost sent to the station with CostUpdated
+    #[serde(rename = "totalCost")]
+    pub total_cost: Option<f64>,
+}
+
+/// A RequestStartTransaction sent to a station, kept until it is linked to a transaction
+#[derive(Clone, Serialize)]
+pub struct RemoteStart {
+    #[serde(rename = "remoteStartId")]
+    pub remote_start_id: i64,
+    #[serde(rename = "evseId")]
+    pub evse_id: Option<i64>,
+    #[serde(rename = "idToken")]
+    pub id_token: String,
+    #[serde(rename = "transactionId")]
+    pub transaction_id: Option<String>,
+}
+
+pub struct TransactionService {
+    last_transaction_id: i64,
+    last_remote_start_id: i64,
+    transactions: HashMap<(String, String), Transaction>, // key: (charger_id, transaction_id)
+    remote_starts: HashMap<(String, i64), RemoteStart>, // key: (charger_id, remote_start_id)
+    energy_registers: HashMap<(String, String), f64>, // key: (charger_id, transaction_id), value: last reading in Wh
+}
+
+/// Energy.Active.Import.Register of the meterValue of a 1.6 or 2.0.1 message, in Wh. Samples of a
+/// phase or of the EV are not the energy delivered by the station and are skipped.
+pub fn energy_register_wh(meter_values: &Value) -> Option<f64> {
+    let samples = meter_values.as_array()?.iter()
+        .filter_map(|meter_value| meter_value.get("sampledValue").and_then(|samples| samples.as_array()))
+        .flatten();
+    let mut register = None;
+    for sample in samples {
+        let text = |field: &str| sample.get(field).and_then(|value| value.as_str());
+        if text("measurand").unwrap_or("Energy.Active.Import.Register") != "Energy.Active.Import.Register"
+            || text("phase").is_some() || text("location").unwrap_or("Outlet") != "Outlet" {
+            continue;
+        }
+        // the value is a string in 1.6 and a number in 2.0.1
+        let value = match sample.get("value") {
+            Some(Value::String(value)) => value.trim().parse::<f64>().ok(),
+            Some(value) => value.as_f64(),
+            None => None,
+        };
+        let (unit, multiplier) = match sample.get("unitOfMeasure").filter(|unit_of_measure| unit_of_measure.is_object()) {
+            Some(unit_of_measure) => (
+                unit_of_measure.get("unit").and_then(|unit| unit.as_str()).unwrap_or("Wh"),
+                unit_of_measure.get("multiplier").and_then(|multiplier| multiplier.as_i64()).unwrap_or(0),
+            ),
+            None => (text("unit").unwrap_or("Wh"), 0),
+        };
+        let scale = match unit {
+            "Wh" => 1.0,
+            "kWh" => 1000.0,
+            _ => continue,
+        };
+        if let Some(value) = value {
+            register = Some(value * scale * 10f64.powi(multiplier as i32));
+        }
+    }
+    register
+}
+
+impl Default for TransactionService {
+    fn default() -> TransactionService {
+        TransactionService::new()
+    }
+}
+
+impl TransactionService {
+    /// The ids handed out to OCPP 1.6 charge points continue from the start time of the server in
+    /// seconds, so they do not collide with the ones charge points still hold from before a restart
+    pub fn new() -> TransactionService {
+        TransactionService {
+            last_transaction_id: Utc::now().timestamp(),
+            last_remote_start_id: 0,
+            transactions: HashMap::new(),
+            remote_starts: HashMap::new(),
+            energy_registers: HashMap::new(),
+        }
+    }
+
+    /// Registers a started transaction and returns its id. OCPP 2.0.1 stations choose the
+    /// transaction id themselves, OCPP 1.6 charge points expect the CSMS to hand one out, so a
+    /// new id is allocated when `transaction_id` is `None`.
+    pub fn start(&mut self, charger_id: &str, transaction_id: Option<String>, evse_id: Option<i64>,
+                 id_token: Option<String>, timestamp: &str) -> String {
+        let transaction_id = match transaction_id {
+            Some(transaction_id) => transaction_id,
+            None => {
+                self.last_transaction_id += 1;
+                self.last_transaction_id.to_string()
+            }
+        };
+        // the RequestStartTransaction result may name the transaction before it is reported
+        let remote_start_id = self.remote_starts.iter()
+            .find(|((charger, _), remote_start)| {
+                charger == charger_id && remote_start.transaction_id.as_ref() == Some(&transaction_id)
+            })
+            .map(|(_, remote_start)| remote_start.remote_start_id);
+        self.transactions.insert((charger_id.to_string(), transaction_id.clone()), Transaction {
+            transaction_id: transaction_id.clone(),
+            charger_id: charger_id.to_string(),
+            evse_id,
+            id_token,
+            started_at: timestamp.to_string(),
+            stopped_at: None,
+            stopped_reason: None,
+            remote_start_id,
+            total_cost: None,
+        });
+        transaction_id
+    }
+
+    /// Marks a transaction as stopped. Transactions the server has not seen started (e.g. they
+    /// began before the server was restarted) are registered on the fly.
+    pub fn stop(&mut self, charger_id: &str, transaction_id: &str, timestamp: &str, reason: Option<String>) {
+        let transaction = self.transactions
+            .entry((charger_id.to_string(), transaction_id.to_string()))
+            .or_insert_with(|| Transaction {
+                transaction_id: transaction_id.to_string(),
+                charger_id: charger_id.to_string(),
+                evse_id: None,
+                id_token: None,
+                started_at: timestamp.to_string(),
+                stopped_at: None,
+                stopped_reason: None,
+                remote_start_id: None,
+                total_cost: None,
+            });
+        transaction.stopped_at = Some(timestamp.to_string());
+        transaction.stopped_reason = reason;
+        self.energy_registers.remove(&(charger_id.to_string(), transaction_id.to_string()));
+    }
+
+    /// Allocates the remoteStartId of a RequestStartTransaction about to be sent to a station
+    pub fn request_remote_start(&mut self, charger_id: &str, evse_id: Option<i64>, id_token: &str) -> i64 {
+        self.last_remote_start_id += 1;
+        self.remote_starts.insert((charger_id.to_string(), self.last_remote_start_id), RemoteStart {
+            remote_start_id: self.last_remote_start_id,
+            evse_id,
+            id_token: id_token.to_string(),
+            transaction_id: None,
+        });
+        self.last_remote_start_id
+    }
+
+    /// Forgets a remote start the station rejected
+    pub fn cancel_remote_start(&mut self, charger_id: &str, remote_start_id: i64) {
+        self.remote_starts.remove(&(charger_id.to_string(), remote_start_id));
+    }
+
+    /// Links a remote start to its transaction. The link is known either from the
+    /// RequestStartTransaction result, when the transaction had already begun, or from the
+    /// remoteStartId of the first TransactionEvent of the transaction.
+    pub fn link_remote_start(&mut self, charger_id: &str, remote_start_id: i64, transaction_id: &str) {
+        if let Some(remote_start) = self.remote_starts.get_mut(&(charger_id.to_string(), remote_start_id)) {
+            remote_start.transaction_id = Some(transaction_id.to_string());
+        }
+        if let Some(transaction) = self.transactions.get_mut(&(charger_id.to_string(), transaction_id.to_string())) {
+            transaction.remote_start_id = Some(remote_start_id);
+        }
+    }
+
+    pub fn get(&self, charger_id: &str, transaction_id: &str) -> Option<Transaction> {
+        self.transactions.get(&(charger_id.to_string(), transaction_id.to_string())).cloned()
+    }
+
+    /// The transaction running on an EVSE (connector for OCPP 1.6), if any
+    pub fn active(&self, charger_id: &str, evse_id: i64) -> Option<Transaction> {
+        self.transactions.values()
+            .find(|transaction| {
+                transaction.charger_id == charger_id && transaction.evse_id == Some(evse_id) &&
+                    transaction.stopped_at.is_none()
+            })
+            .cloned()
+    }
+
+    /// Transactions started and not stopped, on all stations
+    pub fn active_count(&self) -> usize {
+        self.transactions.values().filter(|transaction| transaction.stopped_at.is_none()).count()
+    }
+
+    /// Records a reading of the energy register of a transaction and returns the energy delivered
+    /// since the previous one, in Wh. The first reading (meterStart) delivers nothing; a register
+    /// that goes back, e.g. a replaced meter, counts from the new value. The readings are kept
+    /// until the transaction stops, so the last one has to come before the stop.
+    pub fn meter_reading(&mut self, charger_id: &str, transaction_id: &str, energy_wh: f64) -> f64 {
+        let key = (charger_id.to_string(), transaction_id.to_string());
+        // a late reading must not bring the register of a stopped transaction back
+        if self.transactions.get(&key).is_some_and(|transaction| transaction.stopped_at.is_some()) {
+            return 0.0;
+        }
+        let previous = self.energy_registers.insert(key, energy_wh);
+        match previous {
+            Some(previous) if energy_wh > previous => energy_wh - previous,
+            _ => 0.0,
+        }
+    }
+
+    pub fn update_cost(&mut self, charger_id: &str, transaction_id: &str, total_cost: f64) {
+        if let Some(transaction) = self.transactions.get_mut(&(charger_id.to_string(), transaction_id.to_string())) {
+            transaction.total_cost = Some(total_cost);
+        }
+    }
+}
+
+/// Network connection profiles accepted by the stations through SetNetworkProfile
+#[derive(Default)]
+pub struct NetworkProfileService {
+    profiles: HashMap<(String, i64), Value>, // key: (charger_id, configuration slot)
+}
+
+impl NetworkProfileService {
+    pub fn new() -> NetworkProfileService {
+        NetworkProfileService { profiles: HashMap::new() }
+    }
+
+    pub fn set(&mut self, charger_id: &str, configuration_slot: i64, connection_data: Value) {
+        self.profiles.insert((charger_id.to_string(), configuration_slot), connection_data);
+    }
+
+    /// The profiles of a station by configuration slot
+    pub fn profiles(&self, charger_id: &str) -> Vec<NetworkProfile> {
+        let mut profiles: Vec<NetworkProfile> = self.profiles.iter()
+            .filter(|((charger, _), _)| charger == charger_id)
+            .map(|((_, configuration_slot), connection_data)| NetworkProfile {
+                configuration_slot: *configuration_slot,
+                connection_data: connection_data.clone(),
+            })
+            .collect();
+        profiles.sort_by_key(|profile| profile.configuration_slot);
+        profiles
+    }
+}
+
+/// A network connection profile in a configuration slot of a station
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct NetworkProfile {
+    #[serde(rename = "configurationSlot")]
+    pub configuration_slot: i64,
+    /// NetworkConnectionProfileType of the SetNetworkProfile request
+    #[serde(rename = "connectionData")]
+    pub connection_data: Value,
+}
+
+/// Last known values of the device model variables of every station, as reported in
+/// GetVariables results or accepted through SetVariables
+#[derive(Default)]
+pub struct DeviceModelService {
+    values: HashMap<(String, String, String), String>, // key: (charger_id, component, variable)
+}
+
+impl DeviceModelService {
+    pub fn new() -> DeviceModelService {
+        DeviceModelService { values: HashMap::new() }
+    }
+
+    pub fn set(&mut self, charger_id: &str, component: &str, variable: &str, value: &str) {
+        self.values.insert((charger_id.to_string(), component.to_string(), variable.to_string()),
+                           value.to_string());
+    }
+
+    pub fn get(&self, charger_id: &str, component: &str, variable: &str) -> Option<&String> {
+        self.values.get(&(charger_id.to_string(), component.to_string(), variable.to_string()))
+    }
+
+    /// The known variables of a station by component and variable name
+    pub fn variables(&self, charger_id: &str) -> Vec<VariableValue> {
+        let mut variables: Vec<VariableValue> = self.values.iter()
+            .filter(|((charger, _, _), _)| charger == charger_id)
+            .map(|((_, component, variable), value)| VariableValue {
+                component: component.clone(),
+                variable: variable.clone(),
+                value: value.clone(),
+            })
+            .collect();
+        variables.sort_by(|a, b| (&a.component, &a.variable).cmp(&(&b.component, &b.variable)));
+        variables
+    }
+}
+
+/// Last known value of a device model variable
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct VariableValue {
+    pub component: String,
+    pub variable: String,
+    pub value: String,
+}
+
+/// A reservation accepted by a station
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct Reservation {
+    pub id: i64,
+    #[serde(rename = "evseId")]
+    pub evse_id: Option<i64>,
+    #[serde(rename = "idToken")]
+    pub id_token: String,
+    #[serde(rename = "expiryDateTime")]
+    pub expiry_date_time: String,
+}
+
+#[derive(Default)]
+pub struct ReservationService {
+    reservations: HashMap<(String, i64), Reservation>, // key: (charger_id, reservation id)
+}
+
+impl ReservationService {
+    pub fn new() -> ReservationService {
+        ReservationService { reservations: HashMap::new() }
+    }
+
+    pub fn reserve(&mut self, charger_id: &str, reservation: Reservation) {
+        self.reservations.insert((charger_id.to_string(), reservation.id), reservation);
+    }
+
+    pub fn cancel(&mut self, charger_id: &str, reservation_id: i64) {
+        self.reservations.remove(&(charger_id.to_string(), reservation_id));
+    }
+
+    /// The reservations of a station by id
+    pub fn reservations(&self, charger_id: &str) -> Vec<Reservation> {
+        let mut reservations: Vec<Reservation> = self.reservations.iter()
+            .filter(|((charger, _), _)| charger == charger_id)
+            .map(|(_, reservation)| reservation.clone())
+            .collect();
+        reservations.sort_by_key(|reservation| reservation.id);
+        reservations
+    }
+}
+
+/// A charging profile installed on a station
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct ChargingProfile {
+    pub id: i64,
+    #[serde(rename = "evseId")]
+    pub evse_id: i64,
+    #[serde(rename = "stackLevel")]
+    pub stack_level: i64,
+    pub purpose: String,
+}
+
+#[derive(Default)]
+pub struct ChargingProfileService {
+    profiles: HashMap<(String, i64), ChargingProfile>, // key: (charger_id, profile id)
+}
+
+impl ChargingProfileService {
+    pub fn new() -> ChargingProfileService {
+        ChargingProfileService { profiles: HashMap::new() }
+    }
+
+    /// A profile with the same id replaces the installed one, like it does on the station
+    pub fn set(&mut self, charger_id: &str, profile: ChargingProfile) {
+        self.profiles.insert((charger_id.to_string(), profile.id), profile);
+    }
+
+    /// Removes the profiles matching all given criteria, `None` matches any value
+    pub fn clear(&mut self, charger_id: &str, profile_id: Option<i64>, evse_id: Option<i64>,
+                 stack_level: Option<i64>, purpose: Option<String>) {
+        self.profiles.retain(|(charger, _), profile| {
+            charger != charger_id ||
+                !(profile_id.is_none_or(|id| id == profile.id) &&
+                    evse_id.is_none_or(|id| id == profile.evse_id) &&
+                    stack_level.is_none_or(|level| level == profile.stack_level) &&
+                    purpose.as_ref().is_none_or(|purpose| *purpose == profile.purpose))
+        });
+    }
+
+    /// The profiles installed on a station by id
+    pub fn profiles(&self, charger_id: &str) -> Vec<ChargingProfile> {
+        let mut profiles: Vec<ChargingProfile> = self.profiles.iter()
+            .filter(|((charger, _), _)| charger == charger_id)
+            .map(|(_, profile)| profile.clone())
+            .collect();
+        profiles.sort_by_key(|profile| profile.id);
+        profiles
+    }
+}
+
+// OCPP 2.0.1 keeps the station configuration in a device model of components and variables,
+// OCPP 1.6 uses a flat list of configuration keys. The table maps the standardized variables
+// onto the keys of the 1.6 core profile. Variables missing from the table are assumed to use
+// their variable name as a (vendor specific) configuration key.
+const CONFIGURATION_KEYS: [(&str, &str, &str); 19] = [
+    // (component, variable, 1.6 configuration key)
+    ("AlignedDataCtrlr", "Interval", "ClockAlignedDataInterval"),
+    ("AlignedDataCtrlr", "Measurands", "MeterValuesAlignedData"),
+    ("AlignedDataCtrlr", "TxEndedMeasurands", "StopTxnAlignedData"),
+    ("AuthCacheCtrlr", "Enabled", "AuthorizationCacheEnabled"),
+    ("AuthCtrlr", "AuthorizeRemoteStart", "AuthorizeRemoteTxRequests"),
+    ("AuthCtrlr", "LocalAuthorizeOffline", "LocalAuthorizeOffline"),
+    ("AuthCtrlr", "LocalPreAuthorize", "LocalPreAuthorize"),
+    ("LocalAuthListCtrlr", "Enabled", "LocalAuthListEnabled"),
+    ("OCPPCommCtrlr", "HeartbeatInterval", "HeartbeatInterval"),
+    ("OCPPCommCtrlr", "MessageAttemptInterval", "TransactionMessageRetryInterval"),
+    ("OCPPCommCtrlr", "MessageAttempts", "TransactionMessageAttempts"),
+    ("OCPPCommCtrlr", "ResetRetries", "ResetRetries"),
+    ("OCPPCommCtrlr", "WebSocketPingInterval", "WebSocketPingInterval"),
+    ("SampledDataCtrlr", "TxEndedMeasurands", "StopTxnSampledData"),
+    ("SampledDataCtrlr", "TxUpdatedInterval", "MeterValueSampleInterval"),
+    ("SampledDataCtrlr", "TxUpdatedMeasurands", "MeterValuesSampledData"),
+    ("TxCtrlr", "EVConnectionTimeOut", "ConnectionTimeOut"),
+    ("TxCtrlr", "StopTxOnEVSideDisconnect", "StopTransactionOnEVSideDisconnect"),
+    ("TxCtrlr", "StopTxOnInvalidId", "StopTransactionOnInvalidId"),
+];
+
+/// Returns the OCPP 1.6 configuration key of an OCPP 2.0.1 component variable
+pub fn configuration_key(component: &str, variable: &str) -> String {
+    CONFIGURATION_KEYS.iter()
+        .find(|(c, v, _)| c.eq_ignore_ascii_case(component) && v.eq_ignore_ascii_case(variable))
+        .map(|(_, _, key)| key.to_string())
+        .unwrap_or_else(|| variable.to_string())
+}
+
+/// What a station told about itself in its last BootNotification
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct BootInfo {
+    pub vendor: String,
+    pub model: String,
+    #[serde(rename = "serialNumber")]
+    pub serial_number: Option<String>,
+    #[serde(rename = "firmwareVersion")]
+    pub firmware_version: Option<String>,
+    /// BootReasonEnumType of OCPP 2.0.1, OCPP 1.6 charge points do not report a reason
+    pub reason: Option<String>,
+    /// when the server received the BootNotification
+    pub timestamp: String,
+}
+
+/// Latest status reported for a connector with StatusNotification
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct Connector {
+    pub id: i64,
+    /// ConnectorStatusEnumType for OCPP 2.0.1, ChargePointStatus for OCPP 1.6
+    pub status: String,
+    pub timestamp: String,
+}
+
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct Evse {
+    pub id: i64,
+    pub connectors: Vec<Connector>,
+    #[serde(rename = "activeTransaction")]
+    pub active_transaction: Option<Transaction>,
+}
+
+/// Where the websocket connection of a station comes from, see proxy.rs
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct StationPeer {
+    pub ip_address: Option<String>,
+    /// subject of the client certificate, as passed on by a trusted proxy
+    pub certificate_subject: Option<String>,
+}
+
+/// A charging station known to the server. OCPP 1.6 connectors are modelled as EVSEs with a
+/// single connector, the way OCPP 2.0.1 migrates them.
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct Station {
+    pub id: String,
+    pub online: bool,
+    /// OCPP version of the current or last connection
+    pub version: OcppVersion,
+    /// address of the current or last connection
+    #[serde(rename = "ipAddress")]
+    pub ip_address: Option<String>,
+    #[serde(rename = "certificateSubject")]
+    pub certificate_subject: Option<String>,
+    #[serde(rename = "lastHeartbeat")]
+    pub last_heartbeat: Option<String>,
+    pub boot: Option<BootInfo>,
+    pub evses: Vec<Evse>,
+}
+
+/// A station together with what the server learned from the results of the calls it sent to
+/// the station
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct StationDetail {
+    #[serde(flatten)]
+    pub station: Station,
+    /// device model variables read with GetVariables or set with SetVariables
+    pub variables: Vec<VariableValue>,
+    pub reservations: Vec<Reservation>,
+    #[serde(rename = "chargingProfiles")]
+    pub charging_profiles: Vec<ChargingProfile>,
+    #[serde(rename = "networkProfiles")]
+    pub network_profiles: Vec<NetworkProfile>,
+}
+
+impl Station {
+    fn new(id: &str, version: OcppVersion) -> Station {
+        Station {
+            id: id.to_string(),
+            online: false,
+            version,
+            ip_address: None,
+            certificate_subject: None,
+            last_heartbeat: None,
+            boot: None,
+            evses: Vec::new(),
+        }
+    }
+}
+
+/// Keeps the stations seen since the server started, including the ones that went offline
+#[derive(Default)]
+pub struct StationService {
+    stations: HashMap<String, Station>, // key: charger_id
+}
+
+impl StationService {
+    pub fn new() -> StationService {
+        StationService { stations: HashMap::new() }
+    }
+
+    pub fn connected(&mut self, charger_id: &str, version: OcppVersion, peer: &StationPeer) {
+        let station = self.stations.entry(charger_id.to_string())
+            .or_insert_with(|| Station::new(charger_id, version));
+        station.online = true;
+        station.version = version;
+        station.ip_address = peer.ip_address.clone();
+        station.certificate_subject = peer.certificate_subject.clone();
+    }
+
+    pub fn disconnected(&mut self, charger_id: &str) {
+        if let Some(station) = self.stations.get_mut(charger_id) {
+            station.online = false;
+        }
+    }
+
+    pub fn booted(&mut self, charger_id: &str, boot: BootInfo) {
+        if let Some(station) = self.stations.get_mut(charger_id) {
+            station.boot = Some(boot);
+        }
+    }
+
+    /// Records a Heartbeat, which also brings back a station whose heartbeats had lapsed
+    pub fn heartbeat(&mut self, charger_id: &str, timestamp: &str) {
+        if let Some(station) = self.stations.get_mut(charger_id) {
+            station.last_heartbeat = Some(timestamp.to_string());
+            station.online = true;
+        }
+    }
+
+    /// Marks a station offline whose websocket is still open but whose heartbeats stopped
+    pub fn heartbeat_lapsed(&mut self, charger_id: &str) {
+        self.disconnected(charger_id);
+    }
+
+    /// Records the status of a connector, EVSEs and connectors are added when first reported
+    pub fn connector_status(&mut self, charger_id: &str, evse_id: i64, connector_id: i64, status: &str,
+                            timestamp: &str) {
+        let station = match self.stations.get_mut(charger_id) {
+            Some(station) => station,
+            None => return,
+        };
+        let evse = match station.evses.iter().position(|evse| evse.id == evse_id) {
+            Some(index) => &mut station.evses[index],
+            None => {
+                station.evses.push(Evse { id: evse_id, connectors: Vec::new(), active_transaction: None });
+                station.evses.sort_by_key(|evse| evse.id);
+                station.evses.iter_mut().find(|evse| evse.id == evse_id).unwrap()
+            }
+        };
+        let connector = Connector { id: connector_id, status: status.to_string(), timestamp: timestamp.to_string() };
+        match evse.connectors.iter_mut().find(|connector| connector.id == connector_id) {
+            Some(known) => *known = connector,
+            None => {
+                evse.connectors.push(connector);
+                evse.connectors.sort_by_key(|connector| connector.id);
+            }
+        }
+    }
+
+    pub fn get(&self, charger_id: &str) -> Option<Station> {
+        self.stations.get(charger_id).cloned()
+    }
+
+    /// All stations ordered by id
+    pub fn all(&self) -> Vec<Station> {
+        let mut stations: Vec<Station> = self.stations.values().cloned().collect();
+        stations.sort_by(|a, b| a.id.cmp(&b.id));
+        stations
+    }
+}
+
+/// How the liveness of a station is checked: the server pings its websocket, and the station is
+/// told in the BootNotification response how often to send a Heartbeat
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct HeartbeatPolicy {
+    /// seconds between two websocket pings of the server
+    #[serde(rename = "pingInterval")]
+    pub ping_interval: u64,
+    /// seconds without any frame of the station, pongs included, before its websocket is closed
+    #[serde(rename = "pongTimeout")]
+    pub pong_timeout: u64,
+    /// seconds between two OCPP Heartbeats of the station
+    #[serde(rename = "heartbeatInterval")]
+    pub heartbeat_interval: u64,
+    /// the station is marked offline when no Heartbeat came for heartbeatInterval × graceFactor
+    #[serde(rename = "graceFactor")]
+    pub grace_factor: f64,
+}
+
+impl Default for HeartbeatPolicy {
+    fn default() -> Self {
+        HeartbeatPolicy {
+            ping_interval: 60,
+            pong_timeout: 600,
+            heartbeat_interval: 3600,
+            grace_factor: 2.0,
+        }
+    }
+}
+
+impl HeartbeatPolicy {
+    pub fn ping_interval(&self) -> Duration {
+        Duration::from_secs(self.ping_interval)
+    }
+
+    pub fn pong_timeout(&self) -> Duration {
+        Duration::from_secs(self.pong_timeout)
+    }
+
+    /// How long the server waits for a Heartbeat before the station is marked offline
+    pub fn lapse(&self) -> Duration {
+        Duration::from_secs_f64(self.heartbeat_interval as f64 * self.grace_factor)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.ping_interval == 0 || self.heartbeat_interval == 0 {
+            return Err(String::from("pingInterval and heartbeatInterval must be at least 1 second"));
+        }
+        if self.pong_timeout <= self.ping_interval {
+            return Err(String::from("pongTimeout must be longer than pingInterval"));
+        }
+        if self.grace_factor.is_nan() || self.grace_factor < 1.0 {
+            return Err(String::from("graceFactor must be at least 1"));
+        }
+        Ok(())
+    }
+
+    /// The policy with the fields of a change applied
+    pub fn with(&self, change: &HeartbeatPolicyChange) -> HeartbeatPolicy {
+        HeartbeatPolicy {
+            ping_interval: change.ping_interval.unwrap_or(self.ping_interval),
+            pong_timeout: change.pong_timeout.unwrap_or(self.pong_timeout),
+            heartbeat_interval: change.heartbeat_interval.unwrap_or(self.heartbeat_interval),
+            grace_factor: change.grace_factor.unwrap_or(self.grace_factor),
+        }
+    }
+}
+
+/// Fields of a heartbeat policy to change, the others are kept
+#[derive(Clone, Default, Deserialize, JsonSchema)]
+pub struct HeartbeatPolicyChange {
+    #[serde(rename = "pingInterval", alias = "ping_interval")]
+    pub ping_interval: Option<u64>,
+    #[serde(rename = "pongTimeout", alias = "pong_timeout")]
+    pub pong_timeout: Option<u64>,
+    #[serde(rename = "heartbeatInterval", alias = "heartbeat_interval")]
+    pub heartbeat_interval: Option<u64>,
+    #[serde(rename = "graceFactor", alias = "grace_factor")]
+    pub grace_factor: Option<f64>,
+}
+
+/// Heartbeat policy of every station: the global one, unless the station has its own
+pub struct HeartbeatService {
+    default: HeartbeatPolicy,
+    stations: HashMap<String, HeartbeatPolicy>, // key: charger_id
+}
+
+impl HeartbeatService {
+    pub fn new(default: HeartbeatPolicy, stations: HashMap<String, HeartbeatPolicy>) -> HeartbeatService {
+        HeartbeatService { default, stations }
+    }
+
+    /// Policy of a station. Station ids of the configuration may have been lowercased by the
+    /// environment source, so they are matched ignoring case.
+    pub fn policy(&self, charger_id: &str) -> HeartbeatPolicy {
+        self.stations.get(charger_id)
+            .or_else(|| self.stations.iter()
+                .find(|(id, _)| id.eq_ignore_ascii_case(charger_id))
+                .map(|(_, policy)| policy))
+            .cloned()
+            .unwrap_or(self.default)
+    }
+
+    pub fn set(&mut self, charger_id: &str, policy: HeartbeatPolicy) {
+        self.stations.retain(|id, _| !id.eq_ignore_ascii_case(charger_id));
+        self.stations.insert(charger_id.to_string(), policy);
+    }
+}
+
+/// A change in the connectivity of a station
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, JsonSchema)]
+pub enum ConnectivityChange {
+    Connected,
+    Disconnected,
+    /// the websocket stayed open but the Heartbeats stopped
+    HeartbeatLapsed,
+    HeartbeatResumed,
+}
+
+impl ConnectivityChange {
+    /// Whether the station is online after the change
+    fn is_online(self) -> bool {
+        matches!(self, ConnectivityChange::Connected | ConnectivityChange::HeartbeatResumed)
+    }
+}
+
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct ConnectivityEvent {
+    pub change: ConnectivityChange,
+    /// why the station disconnected or was marked offline
+    pub reason: Option<String>,
+    /// seconds the station was online before going offline, or offline before coming back;
+    /// missing for the first event and for changes that keep the station offline
+    #[serde(rename = "previousStateSeconds")]
+    pub previous_state_seconds: Option<i64>,
+    pub timestamp: String,
+    #[serde(skip)]
+    time: DateTime<Utc>,
+}
+
+/// Share of a window the station was online
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct Uptime {
+    /// the window as configured, e.g. "24h"
+    pub window: String,
+    #[serde(rename = "windowSeconds")]
+    pub window_seconds: u64,
+    /// percentage of the part of the window since the station was first seen, missing when the
+    /// station was not seen in the window
+    #[serde(rename = "uptimePercent")]
+    pub uptime_percent: Option<f64>,
+}
+
+/// Connectivity history and uptime of a station
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct Availability {
+    #[serde(rename = "stationId")]
+    pub station_id: String,
+    pub online: bool,
+    pub uptime: Vec<Uptime>,
+    /// oldest first
+    pub history: Vec<ConnectivityEvent>,
+}
+
+/// A station that has been offline for a while
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct OfflineStation {
+    #[serde(rename = "stationId")]
+    pub station_id: String,
+    #[serde(rename = "offlineSince")]
+    pub offline_since: String,
+    #[serde(rename = "offlineSeconds")]
+    pub offline_seconds: i64,
+    pub reason: Option<String>,
+}
+
+/// Uptime windows and offline threshold of the connectivity history
+pub struct AvailabilitySettings {
+    /// (name, length), e.g. ("24h", 86400 seconds)
+    pub uptime_windows: Vec<(String, Duration)>,
+    /// stations offline for longer are reported to the web clients and by the API
+    pub offline_threshold: Duration,
+    /// events kept per station, the oldest are dropped
+    pub max_events: usize,
+}
+
+impl Default for AvailabilitySettings {
+    fn default() -> Self {
+        AvailabilitySettings {
+            uptime_windows: vec![
+                (String::from("1h"), Duration::from_secs(60 * 60)),
+                (String::from("24h"), Duration::from_secs(24 * 60 * 60)),
+                (String::from("7d"), Duration::from_secs(7 * 24 * 60 * 60)),
+            ],
+            offline_threshold: Duration::from_secs(5 * 60),
+            max_events: 10000,
+        }
+    }
+}
+
+/// Parses a window such as "90s", "15m", "24h" or "7d"
+pub fn parse_window(window: &str) -> Result<Duration, String> {
+    let window = window.trim();
+    let unit = match window.chars().last() {
+        Some('s') => 1,
+        Some('m') => 60,
+        Some('h') => 60 * 60,
+        Some('d') => 24 * 60 * 60,
+        _ => return Err(format!("{} is not a window, e.g. 24h or 7d", window)),
+    };
+    match window[..window.len() - 1].parse::<u64>() {
+        Ok(count) if count > 0 => Ok(Duration::from_secs(count * unit)),
+        _ => Err(format!("{} is not a window, e.g. 24h or 7d", window)),
+    }
+}
+
+/// Connects, disconnects and heartbeat lapses of every station seen since the server started
+pub struct ConnectivityService {
+    history: HashMap<String, Vec<ConnectivityEvent>>, // key: charger_id
+    max_events: usize,
+}
+
+impl ConnectivityService {
+    pub fn new(max_events: usize) -> ConnectivityService {
+        ConnectivityService { history: HashMap::new(), max_events }
+    }
+
+    pub fn record(&mut self, charger_id: &str, change: ConnectivityChange, reason: Option<String>,
+                  now: DateTime<Utc>) -> ConnectivityEvent {
+        let events = self.history.entry(charger_id.to_string()).or_default();
+        // the time since the station last went the other way
+        let previous_state_seconds = events.last()
+            .filter(|last| last.change.is_online() != change.is_online())
+            .map(|_| {
+                let since = events.iter().rev()
+                    .take_while(|event| event.change.is_online() != change.is_online())
+                    .last()
+                    .map(|event| event.time)
+                    .unwrap_or(now);
+                (now - since).num_seconds()
+            });
+        let event = ConnectivityEvent {
+            change,
+            reason,
+            previous_state_seconds,
+            timestamp: now.to_rfc3339_opts(SecondsFormat::Millis, false),
+            time: now,
+        };
+        events.push(event.clone());
+        if events.len() > self.max_events {
+            let excess = events.len() - self.max_events;
+            events.drain(..excess);
+        }
+        event
+    }
+
+    pub fn history(&self, charger_id: &str) -> Option<&Vec<ConnectivityEvent>> {
+        self.history.get(charger_id)
+    }
+
+    /// Share of the window the station was online, in percent, counted from the first event in
+    /// or before the window
+    pub fn uptime(&self, charger_id: &str, window: Duration, now: DateTime<Utc>) -> Option<f64> {
+        let events = self.history.get(charger_id)?;
+        let start = now - chrono::Duration::from_std(window).ok()?;
+        let (mut online, mut observed) = (0, 0);
+        for (index, event) in events.iter().enumerate() {
+            let end = events.get(index + 1).map_or(now, |next| next.time);
+            let from = event.time.max(start);
+            if end <= from {
+                continue;
+            }
+            let seconds = (end - from).num_milliseconds();
+            observed += seconds;
+            if event.change.is_online() {
+                online += seconds;
+            }
+        }
+        if observed == 0 {
+            return None;
+        }
+        Some(online as f64 * 100.0 / observed as f64)
+    }
+
+    /// Stations offline for longer than the threshold, the longest offline first
+    pub fn offline(&self, threshold: Duration, now: DateTime<Utc>) -> Vec<OfflineStation> {
+        let mut offline: Vec<OfflineStation> = self.history.iter()
+            .filter_map(|(charger_id, events)| {
+                let last = events.last().filter(|last| !last.change.is_online())?;
+                // the station went offline with the first of the changes that kept it offline
+                let since = events.iter().rev().take_while(|event| !event.change.is_online()).last()?;
+                if (now - since.time).to_std().map_or(true, |offline| offline <= threshold) {
+                    return None;
+                }
+                Some(OfflineStation {
+                    station_id: charger_id.clone(),
+                    offline_since: since.timestamp.clone(),
+                    offline_seconds: (now - since.time).num_seconds(),
+                    reason: since.reason.clone().or_else(|| last.reason.clone()),
+                })
+            })
+            .collect();
+        offline.sort_by(|a, b| b.offline_seconds.cmp(&a.offline_seconds).then(a.station_id.cmp(&b.station_id)));
+        offline
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration as ChronoDuration, TimeZone};
+
+    use super::*;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_600_000_000, 0).unwrap() + ChronoDuration::seconds(seconds)
+    }
+
+    #[test]
+    fn uptime_counts_the_observed_part_of_the_window() {
+        let mut connectivity = ConnectivityService::new(100);
+        connectivity.record("CS001", ConnectivityChange::Connected, None, at(0));
+        connectivity.record("CS001", ConnectivityChange::HeartbeatLapsed, None, at(600));
+        connectivity.record("CS001", ConnectivityChange::Disconnected, Some(String::from("connection lost")), at(700));
+        connectivity.record("CS001", ConnectivityChange::Connected, None, at(900));
+        // online 0-600 and 900-1200 of 1200 observed seconds
+        assert_eq!(connectivity.uptime("CS001", Duration::from_secs(3600), at(1200)), Some(75.0));
+        // online 900-1200 of the window 600-1200
+        assert_eq!(connectivity.uptime("CS001", Duration::from_secs(600), at(1200)), Some(50.0));
+        assert_eq!(connectivity.uptime("CS002", Duration::from_secs(600), at(1200)), None);
+    }
+
+    #[test]
+    fn durations_of_the_previous_state() {
+        let mut connectivity = ConnectivityService::new(100);
+        assert_eq!(connectivity.record("CS001", ConnectivityChange::Connected, None, at(0)).previous_state_seconds, None);
+        let lapsed = connectivity.record("CS001", ConnectivityChange::HeartbeatLapsed, None, at(600));
+        assert_eq!(lapsed.previous_state_seconds, Some(600));
+        let disconnected = connectivity.record("CS001", ConnectivityChange::Disconnected, None, at(700));
+        assert_eq!(disconnected.previous_state_seconds, None);
+        let connected = connectivity.record("CS001", ConnectivityChange::Connected, None, at(1000));
+        assert_eq!(connected.previous_state_seconds, Some(400));
+    }
+
+    #[test]
+    fn offline_stations_above_the_threshold() {
+        let mut connectivity = ConnectivityService::new(100);
+        connectivity.record("CS001", ConnectivityChange::Connected, None, at(0));
+        connectivity.record("CS001", ConnectivityChange::Disconnected, Some(String::from("connection lost")), at(100));
+        connectivity.record("CS002", ConnectivityChange::Connected, None, at(0));
+        connectivity.record("CS002", ConnectivityChange::Disconnected, None, at(350));
+        connectivity.record("CS003", ConnectivityChange::Connected, None, at(0));
+        let offline = connectivity.offline(Duration::from_secs(300), at(500));
+        assert_eq!(offline.len(), 1);
+        assert_eq!(offline[0].station_id, "CS001");
+        assert_eq!(offline[0].offline_seconds, 400);
+        assert_eq!(offline[0].reason.as_deref(), Some("connection lost"));
+    }
+
+    #[test]
+    fn windows() {
+        assert_eq!(parse_window("24h"), Ok(Duration::from_secs(86400)));
+        assert_eq!(parse_window("15m"), Ok(Duration::from_secs(900)));
+        assert!(parse_window("0d").is_err());
+        assert!(parse_window("week").is_err());
+    }
+
+    #[test]
+    fn energy_registers_of_both_versions() {
+        let v16 = serde_json::json!([{"timestamp": "2024-01-01T00:00:00Z", "sampledValue": [
+            {"value": "230", "measurand": "Voltage", "unit": "V"},
+            {"value": "4.2", "measurand": "Energy.Active.Import.Register", "unit": "kWh", "phase": "L1"},
+            {"value": "12.5", "unit": "kWh"},
+        ]}]);
+        assert_eq!(energy_register_wh(&v16), Some(12500.0));
+        let v201 = serde_json::json!([{"timestamp": "2024-01-01T00:00:00Z", "sampledValue": [
+            {"value": 3.5, "measurand": "Energy.Active.Import.Register", "phase": null,
+             "unitOfMeasure": {"unit": "Wh", "multiplier": 3}},
+        ]}]);
+        assert_eq!(energy_register_wh(&v201), Some(3500.0));
+        assert_eq!(energy_register_wh(&serde_json::json!([{"sampledValue": [
+            {"value": 11.0, "measurand": "Power.Active.Import"}]}])), None);
+    }
+
+    #[test]
+    fn energy_is_delivered_between_readings() {
+        let mut transactions = TransactionService::new();
+        assert_eq!(transactions.meter_reading("CS001", "1", 1000.0), 0.0);
+        assert_eq!(transactions.meter_reading("CS001", "1", 1500.0), 500.0);
+        assert_eq!(transactions.meter_reading("CS001", "2", 1500.0), 0.0);
+        assert_eq!(transactions.meter_reading("CS001", "1", 200.0), 0.0);
+        assert_eq!(transactions.meter_reading("CS001", "1", 300.0), 100.0);
+        transactions.stop("CS001", "1", "2024-01-01T00:00:00Z", None);
+        assert_eq!(transactions.meter_reading("CS001", "1", 400.0), 0.0);
+        assert!(!transactions.energy_registers.contains_key(&(String::from("CS001"), String::from("1"))));
+    }
+}
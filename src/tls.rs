@@ -0,0 +1,177 @@
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use rustls::internal::pemfile::{certs, pkcs8_private_keys};
+use rustls::sign::{self, CertifiedKey};
+use rustls::{AllowAnyAnonymousOrAuthenticatedClient, AllowAnyAuthenticatedClient, ClientHello, NoClientAuth,
+             ResolvesServerCert, RootCertStore};
+
+use crate::config::{SecurityConfig, TlsConfig};
+
+// TLS settings of the wss:// listener, built from the [tls] and [security] sections of the config.
+// Stations of security profile 3 must present a certificate signed by tls.client_ca; with a
+// lower profile a client certificate is checked when there is a CA but is not required.
+//
+// The certificate and key are served by a `CertificateReloader`, which reads them again when the
+// files change on disk. A renewal then only affects the next handshakes, the connected stations
+// keep their sessions. The client CA is read once, a new one needs a restart.
+
+fn open(path: &Path) -> Result<BufReader<File>, String> {
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|e| format!("unable to open {}: {}", path.display(), e))
+}
+
+/// Certificate chain and key of the listener
+fn certified_key(cert: &Path, key: &Path) -> Result<CertifiedKey, String> {
+    let cert_chain = certs(&mut open(cert)?)
+        .map_err(|_| format!("{} is not a PEM certificate chain", cert.display()))?;
+    if cert_chain.is_empty() {
+        return Err(format!("no certificate in {}", cert.display()));
+    }
+    let mut keys = pkcs8_private_keys(&mut open(key)?)
+        .map_err(|_| format!("{} is not a PEM PKCS8 key", key.display()))?;
+    if keys.is_empty() {
+        return Err(format!("no PKCS8 key in {}", key.display()));
+    }
+    let signing_key = sign::any_supported_type(&keys.remove(0))
+        .map_err(|_| format!("the key of {} is not supported", key.display()))?;
+    Ok(CertifiedKey::new(cert_chain, Arc::new(signing_key)))
+}
+
+/// Modification times of the certificate and key files
+fn modified(cert: &Path, key: &Path) -> Option<(SystemTime, SystemTime)> {
+    let modified = |path: &Path| fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+    Some((modified(cert)?, modified(key)?))
+}
+
+/// Serves the certificate of the listener, read again when the files change
+pub struct CertificateReloader {
+    cert: PathBuf,
+    key: PathBuf,
+    modified: Mutex<Option<(SystemTime, SystemTime)>>,
+    current: RwLock<CertifiedKey>,
+}
+
+impl CertificateReloader {
+    pub fn new(cert: &Path, key: &Path) -> Result<CertificateReloader, String> {
+        Ok(CertificateReloader {
+            modified: Mutex::new(modified(cert, key)),
+            current: RwLock::new(certified_key(cert, key)?),
+            cert: cert.to_path_buf(),
+            key: key.to_path_buf(),
+        })
+    }
+
+    /// Reads the files again when they changed since the last time. Returns whether the
+    /// certificate was replaced; a certificate that cannot be read is an error and the current
+    /// one is kept.
+    pub fn reload_if_changed(&self) -> Result<bool, String> {
+        let modified = modified(&self.cert, &self.key);
+        let mut last_modified = self.modified.lock().unwrap();
+        if modified.is_none() || modified == *last_modified {
+            return Ok(false);
+        }
+        // a renewal read while half written fails and is read again on the next check
+        let certified_key = certified_key(&self.cert, &self.key)?;
+        *self.current.write().unwrap() = certified_key;
+        *last_modified = modified;
+        Ok(true)
+    }
+
+    /// Checks the files every interval on a thread of its own
+    pub fn watch(self: &Arc<Self>, interval: Duration) {
+        let reloader = self.clone();
+        thread::spawn(move || loop {
+            thread::sleep(interval);
+            match reloader.reload_if_changed() {
+                Ok(true) => tracing::info!("reloaded the TLS certificate {}", reloader.cert.display()),
+                Ok(false) => {}
+                Err(e) => tracing::warn!("keeping the current TLS certificate, {}", e),
+            }
+        });
+    }
+}
+
+impl ResolvesServerCert for CertificateReloader {
+    fn resolve(&self, _: ClientHello) -> Option<CertifiedKey> {
+        Some(self.current.read().unwrap().clone())
+    }
+}
+
+/// Server configuration of rustls with the certificate of the reloader, with an error naming the
+/// file that could not be used
+pub fn server_config(tls: &TlsConfig, security: &SecurityConfig,
+                     reloader: Arc<CertificateReloader>) -> Result<rustls::ServerConfig, String> {
+    let client_auth = match &tls.client_ca {
+        Some(client_ca) => {
+            let mut roots = RootCertStore::empty();
+            let (valid, _) = roots.add_pem_file(&mut open(Path::new(client_ca))?)
+                .map_err(|_| format!("{} is not a PEM file", client_ca))?;
+            if valid == 0 {
+                return Err(format!("no CA certificate in {}", client_ca));
+            }
+            if security.profile == 3 {
+                AllowAnyAuthenticatedClient::new(roots)
+            } else {
+                AllowAnyAnonymousOrAuthenticatedClient::new(roots)
+            }
+        }
+        None => NoClientAuth::new(),
+    };
+    let mut config = rustls::ServerConfig::new(client_auth);
+    config.cert_resolver = reloader;
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Copies of the certificate and key of the repository, the reloader must not touch those
+    fn copies(name: &str) -> (PathBuf, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("rusted_ocpp_server-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        fs::copy(root.join("cert.pem"), dir.join("cert.pem")).unwrap();
+        fs::copy(root.join("key.pem"), dir.join("key.pem")).unwrap();
+        (dir.join("cert.pem"), dir.join("key.pem"))
+    }
+
+    fn touch(path: &Path, seconds: u64) {
+        File::options().write(true).open(path).unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(seconds)).unwrap();
+    }
+
+    #[test]
+    fn changed_files_are_reloaded() {
+        let (cert, key) = copies("reload");
+        let reloader = CertificateReloader::new(&cert, &key).unwrap();
+        assert_eq!(reloader.reload_if_changed(), Ok(false));
+        touch(&cert, 10);
+        assert_eq!(reloader.reload_if_changed(), Ok(true));
+        assert_eq!(reloader.reload_if_changed(), Ok(false));
+        fs::remove_dir_all(cert.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn unreadable_files_keep_the_current_certificate_until_they_can_be_read() {
+        let (cert, key) = copies("broken");
+        let reloader = CertificateReloader::new(&cert, &key).unwrap();
+        let current = reloader.current.read().unwrap().cert.clone();
+        fs::write(&key, "not a key").unwrap();
+        touch(&key, 10);
+        assert!(reloader.reload_if_changed().is_err());
+        assert_eq!(reloader.current.read().unwrap().cert, current);
+        // retried until the key can be read
+        assert!(reloader.reload_if_changed().is_err());
+        fs::copy(Path::new(env!("CARGO_MANIFEST_DIR")).join("key.pem"), &key).unwrap();
+        touch(&key, 10);
+        assert_eq!(reloader.reload_if_changed(), Ok(true));
+        fs::remove_dir_all(cert.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::events;
+
+// Traffic tap: every OCPP-J frame a charging station sends or receives, as it went over the
+// websocket. Calls are remembered until the matching CallResult or CallError, which then carries
+// the latency of the call. The frames are sent to the web clients subscribed to the station and
+// to the Server-Sent Events stream of GET /api/stations/{id}/traffic.
+
+/// Direction of a frame, seen from the server
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub enum TrafficDirection {
+    /// sent by the station
+    Inbound,
+    /// sent to the station
+    Outbound,
+}
+
+impl TrafficDirection {
+    fn opposite(self) -> TrafficDirection {
+        match self {
+            TrafficDirection::Inbound => TrafficDirection::Outbound,
+            TrafficDirection::Outbound => TrafficDirection::Inbound,
+        }
+    }
+}
+
+/// A frame to or from a station
+#[derive(Clone, Serialize, JsonSchema)]
+pub struct TrafficFrame {
+    #[serde(rename = "stationId")]
+    pub station_id: String,
+    pub direction: TrafficDirection,
+    /// 2 Call, 3 CallResult, 4 CallError, 6 Send; missing when the frame is not OCPP-J
+    #[serde(rename = "messageTypeId")]
+    pub message_type_id: Option<u64>,
+    #[serde(rename = "messageId")]
+    pub message_id: Option<String>,
+    /// action of the call, also set on its CallResult or CallError
+    pub action: Option<String>,
+    /// milliseconds between the call and this CallResult or CallError
+    #[serde(rename = "latencyMs")]
+    pub latency_ms: Option<u64>,
+    /// the frame as it went over the websocket
+    pub frame: String,
+    pub timestamp: String,
+}
+
+/// Calls waiting for their CallResult or CallError
+#[derive(Default)]
+pub struct TrafficTap {
+    calls: HashMap<(String, TrafficDirection, String), (String, Instant)>, // key: (charger_id, direction of the call, MessageId)
+}
+
+impl TrafficTap {
+    pub fn new() -> TrafficTap {
+        TrafficTap { calls: HashMap::new() }
+    }
+
+    /// Describes a frame, matching CallResults and CallErrors with their call
+    pub fn record(&mut self, station_id: &str, direction: TrafficDirection, frame: String) -> TrafficFrame {
+        let parsed: Option<Vec<Value>> = serde_json::from_str(&frame).ok();
+        let field = |index: usize| parsed.as_ref().and_then(|fields| fields.get(index));
+        let message_type_id = field(0).and_then(|id| id.as_u64());
+        let message_id = field(1).and_then(|id| id.as_str()).map(String::from);
+        let mut action = None;
+        let mut latency_ms = None;
+        match (message_type_id, &message_id) {
+            (Some(2), Some(message_id)) => {
+                action = field(2).and_then(|action| action.as_str()).map(String::from);
+                if let Some(action) = &action {
+                    self.calls.insert((station_id.to_string(), direction, message_id.clone()),
+                                      (action.clone(), Instant::now()));
+                }
+            }
+            (Some(3), Some(message_id)) | (Some(4), Some(message_id)) => {
+                let key = (station_id.to_string(), direction.opposite(), message_id.clone());
+                if let Some((call_action, sent)) = self.calls.remove(&key) {
+                    action = Some(call_action);
+                    latency_ms = Some(sent.elapsed().as_millis() as u64);
+                }
+            }
+            (Some(6), _) => {
+                action = field(2).and_then(|action| action.as_str()).map(String::from);
+            }
+            _ => {}
+        }
+        TrafficFrame {
+            station_id: station_id.to_string(),
+            direction,
+            message_type_id,
+            message_id,
+            action,
+            latency_ms,
+            frame,
+            timestamp: events::now(),
+        }
+    }
+
+    /// Drops the unanswered calls of a station that disconnected
+    pub fn forget(&mut self, station_id: &str) {
+        self.calls.retain(|(charger_id, _, _), _| charger_id != station_id);
+    }
+}
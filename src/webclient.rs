@@ -5,7 +5,9 @@ use crate::messages::*;
 use crate::server;
 use actix_web_actors::ws::{ProtocolError};
 use crate::server::{MessageToWebBrowser, ConnectWebClient, DisconnectWebClient};
+use serde::Deserialize;
 use serde_json::Value;
+use crate::events::{self, WebEvent};
 
 pub struct WebBrowserWebSocketSession {
     pub id: String,
@@ -14,6 +16,19 @@ pub struct WebBrowserWebSocketSession {
 }
 
 
+/// Body of the subscribe and unsubscribe messages of the web client, e.g.
+/// `{"message": "subscribe", "stations": ["CS001"], "groups": ["depot_a"]}` or
+/// `{"message": "unsubscribe", "all": true}`
+#[derive(Deserialize)]
+struct SubscriptionRequest {
+    #[serde(default)]
+    all: bool,
+    #[serde(default)]
+    stations: Vec<String>,
+    #[serde(default)]
+    groups: Vec<String>,
+}
+
 impl WebBrowserWebSocketSession {
     fn hb(&self, ctx: &mut ws::WebsocketContext<Self>) {
         ctx.run_interval(HEARTBEAT_INTERVAL, |act, ctx| {
@@ -57,9 +72,8 @@ impl Handler<server::MessageToWebBrowser> for WebBrowserWebSocketSession {
     type Result = ();
 
     fn handle(&mut self, msg: MessageToWebBrowser, ctx: &mut Self::Context) -> Self::Result {
-        match serde_json::to_string(&msg) {
-            Ok(message_to_web_browser) => {ctx.text(message_to_web_browser)}
-            Err(_) => {}
+        if let Ok(message_to_web_browser) = serde_json::to_string(&msg.0) {
+            ctx.text(message_to_web_browser)
         }
     }
 }
@@ -83,22 +97,37 @@ impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for WebBrowserWebSock
             }
             ws::Message::Text(text) => {
                 let json: Value = serde_json::from_str(text.as_str()).expect("JSON string is wrong");
-                let message = json.get("message");
-                if message.is_some() {
-                    match message.unwrap().as_str() {
+                if let Some(message) = json.get("message") {
+                    match message.as_str() {
                         None => {}
                         Some("connect") => {
-                            match serde_json::to_string(&MessageToWebBrowser{ message: "connected to the ocpp server".to_string() }){
-                                Ok(text) => ctx.text(text),
-                                Err(_) => {}
+                            if let Ok(text) = serde_json::to_string(&WebEvent::SessionOpened { timestamp: events::now() }) {
+                                ctx.text(text)
+                            }
+                        }
+                        Some("subscribe") => {
+                            if let Ok(request) = serde_json::from_value::<SubscriptionRequest>(json.clone()) {
+                                self.address.do_send(server::Subscribe {
+                                    web_client: self.id.clone(),
+                                    all: request.all,
+                                    stations: request.stations,
+                                    groups: request.groups,
+                                });
+                            }
+                        }
+                        Some("unsubscribe") => {
+                            if let Ok(request) = serde_json::from_value::<SubscriptionRequest>(json.clone()) {
+                                self.address.do_send(server::Unsubscribe {
+                                    web_client: self.id.clone(),
+                                    all: request.all,
+                                    stations: request.stations,
+                                    groups: request.groups,
+                                });
                             }
                         }
                         Some("disconnect") => {
-                            match serde_json::to_string(&MessageToWebBrowser{ message: "disconnecting from the ocpp server".to_string() }){
-                                Ok(text) => {
-                                    ctx.text(text)
-                                },
-                                Err(_) => {}
+                            if let Ok(text) = serde_json::to_string(&WebEvent::SessionClosing { timestamp: events::now() }) {
+                                ctx.text(text)
                             }
                             self.address.do_send(
                                 DisconnectWebClient{